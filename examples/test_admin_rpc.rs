@@ -8,7 +8,7 @@
 //! Requires: TENDERLY_ACCESS_KEY, TENDERLY_ACCOUNT, TENDERLY_PROJECT
 
 use tndrly::vnets::{CreateVNetRequest, SendTransactionParams};
-use tndrly::Client;
+use tndrly::{Client, U256};
 
 const TEST_ADDRESS: &str = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"; // vitalik.eth
 const TEST_CONTRACT: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"; // USDC on mainnet
@@ -182,10 +182,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Test: set_balances (multiple addresses)
     let addresses = [TEST_ADDRESS, ZERO_ADDRESS];
-    match admin.set_balances(&addresses, "1000000000000000000").await {
-        Ok(hash) => results.pass(
+    match admin
+        .set_balances(&addresses, U256::from(1_000_000_000_000_000_000u128))
+        .await
+    {
+        Ok(hashes) => results.pass(
             "set_balances (multiple)",
-            &format!("2 addrs, tx: {}...", &hash[..20]),
+            &format!("{} addrs, {} txs", addresses.len(), hashes.len()),
         ),
         Err(e) => results.fail("set_balances (multiple)", &e.to_string()),
     }
@@ -236,8 +236,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     if let Some(ref list) = sim_list {
         if let Some(sim) = list.simulations.first() {
             match client.simulation().info(&sim.id).await {
-                Ok(v) => {
-                    println!("✓ ({} keys)", v.as_object().map(|o| o.len()).unwrap_or(0));
+                Ok(info) => {
+                    let block = info.transaction_info.and_then(|tx| tx.block_number);
+                    println!("✓ (block {:?})", block);
                     passed += 1;
                 }
                 Err(e) => {
@@ -278,7 +279,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let bundle_req = BundleSimulationRequest::new(vec![sim_req.clone()]);
     match client.simulation().simulate_bundle(&bundle_req).await {
         Ok(r) => {
-            println!("✓ ({} results)", r.simulation_results.len());
+            println!("✓ ({} results)", r.transactions.len());
             passed += 1;
         }
         Err(e) => {
@@ -0,0 +1,208 @@
+//! ABI decoding
+
+use super::token::{matching_paren, parse_param_list, Function, ParamType, Token};
+use crate::error::{Error, Result};
+use crate::utils::{decode_hex, encode_hex};
+use crate::U256;
+
+/// Decode return data according to a function's declared return types
+///
+/// `signature` may be a full signature with return types
+/// (`balanceOf(address)(uint256)` or `balanceOf(address) returns (uint256)`)
+/// or just the return types (`uint256`, `(uint256,uint256)`).
+///
+/// # Example
+///
+/// ```
+/// use tndrly::abi::{decode_output, Token};
+///
+/// let output = "0x0000000000000000000000000000000000000000000000000000000000000064";
+/// let values = decode_output("balanceOf(address)(uint256)", output).unwrap();
+///
+/// assert_eq!(values, vec![Token::uint(100u8)]);
+/// ```
+pub fn decode_output(signature: &str, output: &str) -> Result<Vec<Token>> {
+    let data = decode_hex(output)
+        .ok_or_else(|| Error::invalid_param(format!("Invalid hex output: {}", output)))?;
+    decode(&output_types(signature)?, &data)
+}
+
+/// Resolve the return types described by `signature`
+fn output_types(signature: &str) -> Result<Vec<ParamType>> {
+    let signature = signature.trim();
+    if signature.starts_with('(') && matching_paren(signature, 0) == Some(signature.len() - 1) {
+        return parse_param_list(&signature[1..signature.len() - 1]);
+    }
+    if signature.contains('(') && !signature.starts_with('(') {
+        return Ok(Function::parse(signature)?.outputs);
+    }
+    parse_param_list(signature)
+}
+
+/// ABI-decode data against a list of types
+pub fn decode(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>> {
+    decode_tuple(types, data, 0)
+}
+
+fn decode_tuple(types: &[ParamType], data: &[u8], base: usize) -> Result<Vec<Token>> {
+    let mut offset = base;
+    types
+        .iter()
+        .map(|ty| {
+            let token = if ty.is_dynamic() {
+                let location = checked_add(base, read_usize(data, offset)?)?;
+                decode_value(ty, data, location)?
+            } else {
+                decode_value(ty, data, offset)?
+            };
+            offset = checked_add(offset, ty.head_size())?;
+            Ok(token)
+        })
+        .collect()
+}
+
+fn decode_value(ty: &ParamType, data: &[u8], offset: usize) -> Result<Token> {
+    match ty {
+        ParamType::Address => Ok(Token::Address(encode_hex(&word(data, offset)?[12..]))),
+        ParamType::Uint(_) => Ok(Token::Uint(U256::from_be_bytes(*word(data, offset)?))),
+        ParamType::Int(_) => Ok(Token::Int(U256::from_be_bytes(*word(data, offset)?))),
+        ParamType::Bool => match U256::from_be_bytes(*word(data, offset)?).as_u64() {
+            Some(0) => Ok(Token::Bool(false)),
+            Some(1) => Ok(Token::Bool(true)),
+            _ => Err(Error::invalid_param("Invalid ABI boolean value")),
+        },
+        ParamType::FixedBytes(size) => Ok(Token::FixedBytes(word(data, offset)?[..*size].to_vec())),
+        ParamType::Bytes => Ok(Token::Bytes(read_dynamic_bytes(data, offset)?.to_vec())),
+        ParamType::String => {
+            let bytes = read_dynamic_bytes(data, offset)?;
+            String::from_utf8(bytes.to_vec())
+                .map(Token::String)
+                .map_err(|_| Error::invalid_param("Invalid UTF-8 in ABI string"))
+        }
+        ParamType::Array(inner) => {
+            let len = read_usize(data, offset)?;
+            // Each element needs at least one word, which bounds bogus lengths
+            if len > data.len() / 32 {
+                return Err(Error::invalid_param("ABI array length out of bounds"));
+            }
+            let types = vec![(**inner).clone(); len];
+            decode_tuple(&types, data, checked_add(offset, 32)?).map(Token::Array)
+        }
+        ParamType::FixedArray(inner, size) => {
+            if *size > data.len() / 32 {
+                return Err(Error::invalid_param("ABI array length out of bounds"));
+            }
+            let types = vec![(**inner).clone(); *size];
+            decode_tuple(&types, data, offset).map(Token::FixedArray)
+        }
+        ParamType::Tuple(types) => decode_tuple(types, data, offset).map(Token::Tuple),
+    }
+}
+
+fn word(data: &[u8], offset: usize) -> Result<&[u8; 32]> {
+    data.get(offset..offset.saturating_add(32))
+        .and_then(|w| w.try_into().ok())
+        .ok_or_else(|| Error::invalid_param("ABI data too short"))
+}
+
+fn read_usize(data: &[u8], offset: usize) -> Result<usize> {
+    U256::from_be_bytes(*word(data, offset)?)
+        .as_u64()
+        .and_then(|v| usize::try_from(v).ok())
+        .ok_or_else(|| Error::invalid_param("ABI offset out of range"))
+}
+
+fn read_dynamic_bytes(data: &[u8], offset: usize) -> Result<&[u8]> {
+    let len = read_usize(data, offset)?;
+    let start = checked_add(offset, 32)?;
+    data.get(start..checked_add(start, len)?)
+        .ok_or_else(|| Error::invalid_param("ABI data too short"))
+}
+
+/// Add offsets read from untrusted data without overflowing
+fn checked_add(a: usize, b: usize) -> Result<usize> {
+    a.checked_add(b)
+        .ok_or_else(|| Error::invalid_param("ABI offset out of range"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::encode;
+
+    #[test]
+    fn test_decode_balance_of() {
+        let output = "0x0000000000000000000000000000000000000000000000000de0b6b3a7640000";
+        let values = decode_output("balanceOf(address)(uint256)", output).unwrap();
+
+        assert_eq!(values, vec![Token::uint(1_000_000_000_000_000_000u128)]);
+
+        // Bare return type
+        assert_eq!(decode_output("uint256", output).unwrap(), values);
+    }
+
+    #[test]
+    fn test_decode_uint_pair() {
+        let output = "0x\
+            0000000000000000000000000000000000000000000000000000000000000001\
+            0000000000000000000000000000000000000000000000000000000000000002";
+
+        let values = decode_output("(uint256,uint256)", output).unwrap();
+        assert_eq!(values, vec![Token::uint(1u8), Token::uint(2u8)]);
+
+        let values = decode_output("getPair() returns (uint256 a, uint256 b)", output).unwrap();
+        assert_eq!(values, vec![Token::uint(1u8), Token::uint(2u8)]);
+    }
+
+    #[test]
+    fn test_decode_dynamic_roundtrip() {
+        let types = vec![
+            ParamType::String,
+            ParamType::Array(Box::new(ParamType::Address)),
+            ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes]),
+            ParamType::Int(256),
+        ];
+        let tokens = vec![
+            Token::string("hello"),
+            Token::Array(vec![
+                Token::address("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"),
+                Token::address("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            ]),
+            Token::Tuple(vec![Token::Bool(true), Token::bytes(vec![0xde, 0xad])]),
+            Token::int(-42),
+        ];
+
+        let data = encode(&types, &tokens).unwrap();
+        assert_eq!(decode(&types, &data).unwrap(), tokens);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // Too short
+        assert!(decode_output("uint256", "0x01").is_err());
+        // Not hex
+        assert!(decode_output("uint256", "0xzz").is_err());
+        // Invalid bool
+        let two = format!("0x{:064x}", 2);
+        assert!(decode_output("bool", &two).is_err());
+    }
+
+    #[test]
+    fn test_decode_hostile_offsets() {
+        let word = |v: u64| format!("{:064x}", v);
+
+        // Element offset of u64::MAX must not overflow
+        let output = format!("0x{}{}{}", word(0x20), word(1), word(u64::MAX));
+        assert!(decode_output("string[]", &output).is_err());
+
+        // Head offset and string length of u64::MAX
+        let output = format!("0x{}", word(u64::MAX));
+        assert!(decode_output("string", &output).is_err());
+        let output = format!("0x{}{}", word(0x20), word(u64::MAX));
+        assert!(decode_output("bytes", &output).is_err());
+
+        // Fixed array larger than the data is rejected before allocating
+        let types = [ParamType::FixedArray(Box::new(ParamType::Bool), usize::MAX)];
+        assert!(decode(&types, &[0; 64]).is_err());
+    }
+}
@@ -0,0 +1,292 @@
+//! ABI encoding
+
+use super::token::{Function, ParamType, Token};
+use crate::error::{Error, Result};
+use crate::utils::{decode_hex, encode_hex, keccak256};
+use crate::U256;
+
+/// Compute the 4-byte function selector for a signature
+///
+/// # Example
+///
+/// ```
+/// use tndrly::abi::selector;
+///
+/// assert_eq!(selector("transfer(address,uint256)").unwrap(), [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+pub fn selector(signature: &str) -> Result<[u8; 4]> {
+    let function = Function::parse(signature)?;
+    Ok(selector_of(&function))
+}
+
+pub(crate) fn selector_of(function: &Function) -> [u8; 4] {
+    let hash = keccak256(function.canonical().as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Encode a function call as `0x`-prefixed calldata
+///
+/// The signature is parsed to derive both the selector and the argument
+/// types, and each argument is checked against its declared type.
+///
+/// # Example
+///
+/// ```
+/// use tndrly::abi::{encode_call, Token};
+///
+/// let data = encode_call(
+///     "approve(address,uint256)",
+///     &[
+///         Token::address("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D"),
+///         Token::uint(u128::MAX),
+///     ],
+/// )
+/// .unwrap();
+///
+/// assert!(data.starts_with("0x095ea7b3"));
+/// ```
+pub fn encode_call(signature: &str, args: &[Token]) -> Result<String> {
+    let function = Function::parse(signature)?;
+    let mut data = selector_of(&function).to_vec();
+    data.extend(encode(&function.inputs, args)?);
+    Ok(encode_hex(&data))
+}
+
+/// ABI-encode a list of values against their types (without selector)
+pub fn encode(types: &[ParamType], tokens: &[Token]) -> Result<Vec<u8>> {
+    if types.len() != tokens.len() {
+        return Err(Error::invalid_param(format!(
+            "Expected {} ABI arguments, got {}",
+            types.len(),
+            tokens.len()
+        )));
+    }
+
+    let head_size: usize = types.iter().map(ParamType::head_size).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = Vec::new();
+
+    for (ty, token) in types.iter().zip(tokens) {
+        let encoded = encode_value(ty, token)?;
+        if ty.is_dynamic() {
+            head.extend_from_slice(&word_usize(head_size + tail.len()));
+            tail.extend(encoded);
+        } else {
+            head.extend(encoded);
+        }
+    }
+
+    head.extend(tail);
+    Ok(head)
+}
+
+fn encode_value(ty: &ParamType, token: &Token) -> Result<Vec<u8>> {
+    match (ty, token) {
+        (ParamType::Address, Token::Address(address)) => {
+            let bytes = decode_hex(address)
+                .filter(|b| b.len() == 20 && address.starts_with("0x"))
+                .ok_or_else(|| Error::invalid_param(format!("Invalid address: {}", address)))?;
+            Ok(left_pad(&bytes).to_vec())
+        }
+        (ParamType::Uint(bits), Token::Uint(value)) => {
+            let bytes = value.to_be_bytes();
+            if bytes[..32 - bits / 8].iter().any(|b| *b != 0) {
+                return Err(Error::invalid_param(format!(
+                    "Value {} does not fit in uint{}",
+                    value, bits
+                )));
+            }
+            Ok(bytes.to_vec())
+        }
+        (ParamType::Int(bits), Token::Int(value)) => {
+            let bytes = value.to_be_bytes();
+            let sign = if bytes[32 - bits / 8] & 0x80 != 0 {
+                0xff
+            } else {
+                0
+            };
+            if bytes[..32 - bits / 8].iter().any(|b| *b != sign) {
+                return Err(Error::invalid_param(format!(
+                    "Value does not fit in int{}",
+                    bits
+                )));
+            }
+            Ok(bytes.to_vec())
+        }
+        (ParamType::Bool, Token::Bool(value)) => Ok(U256::from(*value).to_be_bytes().to_vec()),
+        (ParamType::FixedBytes(size), Token::FixedBytes(bytes)) => {
+            if bytes.len() != *size {
+                return Err(Error::invalid_param(format!(
+                    "Expected {} bytes for bytes{}, got {}",
+                    size,
+                    size,
+                    bytes.len()
+                )));
+            }
+            Ok(right_pad(bytes))
+        }
+        (ParamType::Bytes, Token::Bytes(bytes)) => Ok(encode_dynamic_bytes(bytes)),
+        (ParamType::String, Token::String(value)) => Ok(encode_dynamic_bytes(value.as_bytes())),
+        (ParamType::Array(inner), Token::Array(items)) => {
+            let types = vec![(**inner).clone(); items.len()];
+            let mut out = word_usize(items.len()).to_vec();
+            out.extend(encode(&types, items)?);
+            Ok(out)
+        }
+        (ParamType::FixedArray(inner, size), Token::FixedArray(items)) => {
+            if items.len() != *size {
+                return Err(Error::invalid_param(format!(
+                    "Expected {} elements for {}, got {}",
+                    size,
+                    ty,
+                    items.len()
+                )));
+            }
+            encode(&vec![(**inner).clone(); *size], items)
+        }
+        (ParamType::Tuple(types), Token::Tuple(items)) => encode(types, items),
+        _ => Err(Error::invalid_param(format!(
+            "ABI argument {:?} does not match type {}",
+            token, ty
+        ))),
+    }
+}
+
+fn encode_dynamic_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = word_usize(bytes.len()).to_vec();
+    out.extend(right_pad(bytes));
+    out
+}
+
+fn word_usize(value: usize) -> [u8; 32] {
+    U256::from(value as u64).to_be_bytes()
+}
+
+fn left_pad(bytes: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(bytes);
+    out
+}
+
+fn right_pad(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    out.resize(bytes.len().div_ceil(32) * 32, 0);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_transfer() {
+        let data = encode_call(
+            "transfer(address,uint256)",
+            &[
+                Token::address("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+                Token::uint(1_000_000_000_000_000_000u128),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            data,
+            "0xa9059cbb\
+             000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045\
+             0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+        );
+    }
+
+    #[test]
+    fn test_encode_approve_max() {
+        let data = encode_call(
+            "approve(address spender, uint256 amount)",
+            &[
+                Token::address("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D"),
+                Token::Uint(U256::MAX),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            data,
+            "0x095ea7b3\
+             0000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488d\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        );
+    }
+
+    #[test]
+    fn test_encode_static_types() {
+        // Example from the Solidity ABI specification
+        let data =
+            encode_call("baz(uint32,bool)", &[Token::uint(69u32), Token::Bool(true)]).unwrap();
+
+        assert_eq!(
+            data,
+            "0xcdcd77c0\
+             0000000000000000000000000000000000000000000000000000000000000045\
+             0000000000000000000000000000000000000000000000000000000000000001"
+        );
+    }
+
+    #[test]
+    fn test_encode_dynamic_types() {
+        // Example from the Solidity ABI specification
+        let data = encode_call(
+            "sam(bytes,bool,uint256[])",
+            &[
+                Token::bytes(b"dave".to_vec()),
+                Token::Bool(true),
+                Token::Array(vec![Token::uint(1u8), Token::uint(2u8), Token::uint(3u8)]),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            data,
+            "0xa5643bf2\
+             0000000000000000000000000000000000000000000000000000000000000060\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             00000000000000000000000000000000000000000000000000000000000000a0\
+             0000000000000000000000000000000000000000000000000000000000000004\
+             6461766500000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000003\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000003"
+        );
+    }
+
+    #[test]
+    fn test_encode_string_and_int() {
+        let data = encode(
+            &[ParamType::String, ParamType::Int(8)],
+            &[Token::string("hi"), Token::int(-1)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            encode_hex(&data),
+            "0x\
+             0000000000000000000000000000000000000000000000000000000000000040\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             6869000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_encode_rejects_mismatches() {
+        // Wrong argument count
+        assert!(encode_call("transfer(address,uint256)", &[Token::Bool(true)]).is_err());
+        // Wrong type
+        assert!(encode_call("f(uint256)", &[Token::Bool(true)]).is_err());
+        // Out of range
+        assert!(encode_call("f(uint8)", &[Token::uint(256u16)]).is_err());
+        assert!(encode_call("f(int8)", &[Token::int(128)]).is_err());
+        assert!(encode_call("f(int8)", &[Token::int(-128)]).is_ok());
+        // Bad address
+        assert!(encode_call("f(address)", &[Token::address("0x1234")]).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! Event ABIs and log decoding
+
+use super::decode::decode;
+use super::token::{matching_paren, split_params, ParamType, Token};
+use crate::error::{Error, Result};
+use crate::utils::{decode_hex, encode_hex, keccak256};
+
+/// An event parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventParam {
+    /// Parameter name (may be empty)
+    pub name: String,
+
+    /// Parameter type
+    pub kind: ParamType,
+
+    /// Whether the parameter is stored in a topic
+    pub indexed: bool,
+}
+
+/// An event ABI
+///
+/// Parsed from a human-readable signature such as
+/// `Transfer(address indexed from, address indexed to, uint256 value)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEvent {
+    /// Event name
+    pub name: String,
+
+    /// Event parameters in declaration order
+    pub inputs: Vec<EventParam>,
+
+    /// Whether the event is anonymous (has no signature topic)
+    pub anonymous: bool,
+}
+
+/// An event decoded from a log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// Event name
+    pub name: String,
+
+    /// Parameter names and values in declaration order
+    pub params: Vec<(String, Token)>,
+}
+
+impl DecodedEvent {
+    /// Get a parameter value by name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Token> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl AbiEvent {
+    /// Parse an event signature
+    pub fn parse(signature: &str) -> Result<Self> {
+        let invalid = || Error::invalid_param(format!("Invalid event signature: {}", signature));
+
+        let signature = signature.trim();
+        let signature = signature.strip_prefix("event ").unwrap_or(signature);
+        let open = signature.find('(').ok_or_else(invalid)?;
+        let close = matching_paren(signature, open).ok_or_else(invalid)?;
+
+        let name = signature[..open].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(invalid());
+        }
+
+        let anonymous = match signature[close + 1..].trim().trim_end_matches(';') {
+            "" => false,
+            "anonymous" => true,
+            _ => return Err(invalid()),
+        };
+
+        let inputs = split_params(&signature[open + 1..close])?
+            .into_iter()
+            .map(|param| {
+                let mut words = param.split_whitespace();
+                let kind = ParamType::parse(words.next().unwrap_or_default())?;
+                let mut rest: Vec<&str> = words.collect();
+                let indexed = rest.first() == Some(&"indexed");
+                if indexed {
+                    rest.remove(0);
+                }
+                Ok(EventParam {
+                    name: rest.join(" "),
+                    kind,
+                    indexed,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            inputs,
+            anonymous,
+        })
+    }
+
+    /// Canonical signature (e.g., `Transfer(address,address,uint256)`)
+    #[must_use]
+    pub fn canonical(&self) -> String {
+        let types: Vec<ParamType> = self.inputs.iter().map(|p| p.kind.clone()).collect();
+        format!("{}{}", self.name, ParamType::Tuple(types))
+    }
+
+    /// Signature topic (`keccak256` of the canonical signature, `0x`-prefixed)
+    #[must_use]
+    pub fn topic0(&self) -> String {
+        encode_hex(&keccak256(self.canonical().as_bytes()))
+    }
+
+    /// Decode a log's topics and data against this event
+    ///
+    /// Indexed parameters of dynamic type (strings, bytes, arrays, tuples)
+    /// only store a hash, which is returned as [`Token::FixedBytes`].
+    pub fn decode_log(&self, topics: &[String], data: &str) -> Result<DecodedEvent> {
+        let skip = usize::from(!self.anonymous);
+        let indexed_count = self.inputs.iter().filter(|p| p.indexed).count();
+        if topics.len() != indexed_count + skip {
+            return Err(Error::invalid_param(format!(
+                "Log has {} topics, event {} expects {}",
+                topics.len(),
+                self.name,
+                indexed_count + skip
+            )));
+        }
+
+        let data = decode_hex(data)
+            .ok_or_else(|| Error::invalid_param(format!("Invalid hex log data: {}", data)))?;
+        let data_types: Vec<ParamType> = self
+            .inputs
+            .iter()
+            .filter(|p| !p.indexed)
+            .map(|p| p.kind.clone())
+            .collect();
+        let mut values = decode(&data_types, &data)?.into_iter();
+        let mut topics = topics[skip..].iter();
+
+        let params = self
+            .inputs
+            .iter()
+            .map(|param| {
+                let value = if param.indexed {
+                    let topic = topics.next().and_then(|t| decode_hex(t));
+                    let topic = topic
+                        .filter(|t| t.len() == 32)
+                        .ok_or_else(|| Error::invalid_param("Invalid log topic"))?;
+                    // Only value types are stored directly; others are hashed
+                    if param.kind.is_dynamic()
+                        || matches!(param.kind, ParamType::Tuple(_) | ParamType::FixedArray(..))
+                    {
+                        Token::FixedBytes(topic)
+                    } else {
+                        decode(std::slice::from_ref(&param.kind), &topic)?.remove(0)
+                    }
+                } else {
+                    values
+                        .next()
+                        .ok_or_else(|| Error::invalid_param("Missing log data value"))?
+                };
+                Ok((param.name.clone(), value))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(DecodedEvent {
+            name: self.name.clone(),
+            params,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFER_TOPIC: &str =
+        "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+    #[test]
+    fn test_event_parse() {
+        let event = AbiEvent::parse(
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        )
+        .unwrap();
+        assert_eq!(event.canonical(), "Transfer(address,address,uint256)");
+        assert_eq!(event.topic0(), TRANSFER_TOPIC);
+        assert!(event.inputs[0].indexed);
+        assert_eq!(event.inputs[2].name, "value");
+
+        assert!(AbiEvent::parse("Foo(uint256) anonymous").unwrap().anonymous);
+        assert!(AbiEvent::parse("Foo(uint256) bar").is_err());
+    }
+
+    #[test]
+    fn test_decode_log_topic_count_mismatch() {
+        let event = AbiEvent::parse("Transfer(address indexed, address indexed, uint256)").unwrap();
+        assert!(event
+            .decode_log(&[TRANSFER_TOPIC.to_string()], "0x")
+            .is_err());
+    }
+}
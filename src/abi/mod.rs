@@ -0,0 +1,33 @@
+//! Minimal ABI encoding and decoding helpers
+//!
+//! Build calldata from a human-readable function signature and a list of
+//! [`Token`] arguments, and decode return data, without pulling in a full
+//! Ethereum library.
+//!
+//! # Example
+//!
+//! ```
+//! use tndrly::abi::{encode_call, Token};
+//!
+//! let data = encode_call(
+//!     "transfer(address,uint256)",
+//!     &[
+//!         Token::address("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+//!         Token::uint(1_000_000u64),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! assert!(data.starts_with("0xa9059cbb"));
+//! ```
+
+mod decode;
+mod encode;
+mod event;
+mod token;
+
+pub use crate::U256;
+pub use decode::{decode, decode_output};
+pub use encode::{encode, encode_call, selector};
+pub use event::{AbiEvent, DecodedEvent, EventParam};
+pub use token::{Function, ParamType, Token};
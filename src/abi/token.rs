@@ -0,0 +1,444 @@
+//! ABI types and values
+
+use crate::error::{Error, Result};
+use crate::U256;
+use std::fmt;
+use std::str::FromStr;
+
+/// An ABI value
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Token {
+    /// 20-byte address (`0x`-prefixed hex)
+    Address(String),
+    /// Unsigned integer (`uint8`..`uint256`)
+    Uint(U256),
+    /// Signed integer in two's complement (`int8`..`int256`)
+    Int(U256),
+    /// Boolean
+    Bool(bool),
+    /// Dynamic byte array
+    Bytes(Vec<u8>),
+    /// Fixed-size byte array (`bytes1`..`bytes32`)
+    FixedBytes(Vec<u8>),
+    /// UTF-8 string
+    String(String),
+    /// Dynamic array (`T[]`)
+    Array(Vec<Token>),
+    /// Fixed-size array (`T[N]`)
+    FixedArray(Vec<Token>),
+    /// Tuple (`(T1,T2,...)`)
+    Tuple(Vec<Token>),
+}
+
+impl Token {
+    /// Create an address token
+    pub fn address(address: impl Into<String>) -> Self {
+        Self::Address(address.into())
+    }
+
+    /// Create an unsigned integer token
+    pub fn uint(value: impl Into<U256>) -> Self {
+        Self::Uint(value.into())
+    }
+
+    /// Create a signed integer token
+    pub fn int(value: i128) -> Self {
+        Self::Int(U256::from_i128(value))
+    }
+
+    /// Create a string token
+    pub fn string(value: impl Into<String>) -> Self {
+        Self::String(value.into())
+    }
+
+    /// Create a dynamic bytes token
+    pub fn bytes(value: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes(value.into())
+    }
+
+    /// Get the value of a `Uint` token
+    #[must_use]
+    pub fn as_uint(&self) -> Option<U256> {
+        match self {
+            Self::Uint(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the value of an `Address` token
+    #[must_use]
+    pub fn as_address(&self) -> Option<&str> {
+        match self {
+            Self::Address(address) => Some(address),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a `Bool` token
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a `String` token
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for Token {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<U256> for Token {
+    fn from(value: U256) -> Self {
+        Self::Uint(value)
+    }
+}
+
+/// An ABI type
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParamType {
+    /// `address`
+    Address,
+    /// `uintN`
+    Uint(usize),
+    /// `intN`
+    Int(usize),
+    /// `bool`
+    Bool,
+    /// `bytes`
+    Bytes,
+    /// `bytesN`
+    FixedBytes(usize),
+    /// `string`
+    String,
+    /// `T[]`
+    Array(Box<ParamType>),
+    /// `T[N]`
+    FixedArray(Box<ParamType>, usize),
+    /// `(T1,T2,...)`
+    Tuple(Vec<ParamType>),
+}
+
+impl ParamType {
+    /// Parse a single type (e.g., `uint256`, `address[]`, `(uint256,bool)`)
+    pub fn parse(value: &str) -> Result<Self> {
+        let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        Self::parse_compact(&value)
+    }
+
+    fn parse_compact(value: &str) -> Result<Self> {
+        let invalid = || Error::invalid_param(format!("Invalid ABI type: {}", value));
+
+        if let Some(inner) = value.strip_suffix(']') {
+            let open = inner.rfind('[').ok_or_else(invalid)?;
+            let element = Box::new(Self::parse_compact(&inner[..open])?);
+            let size = &inner[open + 1..];
+            return if size.is_empty() {
+                Ok(Self::Array(element))
+            } else {
+                Ok(Self::FixedArray(
+                    element,
+                    size.parse().map_err(|_| invalid())?,
+                ))
+            };
+        }
+
+        if let Some(inner) = value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            return split_params(inner)?
+                .into_iter()
+                .map(Self::parse_compact)
+                .collect::<Result<Vec<_>>>()
+                .map(Self::Tuple);
+        }
+
+        let bits = |digits: &str, default: usize, max: usize, step: usize| -> Result<usize> {
+            if digits.is_empty() {
+                return Ok(default);
+            }
+            digits
+                .parse::<usize>()
+                .ok()
+                .filter(|n| *n > 0 && *n <= max && n % step == 0)
+                .ok_or_else(invalid)
+        };
+
+        match value {
+            "address" => Ok(Self::Address),
+            "bool" => Ok(Self::Bool),
+            "bytes" => Ok(Self::Bytes),
+            "string" => Ok(Self::String),
+            _ => {
+                if let Some(digits) = value.strip_prefix("uint") {
+                    bits(digits, 256, 256, 8).map(Self::Uint)
+                } else if let Some(digits) = value.strip_prefix("int") {
+                    bits(digits, 256, 256, 8).map(Self::Int)
+                } else if let Some(digits) = value.strip_prefix("bytes") {
+                    bits(digits, 32, 32, 1).map(Self::FixedBytes)
+                } else {
+                    Err(invalid())
+                }
+            }
+        }
+    }
+
+    /// Whether the type is dynamically sized
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            Self::Tuple(params) => params.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Size of the head encoding in bytes
+    pub(crate) fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            Self::FixedArray(inner, size) => inner.head_size().saturating_mul(*size),
+            Self::Tuple(params) => params
+                .iter()
+                .fold(0, |total, param| total.saturating_add(param.head_size())),
+            _ => 32,
+        }
+    }
+}
+
+impl fmt::Display for ParamType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Uint(bits) => write!(f, "uint{}", bits),
+            Self::Int(bits) => write!(f, "int{}", bits),
+            Self::Bool => f.write_str("bool"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::FixedBytes(size) => write!(f, "bytes{}", size),
+            Self::String => f.write_str("string"),
+            Self::Array(inner) => write!(f, "{}[]", inner),
+            Self::FixedArray(inner, size) => write!(f, "{}[{}]", inner, size),
+            Self::Tuple(params) => {
+                f.write_str("(")?;
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", param)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for ParamType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+/// A parsed function signature
+///
+/// Accepts `name(type1,type2)`, optionally with parameter names
+/// (`transfer(address to, uint256 amount)`) and return types, either as
+/// `balanceOf(address)(uint256)` or `balanceOf(address) returns (uint256)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    /// Function name
+    pub name: String,
+
+    /// Input parameter types
+    pub inputs: Vec<ParamType>,
+
+    /// Return types (empty if not declared)
+    pub outputs: Vec<ParamType>,
+}
+
+impl Function {
+    /// Parse a function signature
+    pub fn parse(signature: &str) -> Result<Self> {
+        let invalid = || Error::invalid_param(format!("Invalid function signature: {}", signature));
+
+        let signature = signature.trim();
+        let signature = signature.strip_prefix("function ").unwrap_or(signature);
+        let open = signature.find('(').ok_or_else(invalid)?;
+        let close = matching_paren(signature, open).ok_or_else(invalid)?;
+
+        let name = signature[..open].trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        {
+            return Err(invalid());
+        }
+
+        // Skip modifiers such as "external view" before the return types
+        let rest = signature[close + 1..].trim();
+        let rest = match rest.find("returns") {
+            Some(index) => rest[index + "returns".len()..].trim(),
+            None if rest.starts_with('(') => rest,
+            None => "",
+        };
+        let outputs = if rest.is_empty() {
+            Vec::new()
+        } else {
+            match rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+                Some(inner) if matching_paren(rest, 0) == Some(rest.len() - 1) => {
+                    parse_param_list(inner)?
+                }
+                _ => return Err(invalid()),
+            }
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            inputs: parse_param_list(&signature[open + 1..close])?,
+            outputs,
+        })
+    }
+
+    /// Canonical signature used for the selector (e.g., `transfer(address,uint256)`)
+    #[must_use]
+    pub fn canonical(&self) -> String {
+        format!("{}{}", self.name, ParamType::Tuple(self.inputs.clone()))
+    }
+}
+
+/// Find the index of the parenthesis closing the one at `open`
+pub(crate) fn matching_paren(value: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in value.char_indices().skip_while(|(i, _)| *i < open) {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse a comma-separated parameter list, dropping parameter names
+pub(crate) fn parse_param_list(value: &str) -> Result<Vec<ParamType>> {
+    split_params(value)?
+        .into_iter()
+        .map(|param| {
+            // Drop an optional name and data location (e.g. "uint256 amount", "bytes memory data")
+            let ty = param.split_whitespace().next().unwrap_or_default();
+            ParamType::parse(ty)
+        })
+        .collect()
+}
+
+/// Split on top-level commas
+pub(crate) fn split_params(value: &str) -> Result<Vec<&str>> {
+    if value.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(value[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            break;
+        }
+    }
+    if depth != 0 {
+        return Err(Error::invalid_param(format!(
+            "Unbalanced parentheses in ABI types: {}",
+            value
+        )));
+    }
+    parts.push(value[start..].trim());
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_param_type_parse() {
+        assert_eq!(ParamType::parse("uint").unwrap(), ParamType::Uint(256));
+        assert_eq!(ParamType::parse("int8").unwrap(), ParamType::Int(8));
+        assert_eq!(
+            ParamType::parse("bytes32").unwrap(),
+            ParamType::FixedBytes(32)
+        );
+        assert_eq!(
+            ParamType::parse("address[]").unwrap(),
+            ParamType::Array(Box::new(ParamType::Address))
+        );
+        assert_eq!(
+            ParamType::parse("(uint256, bool)[2]").unwrap().to_string(),
+            "(uint256,bool)[2]"
+        );
+        assert!(ParamType::parse("uint7").is_err());
+        assert!(ParamType::parse("bytes33").is_err());
+        assert!(ParamType::parse("foo").is_err());
+    }
+
+    #[test]
+    fn test_function_parse() {
+        let f = Function::parse("transfer(address to, uint256 amount)").unwrap();
+        assert_eq!(f.name, "transfer");
+        assert_eq!(f.canonical(), "transfer(address,uint256)");
+
+        let f = Function::parse("totalSupply()").unwrap();
+        assert!(f.inputs.is_empty());
+
+        let f = Function::parse("swap((address,uint256)[],bytes)").unwrap();
+        assert_eq!(f.canonical(), "swap((address,uint256)[],bytes)");
+
+        let f = Function::parse("balanceOf(address)(uint256)").unwrap();
+        assert_eq!(f.outputs, vec![ParamType::Uint(256)]);
+
+        let f = Function::parse(
+            "function getReserves() external view returns (uint112, uint112, uint32)",
+        )
+        .unwrap();
+        assert_eq!(f.name, "getReserves");
+        assert_eq!(
+            f.outputs,
+            vec![
+                ParamType::Uint(112),
+                ParamType::Uint(112),
+                ParamType::Uint(32)
+            ]
+        );
+
+        assert!(Function::parse("transfer(address").is_err());
+        assert!(Function::parse("f()(uint256").is_err());
+        assert!(Function::parse("(address)").is_err());
+    }
+}
@@ -1,18 +1,24 @@
 //! Web3 Actions API operations
 
 use super::types::*;
-use crate::client::{encode_path_segment, Client};
+use crate::client::{encode_path_segment, ApiVersion, Client};
 use crate::error::Result;
+use std::borrow::Cow;
 
 /// Web3 Actions API client
 pub struct ActionsApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> ActionsApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new Actions API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// Create a new Web3 Action
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 /// Trigger type for a Web3 Action
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ActionTrigger {
@@ -57,6 +58,7 @@ impl std::str::FromStr for ActionTrigger {
 
 /// Execution type for a Web3 Action
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ActionExecution {
@@ -97,6 +99,7 @@ impl std::str::FromStr for ActionExecution {
 
 /// Request to create a Web3 Action
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateActionRequest {
     /// Action name
     pub name: String,
@@ -217,6 +220,7 @@ impl CreateActionRequest {
 
 /// Trigger configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TriggerConfig {
     /// Alert ID (for Alert trigger)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,10 +285,12 @@ impl TriggerConfig {
 /// be sent to the Tenderly API. Be careful not to serialize `ActionSecret` or any
 /// containing struct for logging purposes, as this will expose the secret.
 #[derive(Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ActionSecret {
     /// Secret name
     pub name: String,
     /// Secret value (protected)
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     value: SecretString,
 }
 
@@ -383,20 +389,10 @@ pub struct Action {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListActionsResponse {
     /// List of actions (handles null as empty)
-    #[serde(default, deserialize_with = "deserialize_null_default")]
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
     pub actions: Vec<Action>,
 }
 
-/// Deserialize null as default value
-fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
-where
-    D: serde::Deserializer<'de>,
-    T: Default + Deserialize<'de>,
-{
-    let opt = Option::deserialize(deserializer)?;
-    Ok(opt.unwrap_or_default())
-}
-
 /// Action execution log entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionLog {
@@ -488,6 +484,7 @@ pub struct ListActionLogsResponse {
 
 /// Request to invoke an action manually
 #[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InvokeActionRequest {
     /// Custom payload to pass to the action
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -524,6 +521,7 @@ pub struct InvokeActionResponse {
 
 /// Request to stop or resume multiple actions
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StopResumeActionsRequest {
     /// Action IDs to stop/resume. Empty = all actions.
     pub actions: Vec<String>,
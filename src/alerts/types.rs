@@ -32,6 +32,7 @@ use serde::{Deserialize, Serialize};
 
 /// Alert trigger types
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum AlertType {
@@ -112,6 +113,7 @@ impl std::str::FromStr for AlertType {
 
 /// Alert target type
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum AlertTarget {
@@ -160,6 +162,7 @@ impl std::str::FromStr for AlertTarget {
 
 /// Alert destination type
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum DestinationType {
@@ -220,6 +223,7 @@ impl std::str::FromStr for DestinationType {
 
 /// Request to create an alert
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateAlertRequest {
     /// Alert name
     pub name: String,
@@ -316,6 +320,7 @@ impl CreateAlertRequest {
 
 /// Alert parameters (type-specific configuration)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AlertParameters {
     /// Function signature (for FunctionCall type)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -443,6 +448,7 @@ pub struct ListAlertsResponse {
 
 /// Request to create a webhook destination
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateWebhookRequest {
     /// Webhook name
     pub name: String,
@@ -508,6 +514,7 @@ pub struct ListWebhooksResponse {
 
 /// Request to add a destination to an alert
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AddDestinationRequest {
     /// Destination type
     pub destination_type: DestinationType,
@@ -627,6 +634,7 @@ pub struct AlertHistoryResponse {
 
 /// Request to send a test alert
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TestAlertRequest {
     /// Alert ID to test
     pub alert_id: String,
@@ -5,7 +5,9 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 
 /// URL-encode a path segment to prevent injection
@@ -16,12 +18,173 @@ pub fn encode_path_segment(segment: &str) -> String {
 /// Base URL for the Tenderly API
 pub const API_BASE_URL: &str = "https://api.tenderly.co/api/v1";
 
+/// Tenderly API host (without version prefix)
+pub const API_HOST: &str = "https://api.tenderly.co";
+
+/// Tenderly dashboard host
+pub const DASHBOARD_URL: &str = "https://dashboard.tenderly.co";
+
+/// Tenderly API version used in request paths
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// `/api/v1`
+    #[default]
+    V1,
+    /// `/api/v2`
+    V2,
+}
+
+impl ApiVersion {
+    /// Get the string representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+        }
+    }
+
+    /// Path prefix for this version (e.g., `/api/v1`)
+    #[must_use]
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::V1 => "/api/v1",
+            Self::V2 => "/api/v2",
+        }
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for ApiVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().trim_start_matches("/api/") {
+            "v1" | "1" => Ok(Self::V1),
+            "v2" | "2" => Ok(Self::V2),
+            _ => Err(format!("Invalid API version: {}. Expected: v1 or v2", s)),
+        }
+    }
+}
+
 /// Default request timeout in seconds
 pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
 /// Default connect timeout in seconds
 pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
 
+/// Retry policy for transient failures
+///
+/// Retries rate-limited (429) and server error (500/502/503/504) responses
+/// as well as connect and timeout errors, with exponential backoff. A
+/// `Retry-After` header takes precedence over the computed backoff.
+///
+/// Only idempotent methods (GET, PUT, DELETE) are retried by default.
+/// Retrying POST/PATCH can repeat side effects: a retried
+/// `/simulate` with `save = true` may store the simulation twice. Opt in
+/// with [`retry_post`](Self::retry_post) if that is acceptable.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tndrly::{Config, RetryPolicy};
+///
+/// let config = Config::new("key", "account", "project")
+///     .with_retry(RetryPolicy::default().max_retries(5).initial_backoff(Duration::from_secs(1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each subsequent retry
+    pub initial_backoff: Duration,
+    /// Upper bound for any single delay
+    pub max_backoff: Duration,
+    /// Also retry non-idempotent methods (POST, PATCH)
+    pub retry_post: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retry_post: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Set the maximum number of retries
+    #[must_use]
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Set the delay before the first retry
+    #[must_use]
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper bound for any single delay
+    #[must_use]
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Also retry POST and PATCH requests
+    ///
+    /// These are not idempotent, so a retry after a lost response can
+    /// repeat the operation (e.g., saving a simulation twice).
+    #[must_use]
+    pub fn retry_post(mut self, enabled: bool) -> Self {
+        self.retry_post = enabled;
+        self
+    }
+
+    /// Check whether requests with this method may be retried
+    fn allows(&self, method: &reqwest::Method) -> bool {
+        use reqwest::Method;
+        match *method {
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS => true,
+            _ => self.retry_post,
+        }
+    }
+
+    /// Delay before retry number `attempt` (zero-based)
+    fn backoff(&self, attempt: u32, retry_after: Option<u64>) -> Duration {
+        let delay = match retry_after {
+            Some(secs) => Duration::from_secs(secs),
+            None => self
+                .initial_backoff
+                .saturating_mul(2u32.saturating_pow(attempt)),
+        };
+        delay.min(self.max_backoff)
+    }
+}
+
 /// Configuration for the Tenderly client
 #[derive(Clone)]
 pub struct Config {
@@ -37,6 +200,29 @@ pub struct Config {
     pub timeout: Duration,
     /// Connect timeout
     pub connect_timeout: Duration,
+    /// API version for requests made directly through [`Client`]
+    pub api_version: ApiVersion,
+    /// Log request bodies in tracing events (requires the `tracing` feature)
+    pub log_bodies: bool,
+    /// Retry policy for transient failures (no retries by default)
+    pub retry: RetryPolicy,
+    /// Reject mixed-case addresses with an invalid EIP-55 checksum
+    pub strict_checksums: bool,
+    /// Hooks run around every request, in registration order
+    pub middleware: Vec<Arc<dyn Middleware>>,
+    /// Gas limit for simulations that don't set one (`None` sends no gas)
+    pub default_gas: Option<u64>,
+    /// Overrides of `default_gas` for individual networks, keyed by network ID
+    ///
+    /// Empty by default, so every network uses `default_gas` until one is set.
+    pub network_default_gas: HashMap<String, u64>,
+    /// Names for addresses, keyed by lowercase address, used when rendering traces
+    pub address_book: HashMap<String, String>,
+    /// Node RPC URLs for parent networks, keyed by network ID
+    pub node_rpc_urls: HashMap<String, String>,
+    /// Cassette that records or replays every request
+    #[cfg(feature = "vcr")]
+    pub vcr: Option<Arc<crate::vcr::Cassette>>,
 }
 
 impl Config {
@@ -53,6 +239,17 @@ impl Config {
             base_url: None,
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             connect_timeout: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            api_version: ApiVersion::default(),
+            log_bodies: false,
+            retry: RetryPolicy::none(),
+            strict_checksums: false,
+            middleware: Vec::new(),
+            default_gas: Some(crate::simulation::DEFAULT_GAS_LIMIT),
+            network_default_gas: HashMap::new(),
+            address_book: HashMap::new(),
+            node_rpc_urls: HashMap::new(),
+            #[cfg(feature = "vcr")]
+            vcr: None,
         }
     }
 
@@ -62,15 +259,24 @@ impl Config {
     /// - `TENDERLY_ACCESS_KEY` (required)
     /// - `TENDERLY_ACCOUNT` (required)
     /// - `TENDERLY_PROJECT` (required)
+    /// - `TENDERLY_BASE_URL` (optional, overrides the API base URL)
+    ///
+    /// Returns [`Error::MissingEnv`] naming the first required variable that
+    /// is not set.
     pub fn from_env() -> Result<Self> {
-        let access_key = std::env::var("TENDERLY_ACCESS_KEY")
-            .map_err(|_| Error::auth("TENDERLY_ACCESS_KEY environment variable not set"))?;
-        let account = std::env::var("TENDERLY_ACCOUNT")
-            .map_err(|_| Error::auth("TENDERLY_ACCOUNT environment variable not set"))?;
-        let project = std::env::var("TENDERLY_PROJECT")
-            .map_err(|_| Error::auth("TENDERLY_PROJECT environment variable not set"))?;
+        fn required(var: &str) -> Result<String> {
+            std::env::var(var).map_err(|_| Error::missing_env(var))
+        }
 
-        Ok(Self::new(access_key, account, project))
+        let config = Self::new(
+            required("TENDERLY_ACCESS_KEY")?,
+            required("TENDERLY_ACCOUNT")?,
+            required("TENDERLY_PROJECT")?,
+        );
+        Ok(match std::env::var("TENDERLY_BASE_URL") {
+            Ok(url) if !url.is_empty() => config.with_base_url(url),
+            _ => config,
+        })
     }
 
     /// Set a custom base URL (useful for testing)
@@ -90,6 +296,20 @@ impl Config {
         self
     }
 
+    /// Set the account slug
+    #[must_use]
+    pub fn with_account(mut self, account: impl Into<String>) -> Self {
+        self.account = account.into();
+        self
+    }
+
+    /// Set the project slug
+    #[must_use]
+    pub fn with_project(mut self, project: impl Into<String>) -> Self {
+        self.project = project.into();
+        self
+    }
+
     /// Set the request timeout
     #[must_use]
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
@@ -104,30 +324,327 @@ impl Config {
         self
     }
 
+    /// Set the API version for requests made directly through [`Client`]
+    ///
+    /// API modules (e.g., [`Client::vnets`]) always use the version their
+    /// endpoints are served from; see
+    /// [`VNetsApi::API_VERSION`](crate::vnets::VNetsApi::API_VERSION).
+    #[must_use]
+    pub fn with_api_version(mut self, version: ApiVersion) -> Self {
+        self.api_version = version;
+        self
+    }
+
+    /// Include request bodies in tracing events
+    ///
+    /// Off by default since bodies may contain sensitive data. Only has an
+    /// effect with the `tracing` feature enabled.
+    #[must_use]
+    pub fn with_log_bodies(mut self, enabled: bool) -> Self {
+        self.log_bodies = enabled;
+        self
+    }
+
+    /// Set the retry policy for transient failures
+    ///
+    /// See [`RetryPolicy`] for which requests are retried.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Reject mixed-case addresses whose EIP-55 checksum doesn't match
+    ///
+    /// Addresses in requests are always lowercased before sending; strict
+    /// mode additionally catches typos in checksummed input. Requires the
+    /// `abi` or `raw-tx` feature to compute checksums.
+    #[must_use]
+    pub fn with_strict_checksums(mut self, strict: bool) -> Self {
+        self.strict_checksums = strict;
+        self
+    }
+
+    /// Register a middleware hook
+    ///
+    /// May be called repeatedly to build a chain; hooks run in the order
+    /// they were added.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Set the gas limit for simulations that don't set one
+    ///
+    /// Defaults to [`DEFAULT_GAS_LIMIT`](crate::simulation::DEFAULT_GAS_LIMIT);
+    /// pass `None` to send such simulations without gas.
+    #[must_use]
+    pub fn with_default_gas(mut self, gas: impl Into<Option<u64>>) -> Self {
+        self.default_gas = gas.into();
+        self
+    }
+
+    /// Set the default gas limit for one network
+    ///
+    /// Takes precedence over [`with_default_gas`](Self::with_default_gas).
+    #[must_use]
+    pub fn with_network_default_gas(mut self, network_id: impl Into<String>, gas: u64) -> Self {
+        self.network_default_gas.insert(network_id.into(), gas);
+        self
+    }
+
+    /// Name addresses in rendered traces
+    ///
+    /// Merged into any labels already set; addresses are matched
+    /// case-insensitively. See [`TraceResponse::pretty`](crate::vnets::TraceResponse::pretty).
+    #[must_use]
+    pub fn with_address_book(mut self, book: HashMap<String, String>) -> Self {
+        for (address, name) in book {
+            self = self.with_address_label(address, name);
+        }
+        self
+    }
+
+    /// Name a single address in rendered traces
+    #[must_use]
+    pub fn with_address_label(mut self, address: impl AsRef<str>, name: impl Into<String>) -> Self {
+        self.address_book
+            .insert(address.as_ref().to_ascii_lowercase(), name.into());
+        self
+    }
+
+    /// Set the Node RPC URL used to read a parent network's head
+    ///
+    /// Used by [`VNetsApi::latest_block`](crate::vnets::VNetsApi::latest_block);
+    /// networks without one use Tenderly's public gateway.
+    #[must_use]
+    pub fn with_node_rpc_url(
+        mut self,
+        network_id: impl Into<String>,
+        url: impl Into<String>,
+    ) -> Self {
+        self.node_rpc_urls.insert(network_id.into(), url.into());
+        self
+    }
+
+    /// Record or replay every request with a cassette
+    ///
+    /// Middleware still runs; in replay mode retries are skipped since
+    /// nothing is sent. See [`vcr`](crate::vcr).
+    #[cfg(feature = "vcr")]
+    #[must_use]
+    pub fn with_vcr(mut self, cassette: Arc<crate::vcr::Cassette>) -> Self {
+        self.vcr = Some(cassette);
+        self
+    }
+
+    /// Default gas limit for simulations on `network_id`
+    pub fn default_gas_for(&self, network_id: &str) -> Option<u64> {
+        self.network_default_gas
+            .get(network_id)
+            .copied()
+            .or(self.default_gas)
+    }
+
     /// Get the base URL
+    ///
+    /// Use [`base_url_for`](Self::base_url_for) for a specific API version.
     pub fn base_url(&self) -> &str {
         self.base_url.as_deref().unwrap_or(API_BASE_URL)
     }
+
+    /// Get the base URL for a specific API version
+    ///
+    /// A custom base URL ending in a version prefix (e.g., `.../api/v1`) has
+    /// that prefix swapped; other custom base URLs are used as-is.
+    pub fn base_url_for(&self, version: ApiVersion) -> String {
+        match &self.base_url {
+            None => format!("{}{}", API_HOST, version.prefix()),
+            Some(url) => [ApiVersion::V1, ApiVersion::V2]
+                .iter()
+                .find_map(|v| url.strip_suffix(v.prefix()))
+                .map(|host| format!("{}{}", host, version.prefix()))
+                .unwrap_or_else(|| url.clone()),
+        }
+    }
 }
 
 impl std::fmt::Debug for Config {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Config")
+        let mut debug = f.debug_struct("Config");
+        debug
             .field("access_key", &"[REDACTED]")
             .field("account", &self.account)
             .field("project", &self.project)
             .field("base_url", &self.base_url)
             .field("timeout", &self.timeout)
             .field("connect_timeout", &self.connect_timeout)
-            .finish()
+            .field("api_version", &self.api_version)
+            .field("log_bodies", &self.log_bodies)
+            .field("retry", &self.retry)
+            .field("strict_checksums", &self.strict_checksums)
+            .field("middleware", &self.middleware.len())
+            .field("default_gas", &self.default_gas)
+            .field("network_default_gas", &self.network_default_gas)
+            .field("address_book", &self.address_book)
+            // Node RPC URLs embed access keys
+            .field(
+                "node_rpc_urls",
+                &self.node_rpc_urls.keys().collect::<Vec<_>>(),
+            );
+        #[cfg(feature = "vcr")]
+        debug.field("vcr", &self.vcr.as_ref().map(|c| c.path()));
+        debug.finish()
+    }
+}
+
+/// A request built but not sent (dry run)
+///
+/// Produced by [`Client::prepare`]; render it with [`Client::to_curl`] to
+/// reproduce the call outside of Rust.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedRequest {
+    /// HTTP method (e.g., "POST")
+    pub method: String,
+    /// Full request URL
+    pub url: String,
+    /// JSON body, if any
+    pub body: Option<serde_json::Value>,
+    /// Request headers (empty for dry runs; filled in for [`Middleware`])
+    pub headers: HeaderMap,
+}
+
+/// Hook run around every API request
+///
+/// Register with [`Config::with_middleware`]. Both methods default to
+/// no-ops, so implement only what you need.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use tndrly::{Middleware, PreparedRequest};
+///
+/// #[derive(Default)]
+/// struct CountRequests(AtomicUsize);
+///
+/// impl Middleware for CountRequests {
+///     fn on_request(&self, _request: &mut PreparedRequest) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Inspect or modify a request before it is sent
+    ///
+    /// Runs once per call, before any retries. Changes to the method, URL,
+    /// headers or body are applied to the outgoing request.
+    fn on_request(&self, request: &mut PreparedRequest) {
+        let _ = request;
+    }
+
+    /// Inspect the final response before it is decoded
+    fn on_response(&self, response: &reqwest::Response) {
+        let _ = response;
+    }
+}
+
+/// Opaque token pointing at the next page of a cursor-paginated endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Wrap a cursor token
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    /// Get the raw token
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// One page of a cursor-paginated list
+///
+/// Newer Tenderly endpoints return `next_cursor` instead of page numbers;
+/// a missing, null, or empty cursor marks the last page.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CursorPage<T> {
+    /// Items on this page
+    #[serde(alias = "data", alias = "results", default = "Vec::new")]
+    pub items: Vec<T>,
+
+    /// Cursor for the next page
+    #[serde(alias = "nextCursor", default, deserialize_with = "deserialize_cursor")]
+    pub next_cursor: Option<Cursor>,
+}
+
+/// Treat an empty cursor string as the end of the list
+fn deserialize_cursor<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Cursor>, D::Error> {
+    let token: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(token.filter(|t| !t.is_empty()).map(Cursor))
+}
+
+/// API usage reported in response headers
+///
+/// Read from Tenderly's `X-Tdly-Limit`, `X-Tdly-Remaining` and
+/// `X-Tdly-Reset-Timestamp` headers, falling back to the standard
+/// `X-RateLimit-*` headers. See [`Client::last_usage`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Usage {
+    /// Request quota for the current window
+    pub limit: Option<u64>,
+    /// Requests remaining in the current window
+    pub remaining: Option<u64>,
+    /// Unix timestamp (seconds) when the window resets
+    pub reset: Option<u64>,
+}
+
+impl Usage {
+    /// Parse usage headers, returning `None` if none are present
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |names: [&str; 2]| {
+            names.iter().find_map(|name| {
+                headers
+                    .get(*name)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|s| s.trim().parse::<u64>().ok())
+            })
+        };
+        let usage = Self {
+            limit: header(["x-tdly-limit", "x-ratelimit-limit"]),
+            remaining: header(["x-tdly-remaining", "x-ratelimit-remaining"]),
+            reset: header(["x-tdly-reset-timestamp", "x-ratelimit-reset"]),
+        };
+        (usage != Self::default()).then_some(usage)
     }
 }
 
 /// The main Tenderly API client
+///
+/// Cloning is cheap: clones share the configuration (including the retry
+/// policy), the HTTP connection pool and the [`last_usage`](Self::last_usage)
+/// state. Create one client and clone it rather than calling
+/// [`Client::new`] per request, which starts a fresh pool each time.
 #[derive(Clone)]
 pub struct Client {
     config: Arc<Config>,
     http: reqwest::Client,
+    version: Option<ApiVersion>,
+    usage: Arc<Mutex<Option<Usage>>>,
 }
 
 impl Client {
@@ -142,10 +659,14 @@ impl Client {
         Ok(Self {
             config: Arc::new(config),
             http,
+            version: None,
+            usage: Arc::default(),
         })
     }
 
     /// Create a client from environment variables
+    ///
+    /// See [`Config::from_env`] for the variables read.
     pub fn from_env() -> Result<Self> {
         Self::new(Config::from_env()?)
     }
@@ -155,6 +676,84 @@ impl Client {
         &self.config
     }
 
+    /// Get the underlying HTTP client
+    ///
+    /// Useful for calling endpoints this crate does not wrap yet, reusing the
+    /// configured timeouts and connection pool. Requests sent this way bypass
+    /// the crate's error mapping, retry and rate-limit handling.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tndrly::{Client, Config};
+    ///
+    /// let client = Client::new(Config::new("key", "account", "project"))?;
+    ///
+    /// let body: serde_json::Value = client
+    ///     .http()
+    ///     .get(client.url("/some-new-endpoint"))
+    ///     .header("X-Access-Key", client.access_key())
+    ///     .send()
+    ///     .await?
+    ///     .json()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn http(&self) -> &reqwest::Client {
+        &self.http
+    }
+
+    /// Build a project-scoped request without sending it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tndrly::{Client, Config};
+    ///
+    /// let client = Client::new(Config::new("key", "account", "project")).unwrap();
+    /// let prepared = client
+    ///     .prepare("POST", "/simulate", Some(&serde_json::json!({"network_id": "1"})))
+    ///     .unwrap();
+    ///
+    /// assert!(client.to_curl(&prepared).contains("-X POST"));
+    /// ```
+    pub fn prepare<B: serde::Serialize>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<PreparedRequest> {
+        Ok(PreparedRequest {
+            method: method.to_uppercase(),
+            url: self.url(path),
+            body: body.map(serde_json::to_value).transpose()?,
+            headers: HeaderMap::new(),
+        })
+    }
+
+    /// Render a prepared request as a runnable curl command
+    ///
+    /// The access key is replaced with `$TENDERLY_ACCESS_KEY`, so the output
+    /// is safe to paste into bug reports. Use
+    /// [`to_curl_with_key`](Self::to_curl_with_key) to embed the real key.
+    pub fn to_curl(&self, prepared: &PreparedRequest) -> String {
+        render_curl(prepared, None)
+    }
+
+    /// Render a prepared request as a curl command including the real access key
+    pub fn to_curl_with_key(&self, prepared: &PreparedRequest) -> String {
+        render_curl(prepared, Some(self.access_key()))
+    }
+
+    /// Get the API access key
+    ///
+    /// Sent as the `X-Access-Key` header. Avoid logging this value.
+    pub fn access_key(&self) -> &str {
+        self.config.access_key.expose_secret()
+    }
+
     /// Get the account slug
     pub fn account(&self) -> &str {
         &self.config.account
@@ -165,31 +764,242 @@ impl Client {
         &self.config.project
     }
 
-    /// Build the full URL for an API endpoint
-    pub fn url(&self, path: &str) -> String {
+    /// Get a client that sends requests to a specific API version
+    ///
+    /// Shares the underlying connection pool. Used by endpoints that only
+    /// exist on one version, regardless of the configured default.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let v2 = client.at_api_version(ApiVersion::V2);
+    /// let body: serde_json::Value = v2.get("/vnets").await?;
+    /// ```
+    #[must_use]
+    pub fn at_api_version(&self, version: ApiVersion) -> Self {
+        Self {
+            config: Arc::clone(&self.config),
+            http: self.http.clone(),
+            version: Some(version),
+            usage: Arc::clone(&self.usage),
+        }
+    }
+
+    /// Borrow this client if it already uses `version`, otherwise pin a clone
+    pub(crate) fn pinned(&self, version: ApiVersion) -> Cow<'_, Self> {
+        if self.api_version() == version {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.at_api_version(version))
+        }
+    }
+
+    /// Get the usage headers from the most recent response that had them
+    ///
+    /// Shared across clones of this client. Returns `None` until a response
+    /// with usage headers has been received.
+    pub fn last_usage(&self) -> Option<Usage> {
+        *self.usage.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Remember usage headers from a response, if present
+    fn record_usage(&self, response: &reqwest::Response) {
+        if let Some(usage) = Usage::from_headers(response.headers()) {
+            *self.usage.lock().unwrap_or_else(PoisonError::into_inner) = Some(usage);
+        }
+    }
+
+    /// Get the API version used for requests from this client
+    pub fn api_version(&self) -> ApiVersion {
+        self.version.unwrap_or(self.config.api_version)
+    }
+
+    /// Get the base URL for requests from this client
+    fn base_url(&self) -> String {
+        self.config.base_url_for(self.api_version())
+    }
+
+    /// Build a project-scoped path (`/account/{account}/project/{project}{suffix}`)
+    ///
+    /// The account and project slugs are URL-encoded.
+    pub fn project_path(&self, suffix: &str) -> String {
         format!(
-            "{}/account/{}/project/{}{}",
-            self.config.base_url(),
-            encode_path_segment(&self.config.account),
+            "{}/project/{}{}",
+            self.account_path(""),
             encode_path_segment(&self.config.project),
-            path
+            suffix
         )
     }
 
+    /// Build an account-scoped path (`/account/{account}{suffix}`)
+    ///
+    /// The account slug is URL-encoded.
+    pub fn account_path(&self, suffix: &str) -> String {
+        format!(
+            "/account/{}{}",
+            encode_path_segment(&self.config.account),
+            suffix
+        )
+    }
+
+    /// Build the full URL for an API endpoint
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url(), self.project_path(path))
+    }
+
     /// Build headers for API requests
     fn headers(&self) -> Result<HeaderMap> {
         let mut headers = HeaderMap::new();
-        let access_key = HeaderValue::from_str(self.config.access_key.expose_secret())
+        let mut access_key = HeaderValue::from_str(self.config.access_key.expose_secret())
             .map_err(|_| Error::auth("API access key contains invalid header characters"))?;
+        // Keeps the key out of `Debug` output, e.g. middleware logging requests
+        access_key.set_sensitive(true);
         headers.insert("X-Access-Key", access_key);
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         Ok(headers)
     }
 
+    /// Send a request built by one of the verb helpers
+    ///
+    /// Every request goes through here, so retries and instrumentation
+    /// apply uniformly.
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let mut request = request.build()?;
+        if !self.config.middleware.is_empty() {
+            request = self.apply_middleware(request)?;
+        }
+        let response = self.send_recorded(request).await?;
+        for middleware in &self.config.middleware {
+            middleware.on_response(&response);
+        }
+        Ok(response)
+    }
+
+    /// Send a request, or replay/record it if a VCR cassette is configured
+    #[cfg(feature = "vcr")]
+    async fn send_recorded(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let Some(cassette) = &self.config.vcr else {
+            return self.send_with_retry(request).await;
+        };
+        let response = cassette
+            .send(request, &self.base_url(), |r| self.send_with_retry(r))
+            .await?;
+        if cassette.mode() == crate::vcr::Mode::Replay {
+            self.record_usage(&response);
+        }
+        Ok(response)
+    }
+
+    /// Send a request
+    #[cfg(not(feature = "vcr"))]
+    async fn send_recorded(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.send_with_retry(request).await
+    }
+
+    /// Run `on_request` hooks and rebuild the request from their changes
+    fn apply_middleware(&self, mut request: reqwest::Request) -> Result<reqwest::Request> {
+        let body = request
+            .body()
+            .and_then(|b| b.as_bytes())
+            .and_then(|b| serde_json::from_slice::<serde_json::Value>(b).ok());
+        let mut prepared = PreparedRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            body: body.clone(),
+            headers: request.headers().clone(),
+        };
+        for middleware in &self.config.middleware {
+            middleware.on_request(&mut prepared);
+        }
+
+        *request.method_mut() = prepared
+            .method
+            .parse()
+            .map_err(|_| Error::invalid_param(format!("Invalid method: {}", prepared.method)))?;
+        *request.url_mut() = prepared
+            .url
+            .parse()
+            .map_err(|_| Error::invalid_param(format!("Invalid URL: {}", prepared.url)))?;
+        *request.headers_mut() = prepared.headers;
+        if prepared.body != body {
+            *request.body_mut() = prepared.body.map(|b| b.to_string().into());
+        }
+        Ok(request)
+    }
+
+    /// Send a request, retrying transient failures per the retry policy
+    async fn send_with_retry(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let policy = &self.config.retry;
+        let mut attempt = 0;
+        loop {
+            let retryable = attempt < policy.max_retries && policy.allows(request.method());
+            let Some(current) = retryable.then(|| request.try_clone()).flatten() else {
+                return self.execute(request).await;
+            };
+            let delay = match self.execute(current).await {
+                Ok(response) if is_retryable_status(response.status().as_u16()) => {
+                    policy.backoff(attempt, retry_after(response.headers()))
+                }
+                Err(Error::Http(e)) if e.is_connect() || e.is_timeout() => {
+                    policy.backoff(attempt, None)
+                }
+                result => return result,
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Execute a single HTTP attempt
+    #[cfg(not(feature = "tracing"))]
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let response = self.http.execute(request).await?;
+        self.record_usage(&response);
+        Ok(response)
+    }
+
+    /// Execute a single HTTP attempt
+    ///
+    /// Emits a `tenderly_request` span with method, path and status. The
+    /// access key and bodies are never recorded unless `log_bodies` is set.
+    #[cfg(feature = "tracing")]
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        use tracing::Instrument;
+
+        let span = tracing::debug_span!(
+            "tenderly_request",
+            method = %request.method(),
+            path = %request.url().path(),
+            status = tracing::field::Empty,
+        );
+        if self.config.log_bodies {
+            if let Some(body) = request.body().and_then(|b| b.as_bytes()) {
+                tracing::debug!(parent: &span, body = %String::from_utf8_lossy(body), "request body");
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let result = self.http.execute(request).instrument(span.clone()).await;
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        match &result {
+            Ok(response) => {
+                self.record_usage(response);
+                span.record("status", response.status().as_u16());
+                tracing::debug!(parent: &span, status = response.status().as_u16(), elapsed_ms, "request completed");
+            }
+            Err(error) => {
+                tracing::debug!(parent: &span, %error, elapsed_ms, "request failed");
+            }
+        }
+        Ok(result?)
+    }
+
     /// Make a GET request to the API
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.url(path);
-        let response = self.http.get(&url).headers(self.headers()?).send().await?;
+        let response = self
+            .send(self.http.get(&url).headers(self.headers()?))
+            .await?;
 
         self.handle_response(response).await
     }
@@ -202,16 +1012,53 @@ impl Client {
     ) -> Result<T> {
         let url = self.url(path);
         let response = self
-            .http
-            .get(&url)
-            .headers(self.headers()?)
-            .query(query)
-            .send()
+            .send(self.http.get(&url).headers(self.headers()?).query(query))
             .await?;
 
         self.handle_response(response).await
     }
 
+    /// Fetch one page of a cursor-paginated endpoint
+    ///
+    /// `cursor` is sent as the `cursor` query parameter alongside `query`;
+    /// pass `None` for the first page.
+    pub async fn get_cursor_page<T: DeserializeOwned, Q: serde::Serialize>(
+        &self,
+        path: &str,
+        query: &Q,
+        cursor: Option<&Cursor>,
+    ) -> Result<CursorPage<T>> {
+        let url = self.url(path);
+        let mut request = self.http.get(&url).headers(self.headers()?).query(query);
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor.as_str())]);
+        }
+        let response = self.send(request).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Fetch every item of a cursor-paginated endpoint
+    ///
+    /// Follows `next_cursor` until the server returns no cursor. Use
+    /// [`get_cursor_page`](Self::get_cursor_page) to stop early.
+    pub async fn list_cursor<T: DeserializeOwned, Q: serde::Serialize>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page: CursorPage<T> = self.get_cursor_page(path, query, cursor.as_ref()).await?;
+            items.extend(page.items);
+            match page.next_cursor {
+                Some(next) if Some(&next) != cursor.as_ref() => cursor = Some(next),
+                _ => return Ok(items),
+            }
+        }
+    }
+
     /// Make a POST request to the API
     pub async fn post<T: DeserializeOwned, B: serde::Serialize>(
         &self,
@@ -220,11 +1067,7 @@ impl Client {
     ) -> Result<T> {
         let url = self.url(path);
         let response = self
-            .http
-            .post(&url)
-            .headers(self.headers()?)
-            .json(body)
-            .send()
+            .send(self.http.post(&url).headers(self.headers()?).json(body))
             .await?;
 
         self.handle_response(response).await
@@ -234,24 +1077,42 @@ impl Client {
     pub async fn post_no_response<B: serde::Serialize>(&self, path: &str, body: &B) -> Result<()> {
         let url = self.url(path);
         let response = self
-            .http
-            .post(&url)
-            .headers(self.headers()?)
-            .json(body)
-            .send()
+            .send(self.http.post(&url).headers(self.headers()?).json(body))
             .await?;
 
         self.handle_empty_response(response).await
     }
 
+    /// Make a POST request whose response body may be empty
+    ///
+    /// Returns `None` for an empty body (e.g., `204 No Content`).
+    pub async fn post_optional<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Option<T>> {
+        let url = self.url(path);
+        let response = self
+            .send(self.http.post(&url).headers(self.headers()?).json(body))
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return self.handle_error(status.as_u16(), response).await;
+        }
+
+        let bytes = response.bytes().await?;
+        if bytes.iter().all(u8::is_ascii_whitespace) {
+            return Ok(None);
+        }
+        decode(&bytes).map(Some)
+    }
+
     /// Make a DELETE request to the API
     pub async fn delete(&self, path: &str) -> Result<()> {
         let url = self.url(path);
         let response = self
-            .http
-            .delete(&url)
-            .headers(self.headers()?)
-            .send()
+            .send(self.http.delete(&url).headers(self.headers()?))
             .await?;
 
         self.handle_empty_response(response).await
@@ -265,11 +1126,7 @@ impl Client {
     ) -> Result<T> {
         let url = self.url(path);
         let response = self
-            .http
-            .put(&url)
-            .headers(self.headers()?)
-            .json(body)
-            .send()
+            .send(self.http.put(&url).headers(self.headers()?).json(body))
             .await?;
 
         self.handle_response(response).await
@@ -280,8 +1137,7 @@ impl Client {
         let status = response.status();
 
         if status.is_success() {
-            let body = response.json().await?;
-            Ok(body)
+            decode(&response.bytes().await?)
         } else {
             self.handle_error(status.as_u16(), response).await
         }
@@ -301,28 +1157,7 @@ impl Client {
     /// Handle error responses
     async fn handle_error<T>(&self, status: u16, response: reqwest::Response) -> Result<T> {
         // Extract rate limit headers before consuming the response
-        // Try standard Retry-After first, then Tenderly's X-Tdly-Reset-Timestamp
-        let retry_after = response
-            .headers()
-            .get("retry-after")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|s| s.parse::<u64>().ok())
-            .or_else(|| {
-                // Tenderly uses X-Tdly-Reset-Timestamp (Unix timestamp)
-                // Convert to seconds from now
-                response
-                    .headers()
-                    .get("x-tdly-reset-timestamp")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|s| s.parse::<u64>().ok())
-                    .and_then(|ts| {
-                        let now = std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .ok()?
-                            .as_secs();
-                        ts.checked_sub(now)
-                    })
-            });
+        let retry_after = retry_after(response.headers());
 
         if status == 429 {
             return Err(Error::rate_limited(retry_after));
@@ -343,11 +1178,30 @@ impl Client {
     }
 
     /// Get raw JSON response (for debugging or custom handling)
+    #[cfg(feature = "dynamic-json")]
     pub async fn get_raw(&self, path: &str) -> Result<serde_json::Value> {
         self.get(path).await
     }
 
+    /// Get the raw response body as text
+    ///
+    /// Useful for diagnosing responses that fail to decode, since the body
+    /// is returned as-is without any JSON parsing.
+    pub async fn get_text(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        let response = self
+            .send(self.http.get(&url).headers(self.headers()?))
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return self.handle_error(status.as_u16(), response).await;
+        }
+        Ok(response.text().await?)
+    }
+
     /// Post and get raw JSON response
+    #[cfg(feature = "dynamic-json")]
     pub async fn post_raw<B: serde::Serialize>(
         &self,
         path: &str,
@@ -356,25 +1210,33 @@ impl Client {
         self.post(path, body).await
     }
 
-    /// Build the full URL for an account-level API endpoint (no project in path)
-    pub fn account_url(&self, path: &str) -> String {
+    /// Build a dashboard URL for this project (`{DASHBOARD_URL}/{account}/{project}{suffix}`)
+    pub fn dashboard_url(&self, suffix: &str) -> String {
         format!(
-            "{}/account/{}{}",
-            self.config.base_url(),
+            "{}/{}/{}{}",
+            DASHBOARD_URL,
             encode_path_segment(&self.config.account),
-            path
+            encode_path_segment(&self.config.project),
+            suffix
         )
     }
 
+    /// Build the full URL for an account-level API endpoint (no project in path)
+    pub fn account_url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url(), self.account_path(path))
+    }
+
     /// Build the full URL for a global API endpoint (no account or project in path)
     pub fn global_url(&self, path: &str) -> String {
-        format!("{}{}", self.config.base_url(), path)
+        format!("{}{}", self.base_url(), path)
     }
 
     /// Make a GET request to an account-level endpoint
     pub async fn get_account<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.account_url(path);
-        let response = self.http.get(&url).headers(self.headers()?).send().await?;
+        let response = self
+            .send(self.http.get(&url).headers(self.headers()?))
+            .await?;
         self.handle_response(response).await
     }
 
@@ -382,10 +1244,7 @@ impl Client {
     pub async fn get_global<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.global_url(path);
         let response = self
-            .http
-            .get(&url)
-            .header(CONTENT_TYPE, "application/json")
-            .send()
+            .send(self.http.get(&url).header(CONTENT_TYPE, "application/json"))
             .await?;
         self.handle_response(response).await
     }
@@ -398,11 +1257,7 @@ impl Client {
     ) -> Result<T> {
         let url = self.url(path);
         let response = self
-            .http
-            .patch(&url)
-            .headers(self.headers()?)
-            .json(body)
-            .send()
+            .send(self.http.patch(&url).headers(self.headers()?).json(body))
             .await?;
         self.handle_response(response).await
     }
@@ -411,11 +1266,7 @@ impl Client {
     pub async fn patch_no_response<B: serde::Serialize>(&self, path: &str, body: &B) -> Result<()> {
         let url = self.url(path);
         let response = self
-            .http
-            .patch(&url)
-            .headers(self.headers()?)
-            .json(body)
-            .send()
+            .send(self.http.patch(&url).headers(self.headers()?).json(body))
             .await?;
         self.handle_empty_response(response).await
     }
@@ -424,20 +1275,72 @@ impl Client {
     pub async fn delete_with_body<B: serde::Serialize>(&self, path: &str, body: &B) -> Result<()> {
         let url = self.url(path);
         let response = self
-            .http
-            .delete(&url)
-            .headers(self.headers()?)
-            .json(body)
-            .send()
+            .send(self.http.delete(&url).headers(self.headers()?).json(body))
             .await?;
         self.handle_empty_response(response).await
     }
 }
 
+/// Build a curl command line, using `$TENDERLY_ACCESS_KEY` when no key is given
+fn render_curl(prepared: &PreparedRequest, access_key: Option<&str>) -> String {
+    fn quote(value: &str) -> String {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+
+    let key_header = match access_key {
+        Some(key) => quote(&format!("X-Access-Key: {}", key)),
+        None => "\"X-Access-Key: $TENDERLY_ACCESS_KEY\"".to_string(),
+    };
+
+    let mut curl = format!(
+        "curl -X {} {} \\\n  -H {} \\\n  -H 'Content-Type: application/json'",
+        prepared.method,
+        quote(&prepared.url),
+        key_header
+    );
+    if let Some(body) = &prepared.body {
+        curl.push_str(" \\\n  -d ");
+        curl.push_str(&quote(&body.to_string()));
+    }
+    curl
+}
+
+/// Deserialize a response body, keeping a snippet of it on failure
+pub(crate) fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    serde_json::from_slice(bytes).map_err(|e| Error::decode(e, bytes))
+}
+
+/// Seconds to wait before retrying, from rate limit headers
+///
+/// Tries standard `Retry-After` first, then Tenderly's
+/// `X-Tdly-Reset-Timestamp` (a Unix timestamp).
+fn retry_after(headers: &HeaderMap) -> Option<u64> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+    };
+    header("retry-after").or_else(|| {
+        let reset = header("x-tdly-reset-timestamp")?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+        reset.checked_sub(now)
+    })
+}
+
+/// Check whether a response status is worth retrying
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 429 | 500 | 502 | 503 | 504)
+}
+
 impl std::fmt::Debug for Client {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Client")
             .field("config", &self.config)
+            .field("api_version", &self.api_version())
             .finish()
     }
 }
@@ -471,6 +1374,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_project_path() {
+        let client = Client::new(
+            Config::new("key", "placeholder", "placeholder")
+                .with_account("my team")
+                .with_project("proj/1"),
+        )
+        .unwrap();
+
+        assert_eq!(client.account(), "my team");
+        assert_eq!(
+            client.project_path("/simulate"),
+            "/account/my%20team/project/proj%2F1/simulate"
+        );
+        assert_eq!(
+            client.account_path("/contracts"),
+            "/account/my%20team/contracts"
+        );
+        assert_eq!(
+            client.url("/simulate"),
+            "https://api.tenderly.co/api/v1/account/my%20team/project/proj%2F1/simulate"
+        );
+    }
+
+    #[test]
+    fn test_api_version_paths() {
+        let client = Client::new(Config::new("key", "acc", "proj")).unwrap();
+        assert_eq!(client.api_version(), ApiVersion::V1);
+        assert_eq!(
+            client.url("/vnets"),
+            "https://api.tenderly.co/api/v1/account/acc/project/proj/vnets"
+        );
+
+        let v2 = Client::new(Config::new("key", "acc", "proj").with_api_version(ApiVersion::V2))
+            .unwrap();
+        assert_eq!(
+            v2.url("/vnets"),
+            "https://api.tenderly.co/api/v2/account/acc/project/proj/vnets"
+        );
+        assert_eq!(
+            v2.account_url("/contracts"),
+            "https://api.tenderly.co/api/v2/account/acc/contracts"
+        );
+        assert_eq!(
+            v2.global_url("/networks"),
+            "https://api.tenderly.co/api/v2/networks"
+        );
+
+        // Pinned versions override the configured default
+        let pinned = v2.at_api_version(ApiVersion::V1);
+        assert_eq!(
+            pinned.url("/simulate"),
+            "https://api.tenderly.co/api/v1/account/acc/project/proj/simulate"
+        );
+        assert_eq!(v2.api_version(), ApiVersion::V2);
+    }
+
+    #[test]
+    fn test_api_version_custom_base_url() {
+        let config = Config::new("key", "acc", "proj").with_base_url("https://proxy.local/api/v1/");
+        assert_eq!(config.base_url(), "https://proxy.local/api/v1");
+        assert_eq!(
+            config.base_url_for(ApiVersion::V2),
+            "https://proxy.local/api/v2"
+        );
+
+        // Unversioned base URLs (e.g. mock servers) are left untouched
+        let config = Config::new("key", "acc", "proj").with_base_url("http://127.0.0.1:8080");
+        assert_eq!(config.base_url_for(ApiVersion::V2), "http://127.0.0.1:8080");
+    }
+
+    #[tokio::test]
+    async fn test_api_modules_pin_their_version() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/account/acc/project/proj/things"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new(
+            Config::new("key", "acc", "proj")
+                .with_base_url(format!("{}/api/v1", server.uri()))
+                .with_api_version(ApiVersion::V2),
+        )
+        .unwrap();
+
+        // The VNets module is served from v1 whatever the configured default
+        assert_eq!(crate::vnets::VNetsApi::API_VERSION, ApiVersion::V1);
+        assert!(client.vnets().list(None).await.unwrap().is_empty());
+
+        // Direct requests use the configured version
+        let things: Vec<serde_json::Value> = client.get("/things").await.unwrap();
+        assert!(things.is_empty());
+    }
+
+    #[test]
+    fn test_api_version_from_str() {
+        assert_eq!("v1".parse::<ApiVersion>().unwrap(), ApiVersion::V1);
+        assert_eq!("/api/v2".parse::<ApiVersion>().unwrap(), ApiVersion::V2);
+        assert_eq!(ApiVersion::V2.to_string(), "v2");
+        assert!("v3".parse::<ApiVersion>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_debug_and_errors_redact_key() {
+        let client = Client::new(
+            Config::new("supersecret", "myaccount", "myproject")
+                .with_base_url("http://127.0.0.1:9"),
+        )
+        .unwrap();
+
+        let debug_str = format!("{:?}", client);
+        assert!(!debug_str.contains("supersecret"));
+        assert!(debug_str.contains("[REDACTED]"));
+
+        // The key travels in a header, so transport errors never carry it
+        let err = client
+            .get::<serde_json::Value>("/simulate")
+            .await
+            .unwrap_err();
+        assert!(!err.to_string().contains("supersecret"));
+        assert!(!format!("{:?}", err).contains("supersecret"));
+    }
+
     #[test]
     fn test_config_debug_redacts_key() {
         let config = Config::new("supersecret", "myaccount", "myproject");
@@ -478,4 +1516,526 @@ mod tests {
         assert!(!debug_str.contains("supersecret"));
         assert!(debug_str.contains("[REDACTED]"));
     }
+
+    #[tokio::test]
+    async fn test_decode_error_includes_body_snippet() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{\"items\": oops}"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/huge"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(10_000)))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(Config::new("key", "acc", "proj").with_base_url(server.uri())).unwrap();
+
+        let err = client
+            .get::<serde_json::Value>("/things")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Decode { .. }));
+        assert_eq!(err.body_snippet(), Some("{\"items\": oops}"));
+        assert!(err.to_string().contains("{\"items\": oops}"));
+
+        let err = client.get::<serde_json::Value>("/huge").await.unwrap_err();
+        let snippet = err.body_snippet().unwrap();
+        assert_eq!(snippet.len(), Error::BODY_SNIPPET_LEN + 3);
+        assert!(snippet.ends_with("..."));
+
+        // The raw text is still retrievable for diagnosis
+        let text = client.get_text("/things").await.unwrap();
+        assert_eq!(text, "{\"items\": oops}");
+    }
+
+    #[tokio::test]
+    async fn test_retry_post_is_opt_in() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        async fn flaky_server(verb: &str) -> MockServer {
+            let server = MockServer::start().await;
+            Mock::given(method(verb))
+                .and(path("/account/acc/project/proj/simulate"))
+                .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+            Mock::given(method(verb))
+                .and(path("/account/acc/project/proj/simulate"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+                .mount(&server)
+                .await;
+            server
+        }
+        let client = |server: &MockServer, policy: RetryPolicy| {
+            Client::new(
+                Config::new("key", "acc", "proj")
+                    .with_base_url(server.uri())
+                    .with_retry(policy.initial_backoff(Duration::ZERO)),
+            )
+            .unwrap()
+        };
+        let body = serde_json::json!({"save": true});
+
+        // POST is not retried under the default policy
+        let server = flaky_server("POST").await;
+        let err = client(&server, RetryPolicy::default())
+            .post::<serde_json::Value, _>("/simulate", &body)
+            .await
+            .unwrap_err();
+        assert!(err.is_rate_limited());
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+        // ...but is once opted in
+        let server = flaky_server("POST").await;
+        client(&server, RetryPolicy::default().retry_post(true))
+            .post::<serde_json::Value, _>("/simulate", &body)
+            .await
+            .unwrap();
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+        // GET is idempotent, so it is retried by default
+        let server = flaky_server("GET").await;
+        client(&server, RetryPolicy::default())
+            .get::<serde_json::Value>("/simulate")
+            .await
+            .unwrap();
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+        // No retries unless a policy is configured
+        let server = flaky_server("GET").await;
+        let plain =
+            Client::new(Config::new("key", "acc", "proj").with_base_url(server.uri())).unwrap();
+        assert!(plain
+            .get::<serde_json::Value>("/simulate")
+            .await
+            .unwrap_err()
+            .is_rate_limited());
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1));
+        assert_eq!(policy.backoff(0, None), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2, None), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10, None), Duration::from_secs(1));
+        assert_eq!(policy.backoff(0, Some(0)), Duration::ZERO);
+        assert_eq!(policy.backoff(0, Some(60)), Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_state_and_connections() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Minimal keep-alive HTTP server that counts accepted connections
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = Arc::clone(&connections);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { return };
+                accepted.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut line = String::new();
+                    loop {
+                        // Read one request head (GET, no body)
+                        loop {
+                            line.clear();
+                            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                                return;
+                            }
+                            if line == "\r\n" {
+                                break;
+                            }
+                        }
+                        let response = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\n\r\n{}";
+                        if stream.write_all(response.as_bytes()).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        let client =
+            Client::new(Config::new("key", "acc", "proj").with_base_url(format!("http://{addr}")))
+                .unwrap();
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(&client.config, &clone.config));
+        assert!(Arc::ptr_eq(&client.usage, &clone.usage));
+
+        client.get::<serde_json::Value>("/a").await.unwrap();
+        clone.get::<serde_json::Value>("/b").await.unwrap();
+        client
+            .at_api_version(ApiVersion::V2)
+            .get::<serde_json::Value>("/c")
+            .await
+            .unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_last_usage_from_headers() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/quota"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({}))
+                    .insert_header("x-tdly-limit", "1000")
+                    .insert_header("x-tdly-remaining", "998")
+                    .insert_header("x-tdly-reset-timestamp", "1700000000"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/plain"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/std"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("x-ratelimit-limit", "60")
+                    .insert_header("x-ratelimit-remaining", "0"),
+            )
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(Config::new("key", "acc", "proj").with_base_url(server.uri())).unwrap();
+        assert_eq!(client.last_usage(), None);
+
+        client.get::<serde_json::Value>("/quota").await.unwrap();
+        let expected = Usage {
+            limit: Some(1000),
+            remaining: Some(998),
+            reset: Some(1_700_000_000),
+        };
+        assert_eq!(client.last_usage(), Some(expected));
+
+        // Responses without usage headers keep the last known values, and
+        // clones share the same state
+        let clone = client.at_api_version(ApiVersion::V1);
+        clone.get::<serde_json::Value>("/plain").await.unwrap();
+        assert_eq!(client.last_usage(), Some(expected));
+
+        // Standard headers are used as a fallback, including on errors
+        assert!(clone.get::<serde_json::Value>("/std").await.is_err());
+        let usage = client.last_usage().unwrap();
+        assert_eq!(usage.limit, Some(60));
+        assert_eq!(usage.remaining, Some(0));
+        assert_eq!(usage.reset, None);
+    }
+
+    #[tokio::test]
+    async fn test_delete_helpers() {
+        use wiremock::matchers::{body_json, header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/things/1"))
+            .and(header("X-Access-Key", "key"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(body_json(serde_json::json!({"ids": ["1", "2"]})))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/things/busy"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "7"))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(Config::new("key", "acc", "proj").with_base_url(server.uri())).unwrap();
+
+        client.delete("/things/1").await.unwrap();
+        client
+            .delete_with_body("/things", &serde_json::json!({"ids": ["1", "2"]}))
+            .await
+            .unwrap();
+
+        let err = client.delete("/things/busy").await.unwrap_err();
+        assert_eq!(err.retry_after(), Some(7));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_tracing_span_for_get() {
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        /// Collects span names and fields as `name key=value ...` strings
+        #[derive(Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        struct Fields(String);
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        impl tracing::Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = Fields(span.metadata().name().to_string());
+                span.record(&mut fields);
+                let mut spans = self.0.lock().unwrap();
+                spans.push(fields.0);
+                Id::from_u64(spans.len() as u64)
+            }
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut fields = Fields(String::new());
+                values.record(&mut fields);
+                self.0.lock().unwrap()[span.into_u64() as usize - 1].push_str(&fields.0);
+            }
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(Config::new("secret-key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let recorder = Recorder::default();
+        let spans = recorder.0.clone();
+        let _guard = tracing::subscriber::set_default(recorder);
+        let _: Vec<serde_json::Value> = client.get("/things").await.unwrap();
+
+        let spans = spans.lock().unwrap();
+        let span = spans
+            .iter()
+            .find(|s| s.starts_with("tenderly_request"))
+            .expect("request span");
+        assert!(span.contains("method=GET"), "{}", span);
+        assert!(
+            span.contains("path=/account/acc/project/proj/things"),
+            "{}",
+            span
+        );
+        assert!(span.contains("status=200"), "{}", span);
+        assert!(!span.contains("secret-key"));
+    }
+
+    #[tokio::test]
+    async fn test_list_cursor_follows_next_cursor() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(query_param("limit", "2"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [1, 2],
+                "next_cursor": "c1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(query_param("cursor", "c1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [3, 4],
+                "next_cursor": "c2"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(query_param("cursor", "c2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [5],
+                "next_cursor": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(Config::new("key", "acc", "proj").with_base_url(server.uri())).unwrap();
+        let items: Vec<u32> = client
+            .list_cursor("/things", &[("limit", "2")])
+            .await
+            .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_cursor_page_empty_cursor_ends() {
+        let page: CursorPage<u32> =
+            serde_json::from_value(serde_json::json!({"data": [1], "nextCursor": ""})).unwrap();
+        assert_eq!(page.items, vec![1]);
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_middleware_runs_once_per_request() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[derive(Default)]
+        struct Counter {
+            requests: AtomicUsize,
+            responses: AtomicUsize,
+        }
+
+        impl Middleware for Counter {
+            fn on_request(&self, request: &mut PreparedRequest) {
+                self.requests.fetch_add(1, Ordering::SeqCst);
+                request
+                    .headers
+                    .insert("X-Access-Key", HeaderValue::from_static("refreshed"));
+            }
+
+            fn on_response(&self, response: &reqwest::Response) {
+                assert_eq!(response.status(), 200);
+                self.responses.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(header("X-Access-Key", "refreshed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let counter = Arc::new(Counter::default());
+        let client = Client::new(
+            Config::new("key", "acc", "proj")
+                .with_base_url(server.uri())
+                .with_middleware(counter.clone()),
+        )
+        .unwrap();
+
+        let _: Vec<u32> = client.get("/things").await.unwrap();
+        let _: Vec<u32> = client.get("/things").await.unwrap();
+
+        assert_eq!(counter.requests.load(Ordering::SeqCst), 2);
+        assert_eq!(counter.responses.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_debug_hides_access_key() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[derive(Default)]
+        struct Logger(Mutex<Vec<String>>);
+
+        impl Middleware for Logger {
+            fn on_request(&self, request: &mut PreparedRequest) {
+                self.0.lock().unwrap().push(format!("{:?}", request));
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(header("X-Access-Key", "super-secret-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let logger = Arc::new(Logger::default());
+        let client = Client::new(
+            Config::new("super-secret-key", "acc", "proj")
+                .with_base_url(server.uri())
+                .with_middleware(logger.clone()),
+        )
+        .unwrap();
+        let _: Vec<serde_json::Value> = client.get("/things").await.unwrap();
+
+        let logged = logger.0.lock().unwrap();
+        assert_eq!(logged.len(), 1);
+        assert!(logged[0].contains("Sensitive"));
+        assert!(!logged[0].contains("super-secret-key"));
+    }
+
+    #[tokio::test]
+    async fn test_middleware_can_rewrite_body() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        struct Tag;
+
+        impl Middleware for Tag {
+            fn on_request(&self, request: &mut PreparedRequest) {
+                if let Some(body) = request.body.as_mut() {
+                    body["tag"] = "ci".into();
+                }
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/things"))
+            .and(body_json(serde_json::json!({"name": "a", "tag": "ci"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new(
+            Config::new("key", "acc", "proj")
+                .with_base_url(server.uri())
+                .with_middleware(Arc::new(Tag)),
+        )
+        .unwrap();
+
+        let _: serde_json::Value = client
+            .post("/things", &serde_json::json!({"name": "a"}))
+            .await
+            .unwrap();
+    }
 }
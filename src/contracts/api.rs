@@ -1,18 +1,24 @@
 //! Contract API operations
 
 use super::types::*;
-use crate::client::{encode_path_segment, Client};
-use crate::error::Result;
+use crate::client::{encode_path_segment, ApiVersion, Client};
+use crate::error::{Error, Result};
+use std::borrow::Cow;
 
 /// Contract API client
 pub struct ContractsApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> ContractsApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new Contract API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// Add a contract to the project
@@ -27,8 +33,36 @@ impl<'a> ContractsApi<'a> {
     ///
     /// let contract = client.contracts().add(&request).await?;
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AlreadyExists`](crate::Error::AlreadyExists) if the
+    /// contract is already part of the project. Use [`add_or_get`](Self::add_or_get)
+    /// to treat that case as success.
     pub async fn add(&self, request: &AddContractRequest) -> Result<Contract> {
-        self.client.post("/address", request).await
+        self.client
+            .post("/address", request)
+            .await
+            .map_err(Error::into_already_exists)
+    }
+
+    /// Add a contract, or fetch the existing entry if it was already added
+    ///
+    /// Makes repeated setup (e.g., in CI) idempotent.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let request = AddContractRequest::new("1", "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+    /// let contract = client.contracts().add_or_get(&request).await?;
+    /// ```
+    pub async fn add_or_get(&self, request: &AddContractRequest) -> Result<Contract> {
+        match self.add(request).await {
+            Err(e) if e.is_already_exists() => {
+                self.get(&request.network_id, &request.address).await
+            }
+            result => result,
+        }
     }
 
     /// List contracts in the project
@@ -88,7 +122,11 @@ impl<'a> ContractsApi<'a> {
     ///
     /// Submits source code for verification. If successful, the contract
     /// will show as verified and its ABI will be available.
+    ///
+    /// Returns [`Error::InvalidParam`](crate::Error::InvalidParam) without
+    /// sending the request if the compiler version is malformed.
     pub async fn verify(&self, request: &VerifyContractRequest) -> Result<VerificationResult> {
+        request.validate()?;
         self.client.post("/contract/verify", request).await
     }
 
@@ -244,6 +282,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_contract_request_minimal_body() {
+        let request = AddContractRequest::new("137", "0xabcd");
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({"network_id": "137", "address": "0xabcd"})
+        );
+    }
+
+    #[test]
+    fn test_list_contracts_deserialization() {
+        let json = r#"[
+            {
+                "id": "eth:1:0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "display_name": "USDC",
+                "account_type": "contract",
+                "contract": {
+                    "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "network_id": "1",
+                    "contract_name": "FiatTokenProxy",
+                    "verification_date": "2023-01-01T00:00:00Z"
+                },
+                "tags": [{"tag": "stablecoin", "created_at": "2024-01-01T00:00:00Z"}]
+            },
+            {
+                "id": "eth:1:0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+                "account_type": "wallet",
+                "tags": null
+            }
+        ]"#;
+
+        let contracts: Vec<ProjectContract> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(contracts.len(), 2);
+        assert!(contracts[0].is_verified());
+        assert!(contracts[0].is_contract());
+        assert_eq!(contracts[0].contract_name(), Some("FiatTokenProxy"));
+        assert_eq!(contracts[0].tags(), vec!["stablecoin".to_string()]);
+        assert!(contracts[1].is_wallet());
+        assert!(!contracts[1].is_verified());
+        assert!(contracts[1].tags().is_empty());
+    }
+
+    #[test]
+    fn test_verified_contract_compiler_metadata() {
+        let json = r#"{
+            "id": "eth:1:0x94c87a7b26980ae7aaa361c5c7e03e632ab36e6c",
+            "account_type": "contract",
+            "contract": {
+                "address": "0x94c87a7b26980ae7aaa361c5c7e03e632ab36e6c",
+                "network_id": "1",
+                "contract_name": "ERC20ByMetadrop",
+                "verification_date": "2023-12-08T22:36:22Z",
+                "standard": "erc20",
+                "standards": ["erc20"],
+                "evm_version": "paris",
+                "compiler_version": "v0.8.21+commit.d9974bed",
+                "optimizations_used": true,
+                "optimization_runs": 200
+            }
+        }"#;
+
+        let contract: ProjectContract = serde_json::from_str(json).unwrap();
+        assert!(contract.is_verified());
+        assert_eq!(contract.compiler_version(), Some("v0.8.21+commit.d9974bed"));
+        assert_eq!(contract.standard(), Some(&ContractStandard::Erc20));
+
+        let details = contract.contract.unwrap();
+        assert_eq!(details.optimization_used, Some(true));
+        assert_eq!(details.optimization_runs, Some(200));
+        assert_eq!(details.evm_version.as_deref(), Some("paris"));
+    }
+
+    #[tokio::test]
+    async fn test_add_already_added_is_distinct() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/address"))
+            .respond_with(
+                ResponseTemplate::new(400).set_body_string(
+                    r#"{"error":{"message":"contract already added to project"}}"#,
+                ),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/contract/1/0x1234"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "eth:1:0x1234",
+                "contract": {"address": "0x1234", "network_id": "1"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let request = AddContractRequest::new("1", "0x1234");
+
+        let err = client.contracts().add(&request).await.unwrap_err();
+        assert!(err.is_already_exists());
+
+        let contract = client.contracts().add_or_get(&request).await.unwrap();
+        assert_eq!(contract.address(), Some("0x1234"));
+    }
+
     #[test]
     fn test_verify_contract_request() {
         let request = VerifyContractRequest::new(
@@ -261,6 +411,212 @@ mod tests {
         assert_eq!(request.evm_version, Some("paris".to_string()));
     }
 
+    #[test]
+    fn test_verify_multi_file_serialization() {
+        let request =
+            VerifyContractRequest::new("1", "0x1234", "Vault", "", "v0.8.24+commit.e11b9ed9")
+                .source(
+                    "src/Vault.sol",
+                    "import \"@openzeppelin/contracts/token/ERC20/ERC20.sol\";",
+                )
+                .source(
+                    "lib/openzeppelin-contracts/contracts/token/ERC20/ERC20.sol",
+                    "contract ERC20 {}",
+                )
+                .remapping("@openzeppelin/=lib/openzeppelin-contracts/")
+                .library("src/Math.sol:Math", "0x5678");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("source_code").is_none());
+        assert_eq!(
+            json["sources"],
+            serde_json::json!({
+                "src/Vault.sol": {
+                    "content": "import \"@openzeppelin/contracts/token/ERC20/ERC20.sol\";"
+                },
+                "lib/openzeppelin-contracts/contracts/token/ERC20/ERC20.sol": {
+                    "content": "contract ERC20 {}"
+                }
+            })
+        );
+        assert_eq!(
+            json["remappings"],
+            serde_json::json!(["@openzeppelin/=lib/openzeppelin-contracts/"])
+        );
+        assert_eq!(
+            json["libraries"],
+            serde_json::json!({"src/Math.sol:Math": "0x5678"})
+        );
+    }
+
+    #[test]
+    fn test_verify_from_foundry_output() {
+        let root = tempfile::tempdir().unwrap();
+        let write = |path: &str, content: &str| {
+            let path = root.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write("src/Vault.sol", "contract Vault is ERC20 {}");
+        write(
+            "lib/openzeppelin-contracts/contracts/token/ERC20/ERC20.sol",
+            "contract ERC20 {}",
+        );
+        let metadata = serde_json::json!({
+            "compiler": {"version": "0.8.24+commit.e11b9ed9"},
+            "settings": {
+                "compilationTarget": {"src/Vault.sol": "Vault"},
+                "optimizer": {"enabled": true, "runs": 200},
+                "evmVersion": "cancun",
+                "remappings": [":@openzeppelin/=lib/openzeppelin-contracts/"],
+                "libraries": {}
+            },
+            "sources": {
+                "src/Vault.sol": {"keccak256": "0x01"},
+                "lib/openzeppelin-contracts/contracts/token/ERC20/ERC20.sol": {"keccak256": "0x02"}
+            }
+        });
+        write(
+            "out/Vault.sol/Vault.json",
+            &serde_json::json!({"abi": [], "metadata": metadata}).to_string(),
+        );
+
+        let request = VerifyContractRequest::from_foundry_output(
+            root.path().join("out/Vault.sol/Vault.json"),
+            "1",
+            "0x1234",
+        )
+        .unwrap();
+        assert_eq!(request.contract_name, "Vault");
+        assert_eq!(request.compiler_version, "v0.8.24+commit.e11b9ed9");
+        assert_eq!(request.evm_version.as_deref(), Some("cancun"));
+        assert_eq!(
+            request.remappings,
+            Some(vec![
+                "@openzeppelin/=lib/openzeppelin-contracts/".to_string()
+            ])
+        );
+        let sources = request.sources.unwrap();
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources["src/Vault.sol"], "contract Vault is ERC20 {}");
+
+        let err = VerifyContractRequest::from_foundry_output(
+            root.path().join("out/Missing.sol/Missing.json"),
+            "1",
+            "0x1234",
+        )
+        .unwrap_err();
+        assert!(matches!(err.root(), crate::Error::Io(_)));
+    }
+
+    #[test]
+    fn test_compiler_version_parsing() {
+        let version: CompilerVersion = "v0.8.24+commit.e11b9ed9".parse().unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (0, 8, 24));
+        assert_eq!(version.commit.as_deref(), Some("e11b9ed9"));
+        assert_eq!(version.to_string(), "v0.8.24+commit.e11b9ed9");
+
+        let nightly: CompilerVersion = "v0.8.25-nightly.2024.1.8+commit.0a5f6a4d".parse().unwrap();
+        assert_eq!(nightly.prerelease.as_deref(), Some("nightly.2024.1.8"));
+        assert_eq!(
+            nightly.to_string(),
+            "v0.8.25-nightly.2024.1.8+commit.0a5f6a4d"
+        );
+
+        assert_eq!(
+            "0.8.24".parse::<CompilerVersion>().unwrap(),
+            CompilerVersion::new(0, 8, 24)
+        );
+
+        for invalid in [
+            "",
+            "v0.8",
+            "v0.8.24.1",
+            "v0.8.x",
+            "0.8.24+e11b9ed9",
+            "v0.8.24+commit.xxxx",
+            "v0.8.24+commit.e11b9ed9a",
+            "v0.8.24-",
+            "solc-0.8.24",
+        ] {
+            assert!(
+                invalid.parse::<CompilerVersion>().is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_evm_version() {
+        assert_eq!("cancun".parse::<EvmVersion>().unwrap(), EvmVersion::Cancun);
+        assert_eq!(
+            "tangerineWhistle".parse::<EvmVersion>().unwrap(),
+            EvmVersion::TangerineWhistle
+        );
+        assert_eq!(EvmVersion::SpuriousDragon.to_string(), "spuriousDragon");
+        assert!("frontier".parse::<EvmVersion>().is_err());
+
+        let request = VerifyContractRequest::new(
+            "1",
+            "0x1234",
+            "MyContract",
+            "pragma solidity ^0.8.0;",
+            CompilerVersion::new(0, 8, 24).commit("e11b9ed9"),
+        )
+        .evm_version(EvmVersion::Shanghai);
+        assert_eq!(request.compiler_version, "v0.8.24+commit.e11b9ed9");
+        assert_eq!(request.evm_version.as_deref(), Some("shanghai"));
+        assert!(request.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_malformed_compiler_version() {
+        // Unreachable base URL: the request must fail before it is sent
+        let client = Client::new(
+            crate::Config::new("key", "acc", "proj").with_base_url("http://127.0.0.1:1"),
+        )
+        .unwrap();
+        let request = VerifyContractRequest::new(
+            "1",
+            "0x1234",
+            "MyContract",
+            "pragma solidity ^0.8.0;",
+            "0.8.latest",
+        );
+
+        let err = client.contracts().verify(&request).await.unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidParam(_)));
+    }
+
+    #[test]
+    fn test_contract_standard_deserialization() {
+        let parsed: Vec<ContractStandard> =
+            serde_json::from_str(r#"["erc20", "erc721", "ERC-1155", "erc4626", "uniswap-v2"]"#)
+                .unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                ContractStandard::Erc20,
+                ContractStandard::Erc721,
+                ContractStandard::Erc1155,
+                ContractStandard::Erc4626,
+                ContractStandard::Other("uniswap-v2".into()),
+            ]
+        );
+
+        assert!(parsed[0].is_fungible() && !parsed[0].is_nft());
+        assert!(parsed[1].is_nft() && !parsed[1].is_fungible());
+        assert!(parsed[2].is_nft());
+        assert!(!parsed[4].is_nft() && !parsed[4].is_fungible());
+
+        // Unknown values round-trip unchanged
+        assert_eq!(
+            serde_json::to_value(&parsed[4]).unwrap(),
+            serde_json::json!("uniswap-v2")
+        );
+        assert_eq!(ContractStandard::Erc1155.to_string(), "erc1155");
+    }
+
     #[test]
     fn test_state_override_input() {
         let override_input = StateOverrideInput::new()
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 
 /// Request to add a contract
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AddContractRequest {
     /// Network ID
     pub network_id: String,
@@ -83,6 +84,22 @@ pub struct Contract {
     /// Account type (e.g., "contract", "wallet")
     #[serde(default)]
     pub account_type: Option<String>,
+
+    /// Tags attached to the contract in this project
+    #[serde(default, deserialize_with = "deserialize_tags")]
+    pub tags: Vec<TagInfo>,
+}
+
+/// Alias for [`Contract`], the project-scoped contract entry returned by the API
+pub type ProjectContract = Contract;
+
+/// Deserialize tags, treating `null` as an empty list
+fn deserialize_tags<'de, D>(deserializer: D) -> std::result::Result<Vec<TagInfo>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let tags: Option<Vec<TagInfo>> = Option::deserialize(deserializer)?;
+    Ok(tags.unwrap_or_default())
 }
 
 impl Contract {
@@ -107,13 +124,24 @@ impl Contract {
     pub fn is_verified(&self) -> bool {
         self.contract
             .as_ref()
-            .is_some_and(|c| c.verification_date.is_some())
+            .is_some_and(ContractDetails::is_verified)
     }
 
-    /// Get tags from contract details
+    /// Get the compiler version recorded at verification
+    pub fn compiler_version(&self) -> Option<&str> {
+        self.contract
+            .as_ref()
+            .and_then(|c| c.compiler_version.as_deref())
+    }
+
+    /// Get the detected token standard
+    pub fn standard(&self) -> Option<&ContractStandard> {
+        self.contract.as_ref().and_then(|c| c.standard.as_ref())
+    }
+
+    /// Get the tag names attached to this contract
     pub fn tags(&self) -> Vec<String> {
-        // Tags may be stored at different levels depending on API version
-        Vec::new()
+        self.tags.iter().map(|t| t.tag.clone()).collect()
     }
 
     /// Check if this is an actual contract (not a wallet)
@@ -157,13 +185,13 @@ pub struct ContractDetails {
     #[serde(default)]
     pub verification_date: Option<String>,
 
-    /// Token standard (e.g., "erc20")
+    /// Token standard (e.g., ERC-20)
     #[serde(default)]
-    pub standard: Option<String>,
+    pub standard: Option<ContractStandard>,
 
     /// Multiple standards
     #[serde(default)]
-    pub standards: Vec<String>,
+    pub standards: Vec<ContractStandard>,
 
     /// Token data (for ERC20 tokens)
     #[serde(default)]
@@ -173,10 +201,22 @@ pub struct ContractDetails {
     #[serde(default)]
     pub public: bool,
 
-    /// Compiler version
+    /// Compiler version (e.g., "v0.8.21+commit.d9974bed")
     #[serde(default)]
     pub compiler_version: Option<String>,
 
+    /// Whether the optimizer was enabled
+    #[serde(default, alias = "optimizations_used")]
+    pub optimization_used: Option<bool>,
+
+    /// Optimizer runs
+    #[serde(default)]
+    pub optimization_runs: Option<u64>,
+
+    /// Target EVM version (empty when the compiler default was used)
+    #[serde(default)]
+    pub evm_version: Option<String>,
+
     /// Contract language
     #[serde(default)]
     pub language: Option<String>,
@@ -194,6 +234,100 @@ pub struct ContractDetails {
     pub balance: Option<String>,
 }
 
+/// Token standard implemented by a contract
+///
+/// Unrecognized values are preserved in [`ContractStandard::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContractStandard {
+    /// ERC-20 fungible token
+    Erc20,
+    /// ERC-721 non-fungible token
+    Erc721,
+    /// ERC-1155 multi-token
+    Erc1155,
+    /// ERC-777 fungible token
+    Erc777,
+    /// ERC-4626 tokenized vault
+    Erc4626,
+    /// Standard not known to this client
+    Other(String),
+}
+
+impl ContractStandard {
+    /// Get the string representation (as used by the API)
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Erc20 => "erc20",
+            Self::Erc721 => "erc721",
+            Self::Erc1155 => "erc1155",
+            Self::Erc777 => "erc777",
+            Self::Erc4626 => "erc4626",
+            Self::Other(s) => s,
+        }
+    }
+
+    /// Check if this is an NFT standard (ERC-721 or ERC-1155)
+    #[must_use]
+    pub fn is_nft(&self) -> bool {
+        matches!(self, Self::Erc721 | Self::Erc1155)
+    }
+
+    /// Check if this is a fungible token standard (ERC-20, ERC-777, ERC-4626)
+    #[must_use]
+    pub fn is_fungible(&self) -> bool {
+        matches!(self, Self::Erc20 | Self::Erc777 | Self::Erc4626)
+    }
+}
+
+impl std::fmt::Display for ContractStandard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for ContractStandard {
+    type Err = std::convert::Infallible;
+
+    /// Parse a standard, accepting forms like `erc20`, `ERC-20` and `erc_20`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_lowercase();
+        Ok(match normalized.as_str() {
+            "erc20" => Self::Erc20,
+            "erc721" => Self::Erc721,
+            "erc1155" => Self::Erc1155,
+            "erc777" => Self::Erc777,
+            "erc4626" => Self::Erc4626,
+            _ => Self::Other(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ContractStandard {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContractStandard {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+impl ContractDetails {
+    /// Check if the contract is verified
+    pub fn is_verified(&self) -> bool {
+        self.verification_date.is_some()
+    }
+}
+
 /// Token data for ERC20/ERC721 contracts
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenData {
@@ -212,6 +346,7 @@ pub struct TokenData {
 
 /// Request to update a contract
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateContractRequest {
     /// New display name
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -254,6 +389,7 @@ impl Default for UpdateContractRequest {
 
 /// Request to verify a contract
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VerifyContractRequest {
     /// Network ID
     pub network_id: String,
@@ -264,10 +400,26 @@ pub struct VerifyContractRequest {
     /// Contract name
     pub contract_name: String,
 
-    /// Solidity source code
+    /// Solidity source code (empty when [`sources`](Self::sources) is used)
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub source_code: String,
 
+    /// Source files by path, for contracts with imports
+    ///
+    /// Serialized in standard-JSON form (`{path: {"content": ...}}`).
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_sources"
+    )]
+    pub sources: Option<HashMap<String, String>>,
+
+    /// Import remappings (e.g., `@openzeppelin/=lib/openzeppelin-contracts/`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remappings: Option<Vec<String>>,
+
     /// Compiler version (e.g., "v0.8.19+commit.7dd6d404")
+    ///
+    /// Must parse as a [`CompilerVersion`]; checked by [`validate`](Self::validate).
     pub compiler_version: String,
 
     /// Optimization settings
@@ -289,6 +441,9 @@ pub struct VerifyContractRequest {
 
 impl VerifyContractRequest {
     /// Create a new verify request
+    ///
+    /// `compiler_version` and [`evm_version`](Self::evm_version) also accept
+    /// the typed [`CompilerVersion`] and [`EvmVersion`].
     pub fn new(
         network_id: impl Into<String>,
         address: impl Into<String>,
@@ -301,6 +456,8 @@ impl VerifyContractRequest {
             address: address.into(),
             contract_name: contract_name.into(),
             source_code: source_code.into(),
+            sources: None,
+            remappings: None,
             compiler_version: compiler_version.into(),
             optimization: None,
             constructor_arguments: None,
@@ -309,6 +466,128 @@ impl VerifyContractRequest {
         }
     }
 
+    /// Load a verify request from a Foundry build artifact
+    ///
+    /// `artifact` is `<root>/out/<File>.sol/<Contract>.json`. The compiler
+    /// settings, remappings and libraries come from the artifact's metadata,
+    /// and every source it lists is read relative to `<root>`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let request = VerifyContractRequest::from_foundry_output(
+    ///     "out/Vault.sol/Vault.json",
+    ///     "1",
+    ///     "0x1234567890abcdef1234567890abcdef12345678",
+    /// )?;
+    /// client.contracts().verify(&request).await?;
+    /// ```
+    pub fn from_foundry_output(
+        artifact: impl AsRef<std::path::Path>,
+        network_id: impl Into<String>,
+        address: impl Into<String>,
+    ) -> crate::error::Result<Self> {
+        use crate::error::Error;
+
+        let artifact = artifact.as_ref();
+        let read = |path: &std::path::Path| {
+            std::fs::read_to_string(path)
+                .map_err(|e| Error::from(e).context(format!("reading {}", path.display())))
+        };
+        let json: serde_json::Value = serde_json::from_str(&read(artifact)?)?;
+        let metadata: FoundryMetadata = match (json.get("metadata"), json.get("rawMetadata")) {
+            (Some(serde_json::Value::String(raw)), _)
+            | (_, Some(serde_json::Value::String(raw))) => serde_json::from_str(raw)?,
+            (Some(metadata), _) => serde_json::from_value(metadata.clone())?,
+            _ => {
+                return Err(Error::invalid_param(format!(
+                    "{} has no metadata; build with `extra_output = [\"metadata\"]`",
+                    artifact.display()
+                )))
+            }
+        };
+        let root = artifact
+            .parent()
+            .and_then(|dir| dir.parent())
+            .and_then(|out| out.parent())
+            .ok_or_else(|| {
+                Error::invalid_param(format!(
+                    "{} is not inside an out/ directory",
+                    artifact.display()
+                ))
+            })?;
+
+        let settings = metadata.settings;
+        let contract_name = settings
+            .compilation_target
+            .values()
+            .next()
+            .cloned()
+            .ok_or_else(|| Error::invalid_param("artifact metadata has no compilation target"))?;
+        let compiler_version: CompilerVersion = metadata
+            .compiler
+            .version
+            .parse()
+            .map_err(Error::invalid_param)?;
+
+        let mut sources = HashMap::new();
+        for path in metadata.sources.keys() {
+            sources.insert(path.clone(), read(&root.join(path))?);
+        }
+
+        let mut request =
+            Self::new(network_id, address, contract_name, "", compiler_version).sources(sources);
+        if let Some(optimizer) = settings.optimizer {
+            request = request.optimization(optimizer.enabled, optimizer.runs);
+        }
+        if let Some(evm_version) = settings.evm_version {
+            request = request.evm_version(evm_version);
+        }
+        for remapping in settings.remappings {
+            // solc prefixes context-free remappings with ':'
+            request = request.remapping(remapping.strip_prefix(':').unwrap_or(&remapping));
+        }
+        for (key, value) in settings.libraries {
+            match value {
+                serde_json::Value::String(address) => request = request.library(key, address),
+                serde_json::Value::Object(libraries) => {
+                    for (name, address) in libraries {
+                        if let Some(address) = address.as_str() {
+                            request = request.library(format!("{key}:{name}"), address);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(request)
+    }
+
+    /// Set all source files by path, replacing any set before
+    #[must_use]
+    pub fn sources(mut self, sources: HashMap<String, String>) -> Self {
+        self.sources = Some(sources);
+        self
+    }
+
+    /// Add a source file (e.g., `lib/openzeppelin-contracts/contracts/token/ERC20/ERC20.sol`)
+    #[must_use]
+    pub fn source(mut self, path: impl Into<String>, content: impl Into<String>) -> Self {
+        self.sources
+            .get_or_insert_with(HashMap::new)
+            .insert(path.into(), content.into());
+        self
+    }
+
+    /// Add an import remapping (e.g., `@openzeppelin/=lib/openzeppelin-contracts/`)
+    #[must_use]
+    pub fn remapping(mut self, remapping: impl Into<String>) -> Self {
+        self.remappings
+            .get_or_insert_with(Vec::new)
+            .push(remapping.into());
+        self
+    }
+
     /// Set optimization settings
     #[must_use]
     pub fn optimization(mut self, enabled: bool, runs: u32) -> Self {
@@ -338,10 +617,258 @@ impl VerifyContractRequest {
         self.evm_version = Some(version.into());
         self
     }
+
+    /// Check that the compiler version is well-formed
+    ///
+    /// Called by [`ContractsApi::verify`](super::ContractsApi::verify) before
+    /// submitting.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        self.compiler_version
+            .parse::<CompilerVersion>()
+            .map(drop)
+            .map_err(crate::error::Error::invalid_param)
+    }
+}
+
+fn serialize_sources<S: serde::Serializer>(
+    sources: &Option<HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct Source<'a> {
+        content: &'a str,
+    }
+
+    serializer.collect_map(
+        sources
+            .iter()
+            .flatten()
+            .map(|(path, content)| (path, Source { content })),
+    )
+}
+
+/// Solc metadata embedded in a Foundry artifact (the fields used for verification)
+#[derive(Deserialize)]
+struct FoundryMetadata {
+    compiler: FoundryCompiler,
+    settings: FoundrySettings,
+    #[serde(default)]
+    sources: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct FoundryCompiler {
+    version: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FoundrySettings {
+    #[serde(default)]
+    compilation_target: HashMap<String, String>,
+    #[serde(default)]
+    optimizer: Option<OptimizationSettings>,
+    #[serde(default)]
+    evm_version: Option<String>,
+    #[serde(default)]
+    remappings: Vec<String>,
+    #[serde(default)]
+    libraries: HashMap<String, serde_json::Value>,
+}
+
+/// Solidity compiler version, e.g. `v0.8.24+commit.e11b9ed9`
+///
+/// Parses release (`v0.8.24+commit.e11b9ed9`), prerelease
+/// (`v0.8.25-nightly.2024.1.8+commit.0a5f6a4d`) and bare (`0.8.24`) forms.
+/// The leading `v` is optional when parsing and always added by `Display`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompilerVersion {
+    /// Major version
+    pub major: u32,
+    /// Minor version
+    pub minor: u32,
+    /// Patch version
+    pub patch: u32,
+    /// Prerelease tag (e.g., `nightly.2024.1.8`)
+    pub prerelease: Option<String>,
+    /// 8 hex digit commit hash
+    pub commit: Option<String>,
+}
+
+impl CompilerVersion {
+    /// Create a version without prerelease tag or commit
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            prerelease: None,
+            commit: None,
+        }
+    }
+
+    /// Set the commit hash
+    #[must_use]
+    pub fn commit(mut self, commit: impl Into<String>) -> Self {
+        self.commit = Some(commit.into());
+        self
+    }
+}
+
+impl std::fmt::Display for CompilerVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(prerelease) = &self.prerelease {
+            write!(f, "-{prerelease}")?;
+        }
+        if let Some(commit) = &self.commit {
+            write!(f, "+commit.{commit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for CompilerVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || format!("Invalid compiler version: {s}. Expected e.g. v0.8.24+commit.e11b9ed9");
+        let rest = s.strip_prefix('v').unwrap_or(s);
+        let (rest, commit) = match rest.split_once('+') {
+            Some((rest, build)) => {
+                let commit = build.strip_prefix("commit.").ok_or_else(invalid)?;
+                if commit.len() != 8 || !commit.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid());
+                }
+                (rest, Some(commit.to_string()))
+            }
+            None => (rest, None),
+        };
+        let (core, prerelease) = match rest.split_once('-') {
+            Some((_, "")) => return Err(invalid()),
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (rest, None),
+        };
+        let mut parts = core.split('.').map(|part| {
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse::<u32>().map_err(|_| invalid())
+        });
+        let (Some(major), Some(minor), Some(patch), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        Ok(Self {
+            major: major?,
+            minor: minor?,
+            patch: patch?,
+            prerelease,
+            commit,
+        })
+    }
+}
+
+impl From<CompilerVersion> for String {
+    fn from(version: CompilerVersion) -> Self {
+        version.to_string()
+    }
+}
+
+/// Target EVM version for compilation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvmVersion {
+    /// Homestead
+    Homestead,
+    /// Tangerine Whistle
+    TangerineWhistle,
+    /// Spurious Dragon
+    SpuriousDragon,
+    /// Byzantium
+    Byzantium,
+    /// Constantinople
+    Constantinople,
+    /// Petersburg
+    Petersburg,
+    /// Istanbul
+    Istanbul,
+    /// Berlin
+    Berlin,
+    /// London
+    London,
+    /// Paris (the Merge)
+    Paris,
+    /// Shanghai
+    Shanghai,
+    /// Cancun
+    Cancun,
+    /// Prague
+    Prague,
+}
+
+impl EvmVersion {
+    /// Get the string representation used by solc
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Homestead => "homestead",
+            Self::TangerineWhistle => "tangerineWhistle",
+            Self::SpuriousDragon => "spuriousDragon",
+            Self::Byzantium => "byzantium",
+            Self::Constantinople => "constantinople",
+            Self::Petersburg => "petersburg",
+            Self::Istanbul => "istanbul",
+            Self::Berlin => "berlin",
+            Self::London => "london",
+            Self::Paris => "paris",
+            Self::Shanghai => "shanghai",
+            Self::Cancun => "cancun",
+            Self::Prague => "prague",
+        }
+    }
+}
+
+impl std::fmt::Display for EvmVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for EvmVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['_', '-'], "").as_str() {
+            "homestead" => Ok(Self::Homestead),
+            "tangerinewhistle" => Ok(Self::TangerineWhistle),
+            "spuriousdragon" => Ok(Self::SpuriousDragon),
+            "byzantium" => Ok(Self::Byzantium),
+            "constantinople" => Ok(Self::Constantinople),
+            "petersburg" => Ok(Self::Petersburg),
+            "istanbul" => Ok(Self::Istanbul),
+            "berlin" => Ok(Self::Berlin),
+            "london" => Ok(Self::London),
+            "paris" | "merge" => Ok(Self::Paris),
+            "shanghai" => Ok(Self::Shanghai),
+            "cancun" => Ok(Self::Cancun),
+            "prague" => Ok(Self::Prague),
+            _ => Err(format!("Invalid EVM version: {s}")),
+        }
+    }
+}
+
+impl From<EvmVersion> for String {
+    fn from(version: EvmVersion) -> Self {
+        version.as_str().to_string()
+    }
 }
 
 /// Optimization settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OptimizationSettings {
     /// Whether optimization is enabled
     pub enabled: bool,
@@ -366,6 +893,7 @@ pub struct VerificationResult {
 
 /// Request to encode state overrides
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EncodeStateRequest {
     /// Network ID
     pub network_id: String,
@@ -376,6 +904,7 @@ pub struct EncodeStateRequest {
 
 /// Input format for state overrides
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StateOverrideInput {
     /// Storage slot values to override
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -517,6 +1046,7 @@ impl ListContractsQuery {
 
 /// Request to rename a contract
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RenameContractRequest {
     /// New display name for the contract
     pub display_name: String,
@@ -524,6 +1054,7 @@ pub struct RenameContractRequest {
 
 /// Request to bulk tag contracts
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BulkTagRequest {
     /// Tag to apply
     pub tag: String,
@@ -553,6 +1084,7 @@ pub struct TagInfo {
 
 /// Request to delete a tag from a contract
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeleteTagRequest {
     /// Tag to delete
     pub tag: String,
@@ -1,18 +1,24 @@
 //! Delivery Channels API operations
 
 use super::types::*;
-use crate::client::Client;
+use crate::client::{ApiVersion, Client};
 use crate::error::Result;
+use std::borrow::Cow;
 
 /// Delivery Channels API client
 pub struct DeliveryChannelsApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> DeliveryChannelsApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new Delivery Channels API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// List all delivery channels for the account
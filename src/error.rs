@@ -6,6 +6,9 @@ use thiserror::Error;
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Errors that can occur when interacting with the Tenderly API
+///
+/// Wrapped `reqwest`, `serde_json` and URL errors are exposed through
+/// [`std::error::Error::source`], so error reporters print the full chain.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum Error {
@@ -17,10 +20,33 @@ pub enum Error {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// Response body could not be decoded into the expected type
+    ///
+    /// `body` holds the start of the raw response (at most
+    /// [`Error::BODY_SNIPPET_LEN`] bytes) to help diagnose schema mismatches.
+    #[error("Failed to decode response: {source} (body: {body})")]
+    Decode {
+        /// Underlying deserialization error
+        source: serde_json::Error,
+        /// Truncated raw response body
+        body: String,
+    },
+
     /// API returned an error response
     #[error("Tenderly API error ({status}): {message}")]
     Api { status: u16, message: String },
 
+    /// JSON-RPC endpoint returned an error object (e.g., Admin RPC)
+    #[error("JSON-RPC error ({code}): {message}")]
+    JsonRpc {
+        /// JSON-RPC error code (e.g., `-32602` for invalid params)
+        code: i64,
+        /// Error message
+        message: String,
+        /// Additional error data, if provided
+        data: Option<serde_json::Value>,
+    },
+
     /// Authentication failed or credentials missing
     #[error("Authentication error: {0}")]
     Auth(String),
@@ -29,10 +55,21 @@ pub enum Error {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    /// A required environment variable is not set
+    #[error("Environment variable {var} not set")]
+    MissingEnv {
+        /// Name of the missing variable
+        var: String,
+    },
+
     /// Resource not found
     #[error("Resource not found: {0}")]
     NotFound(String),
 
+    /// Resource already exists (e.g., contract already added to the project)
+    #[error("Resource already exists: {0}")]
+    AlreadyExists(String),
+
     /// Rate limit exceeded
     ///
     /// The `retry_after` field contains the number of seconds to wait before retrying,
@@ -43,16 +80,64 @@ pub enum Error {
         retry_after: Option<u64>,
     },
 
+    /// Endpoint or operation not supported (e.g., VNet stop/start answering
+    /// 405/501, or not available on the current plan)
+    #[error("Operation not supported: {0}")]
+    Unsupported(String),
+
     /// Invalid input parameters
     #[error("Invalid parameter: {0}")]
     InvalidParam(String),
 
+    /// Timed out waiting for an operation to finish
+    #[error("Timed out waiting for {operation}{}", .last_status.as_ref().map(|s| format!(" (last status: {s})")).unwrap_or_default())]
+    Timeout {
+        /// What was being waited for
+        operation: String,
+        /// Last status observed before giving up, if any
+        last_status: Option<String>,
+    },
+
     /// URL parsing error
     #[error("URL parsing error: {0}")]
     UrlParse(#[from] url::ParseError),
+
+    /// Reading a local file failed (e.g., a build artifact)
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Websocket connection failed (requires the `ws` feature)
+    #[cfg(feature = "ws")]
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+
+    /// Another error with a message describing what was being done
+    ///
+    /// Created by [`Error::context`]; the wrapped error is the source.
+    #[error("{message}")]
+    Context {
+        /// Context message
+        message: String,
+        /// Wrapped error
+        source: Box<Error>,
+    },
 }
 
 impl Error {
+    /// Maximum length of the raw body kept in [`Error::Decode`]
+    pub const BODY_SNIPPET_LEN: usize = 2048;
+
+    /// Create a decode error, keeping a truncated snippet of the raw body
+    pub fn decode(source: serde_json::Error, body: &[u8]) -> Self {
+        let truncated = body.len() > Self::BODY_SNIPPET_LEN;
+        let mut body =
+            String::from_utf8_lossy(&body[..body.len().min(Self::BODY_SNIPPET_LEN)]).into_owned();
+        if truncated {
+            body.push_str("...");
+        }
+        Self::Decode { source, body }
+    }
+
     /// Create an API error from status code and message
     pub fn api(status: u16, message: impl Into<String>) -> Self {
         Self::Api {
@@ -61,6 +146,19 @@ impl Error {
         }
     }
 
+    /// Create a JSON-RPC error
+    pub fn json_rpc(
+        code: i64,
+        message: impl Into<String>,
+        data: Option<serde_json::Value>,
+    ) -> Self {
+        Self::JsonRpc {
+            code,
+            message: message.into(),
+            data,
+        }
+    }
+
     /// Create an authentication error
     pub fn auth(message: impl Into<String>) -> Self {
         Self::Auth(message.into())
@@ -71,29 +169,102 @@ impl Error {
         Self::Config(message.into())
     }
 
+    /// Create a missing environment variable error
+    pub fn missing_env(var: impl Into<String>) -> Self {
+        Self::MissingEnv { var: var.into() }
+    }
+
     /// Create a not found error
     pub fn not_found(resource: impl Into<String>) -> Self {
         Self::NotFound(resource.into())
     }
 
+    /// Create an already exists error
+    pub fn already_exists(resource: impl Into<String>) -> Self {
+        Self::AlreadyExists(resource.into())
+    }
+
     /// Create an invalid parameter error
     pub fn invalid_param(message: impl Into<String>) -> Self {
         Self::InvalidParam(message.into())
     }
 
+    /// Create an unsupported operation error
+    pub fn unsupported(message: impl Into<String>) -> Self {
+        Self::Unsupported(message.into())
+    }
+
     /// Create a rate limited error with optional retry-after duration
     pub fn rate_limited(retry_after: Option<u64>) -> Self {
         Self::RateLimited { retry_after }
     }
 
+    /// Create a timeout error with the last observed status
+    pub fn timeout(operation: impl Into<String>, last_status: Option<String>) -> Self {
+        Self::Timeout {
+            operation: operation.into(),
+            last_status,
+        }
+    }
+
+    /// Wrap this error with a message, keeping it as the source
+    ///
+    /// The `is_*` checks and accessors look through context, so
+    /// `err.context("loading vnet").is_not_found()` still holds.
+    pub fn context(self, message: impl Into<String>) -> Self {
+        Self::Context {
+            message: message.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Reclassify a duplicate-resource response as [`Error::AlreadyExists`]
+    ///
+    /// Only create endpoints apply this: a 409, or a 400/422 whose message
+    /// says the resource already exists. Other errors are returned unchanged.
+    pub(crate) fn into_already_exists(self) -> Self {
+        match self {
+            Self::Api {
+                status: 409,
+                message,
+            } => Self::AlreadyExists(message),
+            // Tenderly reports some duplicates as plain validation errors
+            Self::InvalidParam(message) if is_already_exists_message(&message) => {
+                Self::AlreadyExists(message)
+            }
+            other => other,
+        }
+    }
+
+    /// Reclassify a 405 or 501 response as [`Error::Unsupported`]
+    ///
+    /// Applied by endpoints that some plans lack, such as VNet stop/start.
+    pub(crate) fn into_unsupported(self) -> Self {
+        match self {
+            Self::Api {
+                status: 405 | 501,
+                message,
+            } => Self::Unsupported(message),
+            other => other,
+        }
+    }
+
+    /// The innermost error, skipping any [`Error::Context`] wrappers
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::Context { source, .. } => source.root(),
+            other => other,
+        }
+    }
+
     /// Check if this is a rate limit error
     pub fn is_rate_limited(&self) -> bool {
-        matches!(self, Self::RateLimited { .. })
+        matches!(self.root(), Self::RateLimited { .. })
     }
 
     /// Get the retry-after duration if this is a rate limit error
     pub fn retry_after(&self) -> Option<u64> {
-        match self {
+        match self.root() {
             Self::RateLimited { retry_after } => *retry_after,
             _ => None,
         }
@@ -101,6 +272,121 @@ impl Error {
 
     /// Check if this is a not found error
     pub fn is_not_found(&self) -> bool {
-        matches!(self, Self::NotFound(_))
+        matches!(self.root(), Self::NotFound(_))
+    }
+
+    /// Check if this is an already exists error
+    pub fn is_already_exists(&self) -> bool {
+        matches!(self.root(), Self::AlreadyExists(_))
+    }
+
+    /// Check if this is an unsupported operation error
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self.root(), Self::Unsupported(_))
+    }
+
+    /// Check if this is a timeout error
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.root(), Self::Timeout { .. })
+    }
+
+    /// Get the raw body snippet if this is a decode error
+    pub fn body_snippet(&self) -> Option<&str> {
+        match self.root() {
+            Self::Decode { body, .. } => Some(body),
+            _ => None,
+        }
+    }
+
+    /// Get the JSON-RPC error code if this is a JSON-RPC error
+    pub fn code(&self) -> Option<i64> {
+        match self.root() {
+            Self::JsonRpc { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+/// Check whether an error body describes a duplicate resource
+fn is_already_exists_message(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    lower.contains("already exists") || lower.contains("already added")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[tokio::test]
+    async fn test_http_error_source_is_reqwest() {
+        let client = crate::Client::new(
+            crate::Config::new("key", "acc", "proj").with_base_url("http://127.0.0.1:1"),
+        )
+        .unwrap();
+        let err = client.get::<serde_json::Value>("/vnets").await.unwrap_err();
+
+        assert!(matches!(err, Error::Http(_)));
+        assert!(err.to_string().starts_with("HTTP request failed"));
+        let source = err.source().expect("http error should chain its source");
+        assert!(source.downcast_ref::<reqwest::Error>().is_some());
+    }
+
+    #[test]
+    fn test_decode_error_source_is_serde() {
+        let inner = serde_json::from_str::<u64>("\"nope\"").unwrap_err();
+        let err = Error::decode(inner, b"\"nope\"");
+
+        let source = err.source().expect("decode error should chain its source");
+        assert!(source.downcast_ref::<serde_json::Error>().is_some());
+        assert!(err.to_string().contains("\"nope\""));
+
+        // Errors without an underlying cause have no source
+        assert!(Error::not_found("vnet").source().is_none());
+    }
+
+    #[test]
+    fn test_context_preserves_source() {
+        let err = Error::not_found("vnet abc").context("loading fixture VNet");
+
+        // The wrapped error is only reachable through `source()`, so chain
+        // reporters print it once
+        assert_eq!(err.to_string(), "loading fixture VNet");
+        let source = err.source().expect("context should chain its source");
+        assert_eq!(source.to_string(), "Resource not found: vnet abc");
+        assert!(matches!(err.root(), Error::NotFound(_)));
+        assert!(err.is_not_found());
+
+        // Nested context still reaches the original error
+        let inner = serde_json::from_str::<u64>("x").unwrap_err();
+        let err = Error::from(inner).context("parsing").context("loading");
+        assert_eq!(err.to_string(), "loading");
+        assert_eq!(err.source().unwrap().to_string(), "parsing");
+        assert!(matches!(err.root(), Error::Json(_)));
+        assert!(err.source().unwrap().source().is_some());
+    }
+
+    #[test]
+    fn test_into_already_exists() {
+        assert!(is_already_exists_message(
+            r#"{"error":{"message":"Contract already exists in project"}}"#
+        ));
+        assert!(is_already_exists_message("address ALREADY ADDED"));
+        assert!(!is_already_exists_message("invalid network id"));
+
+        assert!(Error::api(409, "conflict")
+            .into_already_exists()
+            .is_already_exists());
+        assert!(Error::invalid_param("address already added")
+            .into_already_exists()
+            .is_already_exists());
+        assert!(matches!(
+            Error::invalid_param("invalid network id").into_already_exists(),
+            Error::InvalidParam(_)
+        ));
+        assert!(matches!(
+            Error::api(500, "already exists").into_already_exists(),
+            Error::Api { status: 500, .. }
+        ));
     }
 }
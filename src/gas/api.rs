@@ -0,0 +1,126 @@
+//! Gas price API operations
+
+use super::types::*;
+use crate::client::{encode_path_segment, ApiVersion, Client};
+use crate::error::Result;
+use std::borrow::Cow;
+
+/// Gas price API client
+pub struct GasApi<'a> {
+    client: Cow<'a, Client>,
+}
+
+impl<'a> GasApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
+    /// Create a new Gas API client
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
+    }
+
+    /// Get the current gas price estimate for a network
+    ///
+    /// Returns `low`, `medium`, and `high` buckets, each with EIP-1559
+    /// `max_fee_per_gas` and `max_priority_fee_per_gas` values in wei.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let estimate = client.gas().gas_price("1").await?;
+    /// println!("Medium max fee: {} wei", estimate.medium().max_fee_per_gas);
+    /// ```
+    pub async fn gas_price(&self, network_id: &str) -> Result<GasPriceEstimate> {
+        self.client
+            .get_global(&format!("/gas-price/{}", encode_path_segment(network_id)))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::SimulationRequest;
+    use crate::vnets::SendVNetTransactionRequest;
+
+    const SAMPLE_RESPONSE: &str = r#"{
+        "currentBlockNumber": "0x12a05f2",
+        "baseFeePerGas": "0x3b9aca00",
+        "price": {
+            "low": {
+                "maxPriorityFeePerGas": "0x5f5e100",
+                "maxFeePerGas": "0x4190ab00",
+                "waitTime": {"lowerBound": 24000, "upperBound": 36000, "unit": "ms"}
+            },
+            "medium": {
+                "maxPriorityFeePerGas": "0x77359400",
+                "maxFeePerGas": "0xb2d05e00"
+            },
+            "high": {
+                "maxPriorityFeePerGas": "0xee6b2800",
+                "maxFeePerGas": "0x12a05f200"
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_gas_price_estimate_deserialization() {
+        let estimate: GasPriceEstimate = serde_json::from_str(SAMPLE_RESPONSE).unwrap();
+
+        assert_eq!(estimate.current_block_number, Some(19_531_250));
+        assert_eq!(estimate.base_fee_per_gas, Some(1_000_000_000));
+
+        assert_eq!(estimate.low().max_priority_fee_per_gas, 100_000_000);
+        assert_eq!(estimate.low().max_fee_per_gas, 1_100_000_000);
+        let wait = estimate.low().wait_time.as_ref().unwrap();
+        assert_eq!(wait.lower_bound, Some(24_000));
+        assert_eq!(wait.unit.as_deref(), Some("ms"));
+
+        assert_eq!(estimate.medium().max_fee_per_gas, 3_000_000_000);
+        assert!(estimate.medium().wait_time.is_none());
+        assert_eq!(
+            estimate.bucket(GasSpeed::High).max_fee_per_gas,
+            5_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_gas_price_decimal_values() {
+        let json = r#"{"maxPriorityFeePerGas": "1500000000", "maxFeePerGas": 30000000000}"#;
+        let price: GasPrice = serde_json::from_str(json).unwrap();
+
+        assert_eq!(price.max_priority_fee_per_gas, 1_500_000_000);
+        assert_eq!(price.max_fee_per_gas, 30_000_000_000);
+    }
+
+    #[test]
+    fn test_gas_speed_from_str() {
+        assert_eq!("low".parse::<GasSpeed>().unwrap(), GasSpeed::Low);
+        assert_eq!("Medium".parse::<GasSpeed>().unwrap(), GasSpeed::Medium);
+        assert_eq!("HIGH".parse::<GasSpeed>().unwrap(), GasSpeed::High);
+        assert!("fast".parse::<GasSpeed>().is_err());
+    }
+
+    #[test]
+    fn test_apply_bucket_to_requests() {
+        let estimate: GasPriceEstimate = serde_json::from_str(SAMPLE_RESPONSE).unwrap();
+        let price = estimate.bucket(GasSpeed::Medium);
+
+        let simulation = SimulationRequest::new("0x1234", "0x5678", "0x").gas_fees(price);
+        assert_eq!(simulation.max_fee_per_gas, Some("3000000000".to_string()));
+        assert_eq!(
+            simulation.max_priority_fee_per_gas,
+            Some("2000000000".to_string())
+        );
+        assert_eq!(simulation.transaction_type, Some(2));
+
+        let send = SendVNetTransactionRequest::new("0x1234", "0x5678", "0x").gas_fees(price);
+        assert_eq!(send.max_fee_per_gas, Some("0xb2d05e00".to_string()));
+        assert_eq!(
+            send.max_priority_fee_per_gas,
+            Some("0x77359400".to_string())
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Gas price API
+//!
+//! Fetch current gas price estimates for a network, bucketed by inclusion
+//! speed, and apply them to simulation or VNet transaction requests.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use tndrly::Client;
+//! use tndrly::gas::GasSpeed;
+//! use tndrly::simulation::SimulationRequest;
+//!
+//! let client = Client::from_env()?;
+//!
+//! let estimate = client.gas().gas_price("1").await?;
+//! println!("Base fee: {:?}", estimate.base_fee_per_gas);
+//!
+//! // Apply the "high" bucket to a simulation
+//! let request = SimulationRequest::new("0xFrom", "0xTo", "0x")
+//!     .gas_fees(estimate.bucket(GasSpeed::High));
+//! ```
+
+mod api;
+mod types;
+
+pub use api::GasApi;
+pub use types::*;
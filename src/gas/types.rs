@@ -0,0 +1,249 @@
+//! Types for Gas price API
+
+use serde::{Deserialize, Serialize};
+
+/// Gas price estimate for a network
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPriceEstimate {
+    /// Block number the estimate was computed at
+    #[serde(default, with = "hex_u64_opt")]
+    pub current_block_number: Option<u64>,
+
+    /// Current base fee per gas in wei
+    #[serde(default, with = "wei_opt")]
+    pub base_fee_per_gas: Option<u128>,
+
+    /// Price buckets by inclusion speed
+    pub price: GasPriceLevels,
+}
+
+impl GasPriceEstimate {
+    /// Get the bucket for the given speed
+    #[must_use]
+    pub fn bucket(&self, speed: GasSpeed) -> &GasPrice {
+        match speed {
+            GasSpeed::Low => &self.price.low,
+            GasSpeed::Medium => &self.price.medium,
+            GasSpeed::High => &self.price.high,
+        }
+    }
+
+    /// Get the low (slowest) bucket
+    #[must_use]
+    pub fn low(&self) -> &GasPrice {
+        &self.price.low
+    }
+
+    /// Get the medium bucket
+    #[must_use]
+    pub fn medium(&self) -> &GasPrice {
+        &self.price.medium
+    }
+
+    /// Get the high (fastest) bucket
+    #[must_use]
+    pub fn high(&self) -> &GasPrice {
+        &self.price.high
+    }
+}
+
+/// Gas price buckets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasPriceLevels {
+    /// Low priority (slower inclusion)
+    pub low: GasPrice,
+
+    /// Medium priority
+    pub medium: GasPrice,
+
+    /// High priority (faster inclusion)
+    pub high: GasPrice,
+}
+
+/// EIP-1559 fee values for a single bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPrice {
+    /// Max fee per gas in wei
+    #[serde(with = "wei")]
+    pub max_fee_per_gas: u128,
+
+    /// Max priority fee per gas in wei
+    #[serde(with = "wei")]
+    pub max_priority_fee_per_gas: u128,
+
+    /// Expected time until inclusion
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_time: Option<WaitTime>,
+}
+
+/// Default base fee multiplier for [`GasPrice::from_base_fee`]
+///
+/// Doubling the base fee keeps a transaction valid through about six
+/// consecutive full blocks, each of which can raise the base fee by 12.5%.
+pub const BASE_FEE_MULTIPLIER: u128 = 2;
+
+impl GasPrice {
+    /// Derive fees from a base fee and priority fee
+    ///
+    /// `max_fee_per_gas = base_fee * BASE_FEE_MULTIPLIER + priority`.
+    #[must_use]
+    pub fn from_base_fee(base_fee: u128, priority: u128) -> Self {
+        Self::from_base_fee_with_multiplier(base_fee, priority, BASE_FEE_MULTIPLIER)
+    }
+
+    /// Derive fees with a custom base fee multiplier
+    ///
+    /// `max_fee_per_gas = base_fee * multiplier + priority`, saturating on
+    /// overflow.
+    #[must_use]
+    pub fn from_base_fee_with_multiplier(base_fee: u128, priority: u128, multiplier: u128) -> Self {
+        Self {
+            max_fee_per_gas: base_fee.saturating_mul(multiplier).saturating_add(priority),
+            max_priority_fee_per_gas: priority,
+            wait_time: None,
+        }
+    }
+}
+
+/// Expected inclusion time range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitTime {
+    /// Lower bound
+    #[serde(default)]
+    pub lower_bound: Option<u64>,
+
+    /// Upper bound
+    #[serde(default)]
+    pub upper_bound: Option<u64>,
+
+    /// Time unit (e.g., "ms")
+    #[serde(default)]
+    pub unit: Option<String>,
+}
+
+/// Gas price bucket selector
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GasSpeed {
+    /// Low priority
+    Low,
+    /// Medium priority
+    #[default]
+    Medium,
+    /// High priority
+    High,
+}
+
+impl GasSpeed {
+    /// Get the string representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+impl std::fmt::Display for GasSpeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for GasSpeed {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            _ => Err(format!(
+                "Invalid gas speed: {}. Expected: low, medium, or high",
+                s
+            )),
+        }
+    }
+}
+
+/// Parse a wei amount given as a hex string, decimal string, or number
+fn parse_wei(value: &serde_json::Value) -> Option<u128> {
+    match value {
+        serde_json::Value::String(s) => match s.strip_prefix("0x") {
+            Some(hex) => u128::from_str_radix(hex, 16).ok(),
+            None => s.parse().ok(),
+        },
+        serde_json::Value::Number(n) => n.as_u64().map(u128::from),
+        _ => None,
+    }
+}
+
+/// Serde helpers for wei amounts (hex on the wire, `u128` in Rust)
+mod wei {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{:x}", value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        super::parse_wei(&value)
+            .ok_or_else(|| D::Error::custom(format!("invalid wei amount: {}", value)))
+    }
+}
+
+/// Serde helpers for optional wei amounts
+mod wei_opt {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<u128>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => super::wei::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u128>, D::Error> {
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(value) => super::parse_wei(&value)
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("invalid wei amount: {}", value))),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Serde helpers for optional hex-encoded block numbers
+mod hex_u64_opt {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_str(&format!("0x{:x}", v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(value) => super::parse_wei(&value)
+                .and_then(|n| u64::try_from(n).ok())
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("invalid block number: {}", value))),
+            None => Ok(None),
+        }
+    }
+}
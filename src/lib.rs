@@ -9,6 +9,7 @@
 //! - **Contract API** - Manage and verify smart contracts
 //! - **Web3 Actions API** - Deploy serverless functions
 //! - **Wallets API** - Track and monitor wallet addresses
+//! - **Gas API** - Gas price estimates
 //!
 //! ## Quick Start
 //!
@@ -50,6 +51,7 @@
 //! - `TENDERLY_ACCESS_KEY` - Your Tenderly API access key
 //! - `TENDERLY_ACCOUNT` - Your account slug (username or organization)
 //! - `TENDERLY_PROJECT` - Your project slug
+//! - `TENDERLY_BASE_URL` - Optional API base URL override
 //!
 //! ## API Modules
 //!
@@ -62,27 +64,66 @@
 //! - [`wallets`] - Wallet monitoring
 //! - [`delivery_channels`] - Notification delivery channels
 //! - [`networks`] - Supported networks
+//! - [`gas`] - Gas price estimates
+//!
+//! [`prelude`] re-exports the client, configuration, error and the common
+//! simulation and VNet types for a single `use tndrly::prelude::*;`.
+//!
+//! ## Cargo Features
+//!
+//! - `abi` (default) - ABI encoding/decoding helpers for calldata and return data ([`abi`])
+//! - `raw-tx` - Decode and simulate signed raw transactions
+//!   ([`SimulationApi::simulate_raw`](simulation::SimulationApi))
+//! - `tracing` - Emit a `tracing` span per API request (method, path, status, timing)
+//! - `dynamic-json` (default) - Methods returning untyped [`serde_json::Value`]
+//!   (`Client::get_raw`/`post_raw`, `SimulationApi::info_raw`/`trace_raw`,
+//!   `AdminRpc::call_raw`); disable it to build against typed responses only
+//! - `testkit` - [`MockTenderly`](testkit::MockTenderly), a local mock server
+//!   with canned simulation, VNet and Admin RPC responses for downstream tests,
+//!   and [`SimulationAssert`](testkit::SimulationAssert) for checking results
+//! - `schemars` - `JsonSchema` derives on request types and their JSON
+//!   Schemas in [`schema`]
+//! - `vcr` - Record API interactions to a cassette file and replay them
+//!   offline ([`vcr`])
+//! - `ws` - Subscribe to VNet blocks and logs over websocket
+//!   ([`vnets::subscription`])
 
 mod client;
 mod error;
+mod u256;
 
+#[cfg(feature = "abi")]
+pub mod abi;
 pub mod actions;
 pub mod alerts;
 pub mod contracts;
 pub mod delivery_channels;
+pub mod gas;
 pub mod networks;
+pub mod prelude;
+#[cfg(feature = "schemars")]
+pub mod schema;
 pub mod simulation;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 pub mod utils;
+#[cfg(feature = "vcr")]
+pub mod vcr;
 pub mod vnets;
 pub mod wallets;
 
-pub use client::{Client, Config, API_BASE_URL};
+pub use client::{
+    ApiVersion, Client, Config, Cursor, CursorPage, Middleware, PreparedRequest, RetryPolicy,
+    Usage, API_BASE_URL, API_HOST, DASHBOARD_URL,
+};
 pub use error::{Error, Result};
+pub use u256::U256;
 
 // Re-export commonly used types at the crate root
 pub use actions::{ActionTrigger, CreateActionRequest};
 pub use alerts::{AlertType, CreateAlertRequest};
 pub use contracts::{AddContractRequest, Contract};
+pub use networks::Chain;
 pub use simulation::{SimulationRequest, SimulationResponse, SimulationType};
 pub use vnets::{CreateVNetRequest, VNet};
 pub use wallets::{AddWalletRequest, AddWalletResponse, WalletOnNetwork};
@@ -175,4 +216,15 @@ impl Client {
     pub fn networks(&self) -> networks::NetworksApi<'_> {
         networks::NetworksApi::new(self)
     }
+
+    /// Access the Gas API
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let estimate = client.gas().gas_price("1").await?;
+    /// ```
+    pub fn gas(&self) -> gas::GasApi<'_> {
+        gas::GasApi::new(self)
+    }
 }
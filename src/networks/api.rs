@@ -1,18 +1,24 @@
 //! Networks API operations
 
 use super::types::*;
-use crate::client::Client;
+use crate::client::{ApiVersion, Client};
 use crate::error::Result;
+use std::borrow::Cow;
 
 /// Networks API client
 pub struct NetworksApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> NetworksApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new Networks API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// Get list of supported networks
@@ -94,4 +100,14 @@ impl<'a> NetworksApi<'a> {
             .filter(|n| n.vnet_supported())
             .collect())
     }
+
+    /// List chain IDs that can be forked into Virtual TestNets
+    ///
+    /// Pass the result to
+    /// [`CreateVNetRequest::validate_against`](crate::vnets::CreateVNetRequest::validate_against)
+    /// to catch unsupported networks before creating a VNet.
+    pub async fn vnet_chain_ids(&self) -> Result<Vec<u64>> {
+        let networks = self.with_vnet_support().await?;
+        Ok(networks.iter().filter_map(Network::chain_id_u64).collect())
+    }
 }
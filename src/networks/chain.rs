@@ -0,0 +1,214 @@
+//! Well-known chain IDs
+
+/// Common EVM chains with their chain IDs
+///
+/// Chains not listed here round-trip through [`Chain::Unknown`], so any
+/// numeric ID can still be used.
+///
+/// # Example
+///
+/// ```
+/// use tndrly::networks::Chain;
+///
+/// assert_eq!(Chain::Polygon.id(), 137);
+/// assert_eq!(Chain::from_id(8453), Chain::Base);
+/// assert_eq!(Chain::from_id(999_999), Chain::Unknown(999_999));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Chain {
+    /// Ethereum mainnet (1)
+    Mainnet,
+    /// Ethereum Sepolia testnet (11155111)
+    Sepolia,
+    /// Ethereum Holesky testnet (17000)
+    Holesky,
+    /// OP Mainnet (10)
+    Optimism,
+    /// OP Sepolia testnet (11155420)
+    OptimismSepolia,
+    /// BNB Smart Chain (56)
+    Bsc,
+    /// Gnosis Chain (100)
+    Gnosis,
+    /// Polygon PoS (137)
+    Polygon,
+    /// Polygon Amoy testnet (80002)
+    PolygonAmoy,
+    /// Fantom Opera (250)
+    Fantom,
+    /// zkSync Era (324)
+    ZkSync,
+    /// Base (8453)
+    Base,
+    /// Base Sepolia testnet (84532)
+    BaseSepolia,
+    /// Arbitrum One (42161)
+    Arbitrum,
+    /// Arbitrum Sepolia testnet (421614)
+    ArbitrumSepolia,
+    /// Avalanche C-Chain (43114)
+    Avalanche,
+    /// Linea (59144)
+    Linea,
+    /// Blast (81457)
+    Blast,
+    /// Scroll (534352)
+    Scroll,
+    /// Any other chain, by ID
+    Unknown(u64),
+}
+
+impl Chain {
+    const KNOWN: [(Chain, u64, &'static str); 19] = [
+        (Chain::Mainnet, 1, "mainnet"),
+        (Chain::Sepolia, 11_155_111, "sepolia"),
+        (Chain::Holesky, 17_000, "holesky"),
+        (Chain::Optimism, 10, "optimism"),
+        (Chain::OptimismSepolia, 11_155_420, "optimism-sepolia"),
+        (Chain::Bsc, 56, "bsc"),
+        (Chain::Gnosis, 100, "gnosis"),
+        (Chain::Polygon, 137, "polygon"),
+        (Chain::PolygonAmoy, 80_002, "polygon-amoy"),
+        (Chain::Fantom, 250, "fantom"),
+        (Chain::ZkSync, 324, "zksync"),
+        (Chain::Base, 8_453, "base"),
+        (Chain::BaseSepolia, 84_532, "base-sepolia"),
+        (Chain::Arbitrum, 42_161, "arbitrum"),
+        (Chain::ArbitrumSepolia, 421_614, "arbitrum-sepolia"),
+        (Chain::Avalanche, 43_114, "avalanche"),
+        (Chain::Linea, 59_144, "linea"),
+        (Chain::Blast, 81_457, "blast"),
+        (Chain::Scroll, 534_352, "scroll"),
+    ];
+
+    /// Get the chain ID
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Unknown(id) => *id,
+            known => Self::KNOWN
+                .iter()
+                .find(|(chain, _, _)| chain == known)
+                .map(|(_, id, _)| *id)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Look up a chain by ID, falling back to [`Chain::Unknown`]
+    #[must_use]
+    pub fn from_id(id: u64) -> Self {
+        Self::KNOWN
+            .iter()
+            .find(|(_, known, _)| *known == id)
+            .map(|(chain, _, _)| *chain)
+            .unwrap_or(Self::Unknown(id))
+    }
+
+    /// Get the chain name, or `None` for [`Chain::Unknown`]
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        Self::KNOWN
+            .iter()
+            .find(|(chain, _, _)| chain == self)
+            .map(|(_, _, name)| *name)
+    }
+
+    /// Get the chain ID as the string form used by the API (e.g., `"137"`)
+    #[must_use]
+    pub fn network_id(&self) -> String {
+        self.id().to_string()
+    }
+}
+
+impl From<Chain> for u64 {
+    fn from(chain: Chain) -> Self {
+        chain.id()
+    }
+}
+
+impl From<u64> for Chain {
+    fn from(id: u64) -> Self {
+        Self::from_id(id)
+    }
+}
+
+impl std::fmt::Display for Chain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.id()),
+        }
+    }
+}
+
+impl std::str::FromStr for Chain {
+    type Err = String;
+
+    /// Parse a chain name (e.g., `polygon`, `base-sepolia`) or numeric ID
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(id) = s.parse::<u64>() {
+            return Ok(Self::from_id(id));
+        }
+        let name = s.to_lowercase().replace('_', "-");
+        let name = match name.as_str() {
+            "ethereum" | "eth" | "homestead" => "mainnet",
+            "matic" => "polygon",
+            "arbitrum-one" => "arbitrum",
+            "op" | "op-mainnet" => "optimism",
+            "bnb" | "binance" => "bsc",
+            "avax" => "avalanche",
+            other => other,
+        }
+        .to_string();
+        Self::KNOWN
+            .iter()
+            .find(|(_, _, known)| *known == name)
+            .map(|(chain, _, _)| *chain)
+            .ok_or_else(|| format!("Unknown chain: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_ids_roundtrip() {
+        for (chain, id) in [
+            (Chain::Mainnet, 1),
+            (Chain::Polygon, 137),
+            (Chain::Arbitrum, 42_161),
+            (Chain::Base, 8_453),
+            (Chain::Sepolia, 11_155_111),
+        ] {
+            assert_eq!(chain.id(), id);
+            assert_eq!(Chain::from_id(id), chain);
+        }
+
+        // Every listed chain maps back to itself
+        for (chain, id, _) in Chain::KNOWN {
+            assert_eq!(Chain::from_id(id), chain);
+            assert_eq!(chain.id(), id);
+        }
+    }
+
+    #[test]
+    fn test_chain_unknown_fallback() {
+        let chain = Chain::from_id(31_337);
+        assert_eq!(chain, Chain::Unknown(31_337));
+        assert_eq!(chain.id(), 31_337);
+        assert_eq!(chain.name(), None);
+        assert_eq!(chain.to_string(), "31337");
+    }
+
+    #[test]
+    fn test_chain_from_str() {
+        assert_eq!("polygon".parse::<Chain>().unwrap(), Chain::Polygon);
+        assert_eq!("Base_Sepolia".parse::<Chain>().unwrap(), Chain::BaseSepolia);
+        assert_eq!("ethereum".parse::<Chain>().unwrap(), Chain::Mainnet);
+        assert_eq!("10".parse::<Chain>().unwrap(), Chain::Optimism);
+        assert!("notachain".parse::<Chain>().is_err());
+        assert_eq!(Chain::Arbitrum.to_string(), "arbitrum");
+    }
+}
@@ -1,9 +1,12 @@
 //! Networks API
 //!
-//! Query supported blockchain networks on Tenderly.
+//! Query supported blockchain networks on Tenderly, and refer to common
+//! chains by name with [`Chain`].
 
 mod api;
+mod chain;
 mod types;
 
 pub use api::NetworksApi;
+pub use chain::Chain;
 pub use types::*;
@@ -0,0 +1,34 @@
+//! Commonly used types
+//!
+//! ```no_run
+//! use tndrly::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Error> {
+//!     let client = Client::new(Config::new("access-key", "account", "project"))?;
+//!
+//!     let request = SimulationRequest::new(
+//!         "0x0000000000000000000000000000000000000000",
+//!         "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+//!         "0x70a08231000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+//!     )
+//!     .network_id("1");
+//!
+//!     let response: SimulationResponse = client.simulation().simulate(&request).await?;
+//!     println!("Gas used: {}", response.simulation.gas_used);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! `Result` is not re-exported so that glob imports don't shadow the standard
+//! library's; use [`crate::Result`] for the crate's alias.
+
+pub use crate::client::{Client, Config};
+pub use crate::error::Error;
+pub use crate::simulation::{
+    BundleSimulationRequest, SimulationRequest, SimulationResponse, SimulationType,
+};
+pub use crate::vnets::{
+    CreateVNetRequest, ForkVNetRequest, SendVNetTransactionRequest, UpdateVNetRequest, VNet,
+    VNetStatus, VNetTransaction,
+};
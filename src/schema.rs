@@ -0,0 +1,107 @@
+//! JSON Schema for request types
+//!
+//! Requires the `schemars` feature. Every request type also implements
+//! [`schemars::JsonSchema`], so other schemas can be generated with
+//! [`schemars::schema_for!`].
+//!
+//! # Example
+//!
+//! ```
+//! let schema = tndrly::schema::simulation_request_schema();
+//! println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+//! ```
+
+use schemars::Schema;
+
+/// JSON Schema for [`SimulationRequest`](crate::simulation::SimulationRequest)
+pub fn simulation_request_schema() -> Schema {
+    schemars::schema_for!(crate::simulation::SimulationRequest)
+}
+
+/// JSON Schema for [`CreateVNetRequest`](crate::vnets::CreateVNetRequest)
+pub fn create_vnet_request_schema() -> Schema {
+    schemars::schema_for!(crate::vnets::CreateVNetRequest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required(schema: &Schema) -> Vec<&str> {
+        schema.as_value()["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field.as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_simulation_request_schema() {
+        let schema = simulation_request_schema();
+        let properties = schema.as_value()["properties"].as_object().unwrap();
+
+        for field in [
+            "network_id",
+            "from",
+            "to",
+            "input",
+            "gas",
+            "type",
+            "state_objects",
+        ] {
+            assert!(properties.contains_key(field), "missing {field}");
+        }
+        // Renamed and skipped fields use their wire names
+        assert!(!properties.contains_key("transaction_type"));
+        assert!(!properties.contains_key("skip_default_gas"));
+
+        let required = required(&schema);
+        assert!(required.contains(&"from"));
+        assert!(required.contains(&"input"));
+        assert!(!required.contains(&"to"));
+        assert!(!required.contains(&"gas"));
+    }
+
+    #[test]
+    fn test_create_vnet_request_schema() {
+        let schema = create_vnet_request_schema();
+        let properties = schema.as_value()["properties"].as_object().unwrap();
+
+        assert!(properties.contains_key("fork_config"));
+        assert!(properties.contains_key("sync_state_config"));
+        let required = required(&schema);
+        for field in [
+            "slug",
+            "display_name",
+            "fork_config",
+            "virtual_network_config",
+        ] {
+            assert!(required.contains(&field), "{field} should be required");
+        }
+        assert!(!required.contains(&"sync_state_config"));
+    }
+
+    #[test]
+    fn test_request_types_implement_json_schema() {
+        fn properties<T: schemars::JsonSchema>() -> Vec<String> {
+            let schema = schemars::schema_for!(T);
+            schema.as_value()["properties"]
+                .as_object()
+                .map(|properties| properties.keys().cloned().collect())
+                .unwrap_or_default()
+        }
+
+        assert!(properties::<crate::contracts::AddContractRequest>().contains(&"address".into()));
+        assert!(properties::<crate::wallets::AddWalletRequest>().contains(&"address".into()));
+        assert!(!properties::<crate::alerts::CreateAlertRequest>().is_empty());
+        assert!(!properties::<crate::alerts::CreateWebhookRequest>().is_empty());
+        assert!(!properties::<crate::actions::CreateActionRequest>().is_empty());
+        assert!(!properties::<crate::vnets::SendVNetTransactionRequest>().is_empty());
+        assert!(!properties::<crate::vnets::VNetSimulationRequest>().is_empty());
+
+        // Secrets are plain strings on the wire
+        let secret = schemars::schema_for!(crate::actions::ActionSecret);
+        assert_eq!(secret.as_value()["properties"]["value"]["type"], "string");
+    }
+}
@@ -1,22 +1,35 @@
 //! Simulation API operations
 
 use super::types::*;
-use crate::client::{encode_path_segment, Client};
-use crate::error::Result;
+use crate::client::{encode_path_segment, ApiVersion, Client, PreparedRequest};
+use crate::error::{Error, Result};
+use std::borrow::Cow;
 
 /// Simulation API client
 pub struct SimulationApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> SimulationApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new simulation API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// Simulate a single transaction
     ///
+    /// The request is checked and its addresses normalized with
+    /// [`SimulationRequest::normalized`] first. Requests without gas get the
+    /// client's default gas limit (see [`Config::with_default_gas`]) unless
+    /// [`SimulationRequest::clear_gas`] was used.
+    ///
+    /// [`Config::with_default_gas`]: crate::Config::with_default_gas
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -28,7 +41,70 @@ impl<'a> SimulationApi<'a> {
     /// let result = client.simulation().simulate(&request).await?;
     /// ```
     pub async fn simulate(&self, request: &SimulationRequest) -> Result<SimulationResponse> {
-        self.client.post("/simulate", request).await
+        let request = self.normalize(request)?;
+        self.client.post("/simulate", &request).await
+    }
+
+    /// Build the simulate request without sending it (dry run)
+    ///
+    /// Pair with [`Client::to_curl`] to reproduce a simulation outside Rust.
+    pub fn prepare(&self, request: &SimulationRequest) -> Result<PreparedRequest> {
+        let request = self.normalize(request)?;
+        self.client.prepare("POST", "/simulate", Some(&request))
+    }
+
+    /// Normalize request addresses using the client's checksum setting and
+    /// fill in the default gas limit
+    fn normalize(&self, request: &SimulationRequest) -> Result<SimulationRequest> {
+        let config = self.client.config();
+        let mut request = request.normalized(config.strict_checksums)?;
+        if request.gas.is_none() && !std::mem::take(&mut request.skip_default_gas) {
+            request.gas = config.default_gas_for(&request.network_id);
+        }
+        Ok(request)
+    }
+
+    /// Simulate many independent requests with bounded concurrency
+    ///
+    /// At most `concurrency` requests are in flight at once (a value of `0`
+    /// is treated as `1`). Results are returned in input order, one per
+    /// request, so individual failures do not abort the batch. Rate-limited
+    /// requests surface as [`Error::RateLimited`] in their slot; lower
+    /// `concurrency` to stay within quota.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let results = client.simulation().simulate_many(&requests, 4).await;
+    /// let failures = results.iter().filter(|r| r.is_err()).count();
+    /// ```
+    pub async fn simulate_many(
+        &self,
+        requests: &[SimulationRequest],
+        concurrency: usize,
+    ) -> Vec<Result<SimulationResponse>> {
+        use futures_util::stream::{self, StreamExt};
+
+        stream::iter(requests.iter().map(|request| self.simulate(request)))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Simulate a signed raw transaction
+    ///
+    /// Decodes the RLP envelope (legacy, EIP-2930 or EIP-1559), recovers the
+    /// sender from the signature, and simulates the resulting request.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = client.simulation().simulate_raw("1", "0x02f8...").await?;
+    /// ```
+    #[cfg(feature = "raw-tx")]
+    pub async fn simulate_raw(&self, network_id: &str, raw_tx: &str) -> Result<SimulationResponse> {
+        let request = SimulationRequest::from_raw_transaction(network_id, raw_tx)?;
+        self.simulate(&request).await
     }
 
     /// Simulate a bundle of transactions in sequence
@@ -38,7 +114,43 @@ impl<'a> SimulationApi<'a> {
         &self,
         request: &BundleSimulationRequest,
     ) -> Result<BundleSimulationResponse> {
-        self.client.post("/simulate-bundle", request).await
+        let request = BundleSimulationRequest {
+            simulations: request
+                .simulations
+                .iter()
+                .map(|simulation| self.normalize(simulation))
+                .collect::<Result<_>>()?,
+            state_objects: request.state_objects.clone(),
+        };
+        self.client.post("/simulate-bundle", &request).await
+    }
+
+    /// Get a saved bundle by the ID of its first simulation
+    ///
+    /// Save the bundle's simulations (e.g., with
+    /// [`save_always`](SimulationRequest::save_always)) and pass
+    /// [`BundleSimulationResponse::root_id`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = client.simulation().simulate_bundle(&bundle).await?;
+    /// let root = result.root_id().unwrap();
+    /// let saved = client.simulation().get_bundle(root).await?;
+    /// println!("{} transactions", saved.bundle.transactions.len());
+    /// ```
+    pub async fn get_bundle(&self, root_id: &str) -> Result<SavedBundle> {
+        let bundle: BundleSimulationResponse = self
+            .client
+            .get(&format!(
+                "/simulations/{}/bundle",
+                encode_path_segment(root_id)
+            ))
+            .await?;
+        Ok(SavedBundle {
+            root_id: root_id.to_string(),
+            bundle,
+        })
     }
 
     /// List saved simulations
@@ -80,29 +192,106 @@ impl<'a> SimulationApi<'a> {
             .await
     }
 
+    /// Delete a saved simulation
+    ///
+    /// Returns [`Error::NotFound`](crate::Error::NotFound) if the simulation
+    /// does not exist.
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/simulations/{}", encode_path_segment(id)))
+            .await
+    }
+
+    /// Delete several saved simulations
+    ///
+    /// Deletes each ID in turn and returns the per-ID results in input
+    /// order, so one failure does not stop the rest.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for (id, result) in client.simulation().delete_many(&["sim-1", "sim-2"]).await {
+    ///     if let Err(e) = result {
+    ///         eprintln!("Failed to delete {}: {}", id, e);
+    ///     }
+    /// }
+    /// ```
+    pub async fn delete_many(&self, ids: &[&str]) -> Vec<(String, Result<()>)> {
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            results.push((id.to_string(), self.delete(id).await));
+        }
+        results
+    }
+
     /// Get simulation info/metadata by ID
-    pub async fn info(&self, id: &str) -> Result<serde_json::Value> {
+    pub async fn info(&self, id: &str) -> Result<SimulationInfo> {
         self.client
             .get(&format!("/simulations/{}/info", encode_path_segment(id)))
             .await
     }
 
-    /// Share a simulation publicly
+    /// Get simulation info as raw JSON
     ///
-    /// Returns the public URL for the shared simulation.
-    pub async fn share(&self, id: &str) -> Result<String> {
-        let empty: serde_json::Value = serde_json::json!({});
+    /// Includes fields [`SimulationInfo`] does not model, such as the block
+    /// header.
+    #[cfg(feature = "dynamic-json")]
+    pub async fn info_raw(&self, id: &str) -> Result<serde_json::Value> {
         self.client
-            .post_no_response(
+            .get(&format!("/simulations/{}/info", encode_path_segment(id)))
+            .await
+    }
+
+    /// Share a simulation publicly
+    ///
+    /// Returns the simulation ID and its public URL. The URL returned by the
+    /// API is used when present; otherwise the default dashboard URL is built.
+    pub async fn share(&self, id: &str) -> Result<SharedSimulation> {
+        self.share_with_options(id, &ShareOptions::default()).await
+    }
+
+    /// Share a simulation publicly with options such as link expiry
+    ///
+    /// If the endpoint does not support an option (e.g., expiry), the
+    /// simulation is shared permanently until [`unshare`](Self::unshare) is
+    /// called.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let options = ShareOptions::new().expires_in(Duration::from_secs(86_400));
+    /// let shared = client.simulation().share_with_options("sim-id", &options).await?;
+    /// println!("{}", shared.url);
+    /// ```
+    pub async fn share_with_options(
+        &self,
+        id: &str,
+        options: &ShareOptions,
+    ) -> Result<SharedSimulation> {
+        let response: ShareResponse = self
+            .client
+            .post_optional(
                 &format!("/simulations/{}/share", encode_path_segment(id)),
-                &empty,
+                options,
             )
-            .await?;
+            .await?
+            .unwrap_or_default();
+
+        // Prefer the URL from the API (custom domains), else build the default one
+        let url = response.url.unwrap_or_else(|| {
+            format!(
+                "https://dashboard.tenderly.co/shared/simulation/{}",
+                encode_path_segment(id)
+            )
+        });
 
-        Ok(format!(
-            "https://dashboard.tenderly.co/shared/simulation/{}",
-            encode_path_segment(id)
-        ))
+        Ok(SharedSimulation {
+            id: id.to_string(),
+            url,
+            shared_id: response.shared_id,
+        })
     }
 
     /// Unshare a simulation (make it private)
@@ -117,11 +306,99 @@ impl<'a> SimulationApi<'a> {
     }
 
     /// Trace an existing transaction
-    pub async fn trace(&self, hash: &str) -> Result<serde_json::Value> {
+    pub async fn trace(&self, hash: &str) -> Result<TransactionTrace> {
         self.client
             .get(&format!("/trace/{}", encode_path_segment(hash)))
             .await
     }
+
+    /// Trace an existing transaction, returning raw JSON
+    #[cfg(feature = "dynamic-json")]
+    pub async fn trace_raw(&self, hash: &str) -> Result<serde_json::Value> {
+        self.client
+            .get(&format!("/trace/{}", encode_path_segment(hash)))
+            .await
+    }
+
+    /// Simulate a request as if it executed right before an existing transaction
+    ///
+    /// Looks up `tx_hash` with [`trace`](Self::trace) to find its block and
+    /// index, then sets `network_id`, `block_number` and `transaction_index`
+    /// on a copy of `request` before simulating. This costs one extra API
+    /// call per invocation.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = client
+    ///     .simulation()
+    ///     .simulate_before_tx("1", "0xabc...", &request)
+    ///     .await?;
+    /// ```
+    pub async fn simulate_before_tx(
+        &self,
+        network_id: &str,
+        tx_hash: &str,
+        request: &SimulationRequest,
+    ) -> Result<SimulationResponse> {
+        let trace = self.trace(tx_hash).await?;
+        let position = trace.block_number.zip(trace.transaction_index);
+        let (block_number, transaction_index) = position.ok_or_else(|| {
+            Error::invalid_param(format!(
+                "Transaction {} has no block number or index",
+                tx_hash
+            ))
+        })?;
+
+        let request = request
+            .clone()
+            .network_id(network_id)
+            .block_number(block_number)
+            .transaction_index(transaction_index);
+        self.simulate(&request).await
+    }
+
+    /// Simulate with a generated access list, then again with it applied
+    ///
+    /// The first run enables `generate_access_list`; the second clears it
+    /// and sets `access_list` to the generated list. Legacy transactions are
+    /// upgraded to EIP-2930 (type 1); other types are kept. Compare the two
+    /// runs with [`AccessListSimulation::gas_saved`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Api`] if the first response has no
+    /// `generated_access_list`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = client
+    ///     .simulation()
+    ///     .simulate_with_optimal_access_list(&request)
+    ///     .await?;
+    /// println!("Access list saves {} gas", result.gas_saved());
+    /// ```
+    pub async fn simulate_with_optimal_access_list(
+        &self,
+        request: &SimulationRequest,
+    ) -> Result<AccessListSimulation> {
+        let initial = self
+            .simulate(&request.clone().generate_access_list(true))
+            .await?;
+        let access_list = initial.generated_access_list.clone().ok_or_else(|| {
+            Error::api(
+                0,
+                "Simulation response did not include generated_access_list",
+            )
+        })?;
+
+        let mut request = request.clone().access_list(access_list);
+        request.generate_access_list = None;
+        let optimized = self.simulate(&request).await?;
+
+        Ok(AccessListSimulation { initial, optimized })
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -167,6 +444,204 @@ mod tests {
         assert!(overrides.contains_key("0xcccc"));
     }
 
+    #[tokio::test]
+    async fn test_simulate_with_optimal_access_list() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut initial = bundle_result("sim1", 30_000, true, 0);
+        initial["generated_access_list"] = serde_json::json!([{
+            "address": "0x2222222222222222222222222222222222222222",
+            "storage_keys": ["0x0"]
+        }]);
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulate"))
+            .and(body_partial_json(
+                serde_json::json!({"generate_access_list": true}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(initial))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulate"))
+            .and(body_partial_json(serde_json::json!({
+                "type": 2,
+                "access_list": [{
+                    "address": "0x2222222222222222222222222222222222222222",
+                    "storage_keys": ["0x0"]
+                }]
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(bundle_result("sim2", 28_000, true, 0)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let request = SimulationRequest::new(
+            "0x1111111111111111111111111111111111111111",
+            "0x2222222222222222222222222222222222222222",
+            "0x",
+        )
+        .transaction_type(2);
+        let result = client
+            .simulation()
+            .simulate_with_optimal_access_list(&request)
+            .await
+            .unwrap();
+
+        assert_eq!(result.initial.simulation.id, "sim1");
+        assert_eq!(result.optimized.simulation.id, "sim2");
+        assert_eq!(result.access_list().len(), 1);
+        assert_eq!(result.gas_saved(), 2_000);
+
+        let requests = server.received_requests().await.unwrap();
+        let second: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert!(second.get("generate_access_list").is_none());
+    }
+
+    #[test]
+    fn test_simulation_request_round_trip() {
+        let request = SimulationRequest::new(
+            "0x1111111111111111111111111111111111111111",
+            "0x2222222222222222222222222222222222222222",
+            "0xa9059cbb",
+        )
+        .network_id("137")
+        .value_wei(1_000u128)
+        .gas(100_000)
+        .eip1559_fees(30, 2)
+        .block_number(19_000_000)
+        .save(true)
+        .override_balance("0x1111111111111111111111111111111111111111", "1000")
+        .override_storage("0x2222222222222222222222222222222222222222", "0x0", "0x1")
+        .override_nonce("0x1111111111111111111111111111111111111111", 7)
+        .block_timestamp(1_700_000_000)
+        .add_access_list_entry(
+            AccessListEntry::new("0x2222222222222222222222222222222222222222").storage_key("0x0"),
+        );
+
+        let json = serde_json::to_string(&request).unwrap();
+        let parsed: SimulationRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, request);
+
+        // Skipped fields come back as their defaults
+        let deploy =
+            SimulationRequest::deploy("0x1111111111111111111111111111111111111111", "0x60");
+        let parsed: SimulationRequest =
+            serde_json::from_value(serde_json::to_value(&deploy).unwrap()).unwrap();
+        assert_eq!(parsed, deploy);
+        assert!(parsed.to.is_empty());
+    }
+
+    #[test]
+    fn test_block_header_cancun_fields() {
+        let root = "0x4a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9";
+        let request = SimulationRequest::new(
+            "0x1111111111111111111111111111111111111111",
+            "0x2222222222222222222222222222222222222222",
+            "0x",
+        )
+        .block_timestamp(1_710_338_135)
+        .excess_blob_gas(0x20000)
+        .parent_beacon_block_root(root);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["block_header"],
+            serde_json::json!({
+                "timestamp": "0x65f1b057",
+                "excessBlobGas": "0x20000",
+                "parentBeaconBlockRoot": root,
+            })
+        );
+
+        // Unset fields are omitted
+        let json = serde_json::to_value(BlockHeaderOverride::default()).unwrap();
+        assert_eq!(json, serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_simulation_request_from_fixture() {
+        let request: SimulationRequest = serde_json::from_str(
+            r#"{"network_id": "1", "from": "0x1", "to": "0x2", "input": "0x", "type": 2}"#,
+        )
+        .unwrap();
+        assert_eq!(request.simulation_type, "full");
+        assert_eq!(request.transaction_type, Some(2));
+        assert!(!request.save);
+    }
+
+    #[test]
+    fn test_simulation_request_nonce_override() {
+        let request = SimulationRequest::new("0xAAAA", "0x5678", "0x")
+            .override_balance("0xAAAA", "0x1")
+            .override_nonce("0xAAAA", 7);
+
+        let overrides = request.state_objects.as_ref().unwrap();
+        assert_eq!(overrides["0xaaaa"].nonce, Some(7));
+        assert_eq!(overrides["0xaaaa"].balance.as_deref(), Some("0x1"));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["state_objects"]["0xaaaa"]["nonce"], 7);
+
+        // Omitted when not overridden
+        let json = serde_json::to_value(
+            SimulationRequest::new("0x1234", "0x5678", "0x").override_code("0xcccc", "0x60"),
+        )
+        .unwrap();
+        assert!(json["state_objects"]["0xcccc"].get("nonce").is_none());
+    }
+
+    #[test]
+    fn test_simulation_request_account_override_matches_fluent() {
+        let fluent = SimulationRequest::new("0x1234", "0x5678", "0x")
+            .override_balance("0xAAAA", "0x1")
+            .override_nonce("0xAAAA", 3)
+            .override_code("0xAAAA", "0x6080")
+            .override_storage("0xAAAA", "0x0", "0x1")
+            .override_balance("0xbbbb", "0x2");
+
+        let account = AccountOverride::new()
+            .balance("0x1")
+            .nonce(3)
+            .code("0x6080")
+            .storage("0x0", "0x1");
+        let typed = SimulationRequest::new("0x1234", "0x5678", "0x")
+            .override_account("0xAAAA", account)
+            .override_accounts(std::collections::HashMap::from([(
+                "0xbbbb".to_string(),
+                AccountOverride {
+                    balance: Some("0x2".into()),
+                    ..Default::default()
+                },
+            )]));
+
+        assert_eq!(
+            serde_json::to_value(&fluent).unwrap(),
+            serde_json::to_value(&typed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_simulation_request_account_override_merges() {
+        let request = SimulationRequest::new("0x1234", "0x5678", "0x")
+            .override_storage("0xaaaa", "0x0", "0x1")
+            .override_account(
+                "0xaaaa",
+                AccountOverride::new().storage("0x1", "0x2").nonce(1),
+            );
+
+        let account = &request.state_objects.unwrap()["0xaaaa"];
+        assert_eq!(account.nonce, Some(1));
+        assert_eq!(account.storage.as_ref().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_simulation_request_gas_estimation() {
         let request = SimulationRequest::new("0x1234", "0x5678", "0xabcd")
@@ -195,6 +670,26 @@ mod tests {
         assert_eq!(request.transaction_type, Some(1));
     }
 
+    #[test]
+    fn test_access_list_keeps_typed_transactions() {
+        let entry = AccessListEntry::new("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let request = SimulationRequest::new("0x1234", "0x5678", "0xabcd");
+
+        for tx_type in [2, 3, 4] {
+            let typed = request
+                .clone()
+                .transaction_type(tx_type)
+                .access_list(vec![entry.clone()])
+                .add_access_list_entry(entry.clone());
+            assert_eq!(typed.transaction_type, Some(tx_type));
+        }
+
+        let legacy = request
+            .transaction_type(0)
+            .add_access_list_entry(entry.clone());
+        assert_eq!(legacy.transaction_type, Some(1));
+    }
+
     #[test]
     fn test_simulation_request_add_access_list_entry() {
         let entry1 = AccessListEntry::new("0xaaaa");
@@ -259,4 +754,1194 @@ mod tests {
         assert!(json.contains("\"estimate_gas\":true"));
         assert!(json.contains("\"generate_access_list\":true"));
     }
+
+    #[test]
+    fn test_simulation_request_source_and_description() {
+        let request = SimulationRequest::new("0x1234", "0x5678", "0xabcd");
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("source").is_none());
+        assert!(json.get("description").is_none());
+
+        let request = request
+            .source("liquidation-bot")
+            .description("Liquidate position 42")
+            .save(true);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["source"], "liquidation-bot");
+        assert_eq!(json["description"], "Liquidate position 42");
+    }
+
+    #[test]
+    fn test_simulation_request_eip1559_fees() {
+        let request =
+            SimulationRequest::new("0x1", "0x2", "0x").eip1559_fees(30_000_000_000, 1_500_000_000);
+        assert_eq!(request.max_fee_per_gas.as_deref(), Some("61500000000"));
+        assert_eq!(
+            request.max_priority_fee_per_gas.as_deref(),
+            Some("1500000000")
+        );
+        assert_eq!(request.transaction_type, Some(2));
+
+        let request =
+            SimulationRequest::new("0x1", "0x2", "0x").eip1559_fees_with_multiplier(10, 1, 3);
+        assert_eq!(request.max_fee_per_gas.as_deref(), Some("31"));
+
+        let price = crate::gas::GasPrice::from_base_fee(u128::MAX, 1);
+        assert_eq!(price.max_fee_per_gas, u128::MAX);
+    }
+
+    #[test]
+    fn test_simulation_request_type_call_order() {
+        let base = SimulationRequest::new("0x1234", "0x5678", "0x");
+
+        // Explicit type after a fee setter wins
+        let legacy = base.clone().eip1559_fees(30, 2).transaction_type(0);
+        assert_eq!(legacy.transaction_type, Some(0));
+        assert!(legacy.max_fee_per_gas.is_some());
+
+        // A fee setter after an explicit type wins
+        let request = base.clone().transaction_type(0).eip1559_fees(30, 2);
+        assert_eq!(request.transaction_type, Some(2));
+
+        // Blob type survives later fee setters
+        let blob = base.clone().max_fee_per_blob_gas("0x1").eip1559_fees(30, 2);
+        assert_eq!(blob.transaction_type, Some(3));
+
+        let inferred = base.eip1559_fees(30, 2).clear_transaction_type();
+        assert_eq!(inferred.transaction_type, None);
+        assert!(serde_json::to_value(&inferred)
+            .unwrap()
+            .get("type")
+            .is_none());
+    }
+
+    #[test]
+    fn test_simulation_request_authorization_list() {
+        let authorization = Authorization::new(
+            1,
+            "0x3333333333333333333333333333333333333333",
+            7,
+            1,
+            "0xaa",
+            "0xbb",
+        );
+        let request = SimulationRequest::new("0x1234", "0x1234", "0x")
+            .add_authorization(authorization.clone())
+            .eip1559_fees(30, 2);
+        assert_eq!(request.transaction_type, Some(4));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], 4);
+        assert_eq!(
+            json["authorization_list"],
+            serde_json::json!([{
+                "chain_id": 1,
+                "address": "0x3333333333333333333333333333333333333333",
+                "nonce": 7,
+                "y_parity": 1,
+                "r": "0xaa",
+                "s": "0xbb"
+            }])
+        );
+
+        let send = request.to_vnet_send();
+        assert_eq!(send.authorization_list, Some(vec![authorization]));
+        assert_eq!(send.transaction_type, Some(4));
+
+        let plain = serde_json::to_value(SimulationRequest::new("0x1234", "0x5678", "0x")).unwrap();
+        assert!(plain.get("authorization_list").is_none());
+    }
+
+    #[test]
+    fn test_simulation_request_blob_fields() {
+        let hash = format!("0x01{}", "ab".repeat(31));
+        let request = SimulationRequest::new("0x1234", "0x5678", "0x")
+            .max_fee_per_blob_gas("1000000000")
+            .blob_versioned_hashes(vec![hash.clone()]);
+        assert_eq!(request.transaction_type, Some(3));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], 3);
+        assert_eq!(json["max_fee_per_blob_gas"], "1000000000");
+        assert_eq!(json["blob_versioned_hashes"], serde_json::json!([hash]));
+
+        // EIP-1559 fees don't downgrade a blob transaction
+        let request = request.max_fee_per_gas("100").max_priority_fee_per_gas("1");
+        assert_eq!(request.transaction_type, Some(3));
+
+        let plain = serde_json::to_value(SimulationRequest::new("0x1234", "0x5678", "0x")).unwrap();
+        assert!(plain.get("max_fee_per_blob_gas").is_none());
+        assert!(plain.get("blob_versioned_hashes").is_none());
+    }
+
+    #[test]
+    fn test_call_trace_gas_attribution() {
+        let mut json = bundle_result("sim1", 100_000, true, 0);
+        json["transaction"]["transaction_info"] = serde_json::json!({
+            "call_trace": {
+                "call_type": "CALL",
+                "function_name": "swap",
+                "gas_used": 100_000,
+                "calls": [
+                    {
+                        "call_type": "CALL",
+                        "function_name": "transferFrom",
+                        "gas_used": 30_000,
+                        "calls": [
+                            {"call_type": "STATICCALL", "function_name": "balanceOf", "gas_used": 5_000, "calls": null}
+                        ]
+                    },
+                    {"call_type": "DELEGATECALL", "function_name": "hook", "gas_used": 20_000}
+                ]
+            }
+        });
+        let response: SimulationResponse = serde_json::from_value(json).unwrap();
+        let trace = response.call_trace().unwrap();
+
+        assert_eq!(trace.self_gas(), 50_000);
+        assert_eq!(trace.calls[0].self_gas(), 25_000);
+        assert_eq!(trace.calls[0].calls[0].self_gas(), 5_000);
+        assert_eq!(trace.total_gas_used(), 100_000);
+        assert_eq!(trace.calls[0].total_gas_used(), 30_000);
+
+        let flat: Vec<_> = trace
+            .flatten()
+            .into_iter()
+            .map(|(depth, call)| (depth, call.function_name.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            flat,
+            vec![
+                (0, "swap"),
+                (1, "transferFrom"),
+                (2, "balanceOf"),
+                (1, "hook")
+            ]
+        );
+        let self_total: u64 = trace.flatten().iter().map(|(_, c)| c.self_gas()).sum();
+        assert_eq!(self_total, trace.gas_used);
+    }
+
+    #[test]
+    fn test_call_trace_decoded_params() {
+        let mut json = bundle_result("sim1", 50_000, true, 0);
+        json["transaction"]["transaction_info"] = serde_json::json!({
+            "call_trace": {
+                "call_type": "CALL",
+                "function_name": "transfer",
+                "gas_used": 50_000,
+                "decoded_input": [
+                    {
+                        "soltype": {"name": "to", "type": "address", "storage_location": "default"},
+                        "value": "0x2222222222222222222222222222222222222222"
+                    },
+                    {
+                        "soltype": {"name": "amount", "type": "uint256", "storage_location": "default"},
+                        "value": "1000000"
+                    }
+                ],
+                "decoded_output": [
+                    {"soltype": {"name": "", "type": "bool"}, "value": true}
+                ],
+                "calls": [{"call_type": "STATICCALL", "decoded_input": null}]
+            }
+        });
+        let response: SimulationResponse = serde_json::from_value(json).unwrap();
+        let trace = response.call_trace().unwrap();
+
+        let input = &trace.decoded_input;
+        assert_eq!(input.len(), 2);
+        assert_eq!(input[0].name, "to");
+        assert_eq!(input[0].param_type, "address");
+        assert_eq!(
+            input[0].value_str(),
+            Some("0x2222222222222222222222222222222222222222")
+        );
+        assert_eq!(input[1].name, "amount");
+        assert_eq!(input[1].param_type, "uint256");
+        assert_eq!(input[1].value_str(), Some("1000000"));
+
+        assert_eq!(trace.decoded_output[0].param_type, "bool");
+        assert_eq!(trace.decoded_output[0].value, serde_json::json!(true));
+        assert!(trace.calls[0].decoded_input.is_empty());
+
+        // The flat form used by VNet traces still parses
+        let flat: DecodedParam = serde_json::from_value(
+            serde_json::json!({"name": "to", "type": "address", "value": "0x1"}),
+        )
+        .unwrap();
+        assert_eq!(flat.name, "to");
+        assert_eq!(flat.param_type, "address");
+    }
+
+    #[test]
+    fn test_simulation_dashboard_url() {
+        let client = Client::new(crate::Config::new("key", "my team", "proj")).unwrap();
+
+        let response: SimulationResponse =
+            serde_json::from_value(bundle_result("sim1", 21_000, true, 0)).unwrap();
+        assert!(response.dashboard_url.is_none());
+        assert_eq!(
+            response.dashboard_url(&client),
+            "https://dashboard.tenderly.co/my%20team/proj/simulator/sim1"
+        );
+
+        let mut json = bundle_result("sim1", 21_000, true, 0);
+        json["dashboard_url"] = "https://custom.example/sim1".into();
+        let response: SimulationResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            response.dashboard_url(&client),
+            "https://custom.example/sim1"
+        );
+    }
+
+    #[test]
+    fn test_state_diff_against() {
+        fn with_state_diff(raw: serde_json::Value) -> SimulationResponse {
+            let mut json = bundle_result("sim", 50_000, true, 0);
+            json["transaction"]["transaction_info"] = serde_json::json!({
+                "state_diff": [{
+                    "address": "0x2222222222222222222222222222222222222222",
+                    "original": "100",
+                    "dirty": "90",
+                    "raw": raw
+                }]
+            });
+            serde_json::from_value(json).unwrap()
+        }
+
+        let baseline = with_state_diff(serde_json::json!([
+            {"address": "0x2222222222222222222222222222222222222222", "key": "0x01", "original": "0x64", "dirty": "0x5a"},
+            {"address": "0x2222222222222222222222222222222222222222", "key": "0x02", "original": "0x0", "dirty": "0x1"}
+        ]));
+        let modified = with_state_diff(serde_json::json!([
+            {"address": "0x2222222222222222222222222222222222222222", "key": "0x01", "original": "0x64", "dirty": "0x50"},
+            {"address": "0x2222222222222222222222222222222222222222", "key": "0x02", "original": "0x0", "dirty": "0x1"}
+        ]));
+
+        assert_eq!(baseline.state_diff().len(), 1);
+        assert_eq!(baseline.storage_changes().count(), 2);
+        assert!(baseline.state_diff_against(&baseline).is_empty());
+
+        let diff = baseline.state_diff_against(&modified);
+        assert_eq!(
+            diff,
+            vec![SlotDiff {
+                address: "0x2222222222222222222222222222222222222222".into(),
+                key: "0x01".into(),
+                value: Some("0x5a".into()),
+                other_value: Some("0x50".into()),
+            }]
+        );
+
+        // A slot written by only one run is reported with None on the other side
+        let untouched: SimulationResponse =
+            serde_json::from_value(bundle_result("sim", 21_000, true, 0)).unwrap();
+        let diff = untouched.state_diff_against(&baseline);
+        assert_eq!(diff.len(), 2);
+        assert!(diff.iter().all(|slot| slot.value.is_none()));
+    }
+
+    fn bundle_result(id: &str, gas_used: u64, status: bool, logs: usize) -> serde_json::Value {
+        serde_json::json!({
+            "simulation": {
+                "id": id,
+                "network_id": "1",
+                "block_number": 19_000_000,
+                "from": "0x1234",
+                "to": "0x5678",
+                "input": "0x",
+                "gas": 100_000,
+                "gas_used": gas_used,
+                "value": "0",
+                "status": status
+            },
+            "transaction": {
+                "status": status,
+                "gas_used": gas_used,
+                "logs": vec![serde_json::json!({"name": "Transfer"}); logs]
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_bundle() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mut first = bundle_result("sim1", 50_000, true, 1);
+        first["simulation"]["root"] = "sim1".into();
+        let mut second = bundle_result("sim2", 30_000, false, 0);
+        second["simulation"]["root"] = "sim1".into();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/simulations/sim1/bundle"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"simulations": [first, second]})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let saved = client.simulation().get_bundle("sim1").await.unwrap();
+
+        assert_eq!(saved.root_id, "sim1");
+        assert_eq!(saved.bundle.simulation_ids(), ["sim1", "sim2"]);
+        assert_eq!(saved.bundle.root_id(), Some("sim1"));
+        assert_eq!(
+            saved.bundle.transactions[1].simulation.root_id.as_deref(),
+            Some("sim1")
+        );
+        assert_eq!(saved.bundle.total_gas_used(), 80_000);
+        assert_eq!(saved.bundle.first_failure().map(|(i, _)| i), Some(1));
+
+        // Serializes flat, like the API response
+        let json = serde_json::to_value(&saved).unwrap();
+        assert_eq!(json["root_id"], "sim1");
+        assert_eq!(json["simulation_results"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_bundle_root_id_defaults_to_first_simulation() {
+        let bundle: BundleSimulationResponse = serde_json::from_value(serde_json::json!({
+            "simulation_results": [
+                bundle_result("a", 1, true, 0),
+                bundle_result("b", 1, true, 0)
+            ]
+        }))
+        .unwrap();
+        assert_eq!(bundle.root_id(), Some("a"));
+
+        let empty: BundleSimulationResponse =
+            serde_json::from_value(serde_json::json!({"simulation_results": []})).unwrap();
+        assert_eq!(empty.root_id(), None);
+    }
+
+    #[test]
+    fn test_bundle_response_second_reverts() {
+        let json = serde_json::json!({
+            "simulation_results": [
+                bundle_result("sim-1", 46_000, true, 1),
+                bundle_result("sim-2", 23_000, false, 0),
+                bundle_result("sim-3", 51_000, true, 2)
+            ]
+        });
+
+        let response: BundleSimulationResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.transactions.len(), 3);
+        assert_eq!(response.total_gas_used(), 120_000);
+        assert!(!response.all_succeeded());
+
+        let (index, failed) = response.first_failure().unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(failed.simulation.id, "sim-2");
+
+        let logs = response.transactions[2]
+            .transaction
+            .as_ref()
+            .and_then(|tx| tx.logs.as_ref())
+            .unwrap();
+        assert_eq!(logs.len(), 2);
+    }
+
+    #[test]
+    fn test_bundle_response_all_succeed() {
+        let json = serde_json::json!({
+            "simulation_results": [
+                bundle_result("sim-1", 21_000, true, 0),
+                bundle_result("sim-2", 21_000, true, 0)
+            ]
+        });
+
+        let response: BundleSimulationResponse = serde_json::from_value(json).unwrap();
+
+        assert!(response.first_failure().is_none());
+        assert!(response.all_succeeded());
+        assert_eq!(response.total_gas_used(), 42_000);
+    }
+
+    #[test]
+    fn test_bundle_builder_auto_nonce_interleaved_senders() {
+        let bundle = BundleBuilder::new()
+            .auto_nonce(true)
+            .base_nonce("0xAAAA", 10)
+            .base_nonce("0xbbbb", 3)
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .add(SimulationRequest::new("0xBBBB", "0x01", "0x"))
+            .add(SimulationRequest::new("0xAAAA", "0x02", "0x"))
+            .add(SimulationRequest::new("0xbbbb", "0x02", "0x"))
+            .add(SimulationRequest::new("0xaaaa", "0x03", "0x"))
+            .build();
+
+        let nonces: Vec<_> = bundle.simulations.iter().map(|s| s.nonce).collect();
+        assert_eq!(nonces, vec![Some(10), Some(3), Some(11), Some(4), Some(12)]);
+    }
+
+    #[test]
+    fn test_bundle_builder_explicit_nonce_rebases() {
+        let bundle = BundleBuilder::new()
+            .auto_nonce(true)
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x").nonce(7))
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .build();
+
+        let nonces: Vec<_> = bundle.simulations.iter().map(|s| s.nonce).collect();
+        assert_eq!(nonces, vec![None, Some(7), Some(8)]);
+    }
+
+    #[test]
+    fn test_bundle_builder_nonce_settings_apply_in_any_order() {
+        let bundle = BundleBuilder::new()
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .base_nonce("0xAAAA", 5)
+            .auto_nonce(true)
+            .build();
+
+        let nonces: Vec<_> = bundle.simulations.iter().map(|s| s.nonce).collect();
+        assert_eq!(nonces, vec![Some(5), Some(6)]);
+    }
+
+    #[test]
+    fn test_bundle_builder_nonce_overflow() {
+        let bundle = BundleBuilder::new()
+            .auto_nonce(true)
+            .base_nonce("0xaaaa", u64::MAX)
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .build();
+
+        let nonces: Vec<_> = bundle.simulations.iter().map(|s| s.nonce).collect();
+        assert_eq!(nonces, vec![Some(u64::MAX), None]);
+    }
+
+    #[test]
+    fn test_bundle_builder_without_auto_nonce() {
+        let bundle = BundleBuilder::new()
+            .base_nonce("0xaaaa", 5)
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .add(SimulationRequest::new("0xaaaa", "0x01", "0x"))
+            .build();
+
+        assert!(bundle.simulations.iter().all(|s| s.nonce.is_none()));
+        assert!(bundle.state_objects.is_none());
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn test_simulation_response_decode_output() {
+        let mut json = bundle_result("sim-1", 24_000, true, 0);
+        json["transaction"]["call_trace"] = serde_json::json!({
+            "output": "0x00000000000000000000000000000000000000000000000000000000000003e8"
+        });
+
+        let response: SimulationResponse = serde_json::from_value(json).unwrap();
+        let values = response
+            .decode_output("balanceOf(address)(uint256)")
+            .unwrap();
+        assert_eq!(values, vec![crate::abi::Token::uint(1000u16)]);
+
+        let empty: SimulationResponse =
+            serde_json::from_value(bundle_result("sim-2", 21_000, true, 0)).unwrap();
+        assert!(empty.output().is_none());
+        assert!(empty.decode_output("uint256").is_err());
+    }
+
+    #[test]
+    fn test_eth_transfer_preset() {
+        let request = SimulationRequest::eth_transfer(
+            "137",
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "0x000000000000000000000000000000000000dEaD",
+            1_500_000_000_000_000_000,
+        );
+
+        assert_eq!(request.network_id, "137");
+        assert_eq!(request.from, "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+        assert_eq!(request.to, "0x000000000000000000000000000000000000dEaD");
+        assert_eq!(request.input, "0x");
+        assert_eq!(request.value.as_deref(), Some("0x14d1120d7b160000"));
+        assert_eq!(request.gas, Some(ETH_TRANSFER_GAS));
+        assert_eq!(request.simulation_type, "full");
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_contract_read_preset() {
+        let request = SimulationRequest::contract_read(
+            "1",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x18160ddd",
+        );
+
+        assert_eq!(request.network_id, "1");
+        assert_eq!(request.from, "0x0000000000000000000000000000000000000000");
+        assert_eq!(request.to, "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        assert_eq!(request.input, "0x18160ddd");
+        assert_eq!(request.value, None);
+        assert_eq!(request.gas, Some(DEFAULT_GAS_LIMIT));
+        assert!(request.validate().is_ok());
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn test_erc20_transfer_request() {
+        let request = SimulationRequest::erc20_transfer(
+            "0x1234",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            1_000_000u64,
+        )
+        .unwrap();
+
+        assert_eq!(request.from, "0x1234");
+        assert_eq!(request.to, "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        assert_eq!(request.gas, Some(ERC20_DEFAULT_GAS));
+        assert_eq!(
+            request.input,
+            "0xa9059cbb\
+             000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045\
+             00000000000000000000000000000000000000000000000000000000000f4240"
+        );
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn test_erc20_approve_request() {
+        let request = SimulationRequest::erc20_approve(
+            "0x1234",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D",
+            u128::MAX,
+        )
+        .unwrap();
+
+        assert_eq!(request.to, "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        assert_eq!(
+            request.input,
+            "0x095ea7b3\
+             0000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488d\
+             00000000000000000000000000000000ffffffffffffffffffffffffffffffff"
+        );
+
+        assert!(SimulationRequest::erc20_approve("0x1234", "0xtoken", "0xbad", 1u8).is_err());
+    }
+
+    #[test]
+    fn test_simulation_request_validate() {
+        let from = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        let to = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+        assert!(SimulationRequest::new(from, to, "0x70a08231")
+            .validate()
+            .is_ok());
+
+        let err = SimulationRequest::new(from, "0x1234", "0x")
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidParam(_)));
+        assert!(err.to_string().contains("`to`"));
+
+        let err = SimulationRequest::new("bob", to, "0x")
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("`from`"));
+
+        let err = SimulationRequest::new(from, to, "0xzz")
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("`input`"));
+    }
+
+    #[test]
+    fn test_simulation_request_normalized() {
+        let request = SimulationRequest::new(
+            "D8DA6BF26964AF9D7EED9E03E53415D37AA96045",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x",
+        )
+        .state_overrides(std::collections::HashMap::from([(
+            "0xA0B86991C6218B36C1D19D4A2E9EB0CE3606EB48".to_string(),
+            StateOverride::new().balance("0x1"),
+        )]));
+
+        let normalized = request.normalized(false).unwrap();
+        assert_eq!(
+            normalized.from,
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+        );
+        assert_eq!(normalized.to, "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        assert!(normalized
+            .state_objects
+            .unwrap()
+            .contains_key("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+
+        let err = SimulationRequest::new("0x1234", "", "0x")
+            .normalized(false)
+            .unwrap_err();
+        assert!(err.to_string().contains("`from`"));
+    }
+
+    #[test]
+    fn test_normalized_rejects_state_override_collisions() {
+        let request = SimulationRequest::new(
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "0x",
+        )
+        .state_overrides(std::collections::HashMap::from([
+            (
+                "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+                StateOverride::new().balance("0x1"),
+            ),
+            (
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string(),
+                StateOverride::new().balance("0x2"),
+            ),
+        ]));
+
+        let err = request.normalized(false).unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidParam(_)));
+        assert!(err
+            .to_string()
+            .contains("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+    }
+
+    #[cfg(feature = "abi")]
+    #[tokio::test]
+    async fn test_simulate_strict_checksums() {
+        let client = Client::new(
+            crate::Config::new("key", "acc", "proj")
+                .with_base_url("http://127.0.0.1:1")
+                .with_strict_checksums(true),
+        )
+        .unwrap();
+        // Case of the first letter flipped
+        let request = SimulationRequest::new(
+            "0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x",
+        );
+
+        // Rejected before any request is sent
+        let err = client.simulation().simulate(&request).await.unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidParam(ref msg) if msg.contains("checksum")));
+    }
+
+    #[test]
+    fn test_simulation_request_to_vnet_send() {
+        let request = SimulationRequest::new("0x1234", "0x5678", "0xa9059cbb")
+            .network_id("8453")
+            .value("0xde0b6b3a7640000")
+            .gas(150_000)
+            .max_fee_per_gas("0x77359400")
+            .max_priority_fee_per_gas("0x3b9aca00")
+            .override_balance("0x1234", "0x1")
+            .block_number(1);
+
+        let send = request.to_vnet_send();
+        assert_eq!(send.from, "0x1234");
+        assert_eq!(send.to, "0x5678");
+        assert_eq!(send.input.as_deref(), Some("0xa9059cbb"));
+        assert_eq!(send.value.as_deref(), Some("0xde0b6b3a7640000"));
+        assert_eq!(send.gas, Some(150_000));
+        assert_eq!(send.gas_price, None);
+        assert_eq!(send.max_fee_per_gas.as_deref(), Some("0x77359400"));
+        assert_eq!(send.max_priority_fee_per_gas.as_deref(), Some("0x3b9aca00"));
+        assert_eq!(send.transaction_type, Some(2));
+        // State overrides are dropped
+        let json = serde_json::to_value(&send).unwrap();
+        assert!(json.get("state_objects").is_none());
+
+        let back = send.to_simulation().network_id("8453");
+        assert_eq!(back.from, request.from);
+        assert_eq!(back.to, request.to);
+        assert_eq!(back.input, request.input);
+        assert_eq!(back.value, request.value);
+        assert_eq!(back.gas, request.gas);
+        assert_eq!(back.max_fee_per_gas, request.max_fee_per_gas);
+        assert_eq!(
+            back.max_priority_fee_per_gas,
+            request.max_priority_fee_per_gas
+        );
+        assert_eq!(back.transaction_type, Some(2));
+        assert!(back.state_objects.is_none());
+        assert_eq!(back.block_number, None);
+    }
+
+    #[test]
+    fn test_simulation_request_validate_contract_creation() {
+        let request = SimulationRequest::new(
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "",
+            "0x6080604052348015600f57600080fd5b50",
+        );
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_deploy_request_and_created_address() {
+        let request = SimulationRequest::deploy(
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "0x6080604052348015600f57600080fd5b50",
+        );
+        assert!(request.validate().is_ok());
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("to").is_none());
+        assert_eq!(json["input"], "0x6080604052348015600f57600080fd5b50");
+
+        let mut json = bundle_result("sim-1", 60_000, true, 0);
+        json["transaction"] = serde_json::json!({
+            "from": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "to": "",
+            "transaction_info": {
+                "contract_address": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                "call_trace": {"call_type": "CREATE", "output": "0x6080"}
+            }
+        });
+        let response: SimulationResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            response.created_address(),
+            Some("0x5fbdb2315678afecb367f032d93f642f64180aa3")
+        );
+
+        // Plain calls report the callee as contract_address, not a deployment
+        let mut json = bundle_result("sim-2", 30_000, true, 0);
+        json["transaction"] = serde_json::json!({
+            "to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "transaction_info": {
+                "contract_address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "call_trace": {"call_type": "CALL"}
+            }
+        });
+        let response: SimulationResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.created_address(), None);
+    }
+
+    #[test]
+    fn test_simulation_type_serialization() {
+        for (sim_type, expected) in [
+            (SimulationType::Full, "full"),
+            (SimulationType::Quick, "quick"),
+            (SimulationType::Abi, "abi"),
+        ] {
+            let request =
+                SimulationRequest::new("0x1234", "0x5678", "0x").simulation_type(sim_type);
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(json["simulation_type"], expected);
+            assert_eq!(expected.parse::<SimulationType>().unwrap(), sim_type);
+        }
+
+        assert_eq!(
+            "abi_only".parse::<SimulationType>().unwrap(),
+            SimulationType::Abi
+        );
+        assert!("detailed".parse::<SimulationType>().is_err());
+
+        let request =
+            SimulationRequest::new("0x1234", "0x5678", "0x").simulation_type_raw("detailed");
+        assert_eq!(request.simulation_type, "detailed");
+    }
+
+    #[test]
+    fn test_save_policy_flags() {
+        for (policy, save, save_if_fails) in [
+            (SavePolicy::Never, false, false),
+            (SavePolicy::Always, true, true),
+            (SavePolicy::OnlyOnFailure, false, true),
+            (SavePolicy::OnlyOnSuccess, true, false),
+        ] {
+            // Start from the opposite flags to check both are overwritten
+            let request = SimulationRequest::new("0x1234", "0x5678", "0x")
+                .save(!save)
+                .save_if_fails(!save_if_fails)
+                .save_policy(policy);
+            assert_eq!(
+                (request.save, request.save_if_fails),
+                (save, save_if_fails),
+                "{:?}",
+                policy
+            );
+        }
+    }
+
+    #[test]
+    fn test_save_policy_display_round_trip() {
+        for policy in [
+            SavePolicy::Never,
+            SavePolicy::Always,
+            SavePolicy::OnlyOnFailure,
+            SavePolicy::OnlyOnSuccess,
+        ] {
+            assert_eq!(policy.to_string().parse::<SavePolicy>().unwrap(), policy);
+        }
+        assert_eq!(
+            "Only-On-Failure".parse::<SavePolicy>().unwrap(),
+            SavePolicy::OnlyOnFailure
+        );
+        assert!("sometimes".parse::<SavePolicy>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_share_with_options() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulations/sim1/share"))
+            .and(body_json(serde_json::json!({"expires_in": 3600})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulations/sim2/share"))
+            .and(body_json(serde_json::json!({})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let options = ShareOptions::new().expires_in(std::time::Duration::from_secs(3600));
+        let shared = client
+            .simulation()
+            .share_with_options("sim1", &options)
+            .await
+            .unwrap();
+        assert_eq!(shared.id, "sim1");
+        assert_eq!(
+            shared.url,
+            "https://dashboard.tenderly.co/shared/simulation/sim1"
+        );
+
+        let shared = client.simulation().share("sim2").await.unwrap();
+        assert_eq!(
+            shared.to_string(),
+            "https://dashboard.tenderly.co/shared/simulation/sim2"
+        );
+        assert_eq!(shared.shared_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_share_uses_api_url() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulations/sim1/share"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://tenderly.example.com/shared/simulation/abc123",
+                "share_id": "abc123"
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let shared = client.simulation().share("sim1").await.unwrap();
+        assert_eq!(shared.id, "sim1");
+        assert_eq!(
+            shared.url,
+            "https://tenderly.example.com/shared/simulation/abc123"
+        );
+        assert_eq!(shared.shared_id.as_deref(), Some("abc123"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_simulations() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/simulations/sim1"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/simulations/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("simulation not found"))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        client.simulation().delete("sim1").await.unwrap();
+
+        let results = client.simulation().delete_many(&["sim1", "missing"]).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "sim1");
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "missing");
+        assert!(results[1].1.as_ref().unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_simulation_request_network() {
+        use crate::networks::Chain;
+
+        let request = SimulationRequest::new("0x1234", "0x5678", "0x").network(Chain::Polygon);
+        assert_eq!(request.network_id, "137");
+
+        let request = request.network(Chain::Unknown(31_337));
+        assert_eq!(request.network_id, "31337");
+    }
+
+    #[tokio::test]
+    async fn test_simulate_before_tx() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/trace/0xabc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transaction": {"hash": "0xabc", "block_number": 19_000_000, "index": 42}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulate"))
+            .and(body_partial_json(serde_json::json!({
+                "network_id": "1",
+                "block_number": 19_000_000,
+                "transaction_index": 42
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(bundle_result("sim1", 21_000, true, 0)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let request = SimulationRequest::new(
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x",
+        )
+        .network_id("137");
+        let response = client
+            .simulation()
+            .simulate_before_tx("1", "0xabc", &request)
+            .await
+            .unwrap();
+        assert_eq!(response.simulation.id, "sim1");
+    }
+
+    #[tokio::test]
+    async fn test_info_typed() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/simulations/sim1/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transaction_info": {
+                    "id": "sim1",
+                    "network_id": "1",
+                    "block_number": "17884583",
+                    "transaction_index": "0",
+                    "nonce": "1094",
+                    "value": "0",
+                    "gas": "648318",
+                    "gas_price": "18312000018",
+                    "gas_used": "337375",
+                    "status": true,
+                    "error_message": ""
+                },
+                "block_header": {"number": "0x110ace7"},
+                "state_overrides": {
+                    "0xdac17f958d2ee523a2206206994597c13d831ec7": {"storage": {"0x0": "0x1"}}
+                },
+                "created_at": 1702050051710u64
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let info = client.simulation().info("sim1").await.unwrap();
+        let tx = info.transaction_info.unwrap();
+
+        assert_eq!(tx.block_number, Some(17_884_583));
+        assert_eq!(tx.gas_used, Some(337_375));
+        assert_eq!(tx.gas_price.as_deref(), Some("18312000018"));
+        assert_eq!(tx.status, Some(true));
+        assert_eq!(info.state_overrides.len(), 1);
+        assert_eq!(info.created_at, Some(1_702_050_051_710));
+    }
+
+    #[test]
+    fn test_transaction_trace_formats() {
+        let trace: TransactionTrace = serde_json::from_value(
+            serde_json::json!({"block_number": "0x10", "transaction_index": "3"}),
+        )
+        .unwrap();
+        assert_eq!(trace.block_number, Some(16));
+        assert_eq!(trace.transaction_index, Some(3));
+
+        let trace: TransactionTrace = serde_json::from_value(
+            serde_json::json!({"transaction": {"block_number": 1, "index": 2}}),
+        )
+        .unwrap();
+        assert_eq!(
+            trace.block_number.zip(trace.transaction_index),
+            Some((1, 2))
+        );
+
+        let trace: TransactionTrace =
+            serde_json::from_value(serde_json::json!({"block_number": 1})).unwrap();
+        assert_eq!(trace.transaction_index, None);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_many_preserves_order() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+        /// Name each simulation after its calldata, with a delay that
+        /// makes early requests finish last
+        struct EchoInput;
+        impl Respond for EchoInput {
+            fn respond(&self, request: &Request) -> ResponseTemplate {
+                let body: serde_json::Value = request.body_json().unwrap();
+                let input = body["input"].as_str().unwrap();
+                let n = u64::from_str_radix(&input[2..], 16).unwrap();
+                ResponseTemplate::new(200)
+                    .set_body_json(bundle_result(input, 21_000, true, 0))
+                    .set_delay(std::time::Duration::from_millis(50 - n * 5))
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/simulate"))
+            .respond_with(EchoInput)
+            .expect(10)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let requests: Vec<_> = (0..10u8)
+            .map(|i| {
+                SimulationRequest::new(
+                    "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+                    "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                    format!("0x{:02x}", i),
+                )
+            })
+            .collect();
+
+        let results = client.simulation().simulate_many(&requests, 3).await;
+        assert_eq!(results.len(), 10);
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(
+                result.as_ref().unwrap().simulation.id,
+                format!("0x{:02x}", i)
+            );
+        }
+    }
+
+    #[test]
+    fn test_prepare_simulate_to_curl() {
+        let client = Client::new(crate::Config::new("secret", "acc", "proj")).unwrap();
+        let request = SimulationRequest::new(
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x",
+        );
+
+        let prepared = client.simulation().prepare(&request).unwrap();
+        let curl = client.to_curl(&prepared);
+
+        assert!(curl.starts_with(
+            "curl -X POST 'https://api.tenderly.co/api/v1/account/acc/project/proj/simulate'"
+        ));
+        assert!(curl.contains("-d '{"));
+        // Addresses are normalized before sending
+        assert!(curl.contains(r#""from":"0xd8da6bf26964af9d7eed9e03e53415d37aa96045""#));
+        assert!(curl.contains("$TENDERLY_ACCESS_KEY"));
+        assert!(!curl.contains("secret"));
+
+        assert!(client
+            .to_curl_with_key(&prepared)
+            .contains("'X-Access-Key: secret'"));
+    }
+
+    #[test]
+    fn test_default_gas_applied_before_sending() {
+        fn sent_gas(client: &Client, request: &SimulationRequest) -> serde_json::Value {
+            let prepared = client.simulation().prepare(request).unwrap();
+            prepared.body.unwrap()["gas"].clone()
+        }
+
+        let request = SimulationRequest::new(
+            "0x1111111111111111111111111111111111111111",
+            "0x2222222222222222222222222222222222222222",
+            "0x",
+        );
+        let client = Client::new(crate::Config::new("key", "acc", "proj")).unwrap();
+        assert_eq!(sent_gas(&client, &request), DEFAULT_GAS_LIMIT);
+        assert_eq!(sent_gas(&client, &request.clone().gas(50_000)), 50_000);
+        assert!(sent_gas(&client, &request.clone().clear_gas()).is_null());
+
+        // The opt-out survives a round trip but never reaches the API
+        let cleared = request.clone().clear_gas();
+        let json = serde_json::to_string(&cleared).unwrap();
+        let restored: SimulationRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cleared);
+        assert_ne!(restored, request);
+        assert!(sent_gas(&client, &restored).is_null());
+        let prepared = client.simulation().prepare(&restored).unwrap();
+        assert!(prepared.body.unwrap().get("skip_default_gas").is_none());
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("skip_default_gas").is_none());
+
+        let client = Client::new(
+            crate::Config::new("key", "acc", "proj")
+                .with_default_gas(10_000_000)
+                .with_network_default_gas("137", 20_000_000),
+        )
+        .unwrap();
+        assert_eq!(sent_gas(&client, &request), 10_000_000);
+        assert_eq!(
+            sent_gas(&client, &request.clone().network_id("137")),
+            20_000_000
+        );
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_default_gas(None)).unwrap();
+        assert!(sent_gas(&client, &request).is_null());
+
+        assert_eq!(request.with_default_gas().gas, Some(DEFAULT_GAS_LIMIT));
+    }
 }
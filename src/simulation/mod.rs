@@ -28,6 +28,8 @@
 //! ```
 
 mod api;
+#[cfg(feature = "raw-tx")]
+mod raw;
 mod types;
 
 pub use api::SimulationApi;
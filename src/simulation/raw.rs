@@ -0,0 +1,364 @@
+//! Signed raw transaction decoding
+//!
+//! Minimal RLP decoder for legacy, EIP-2930 and EIP-1559 transaction
+//! envelopes, with sender recovery from the signature.
+
+use super::types::{AccessListEntry, SimulationRequest};
+use crate::error::{Error, Result};
+use crate::utils::{decode_hex, encode_hex, keccak256};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+/// A decoded RLP item, keeping its full encoding for re-hashing
+enum Item<'a> {
+    Bytes(&'a [u8]),
+    List(Vec<(Item<'a>, &'a [u8])>),
+}
+
+impl<'a> Item<'a> {
+    fn bytes(&self) -> Result<&'a [u8]> {
+        match self {
+            Self::Bytes(b) => Ok(b),
+            Self::List(_) => Err(invalid("expected RLP string, found list")),
+        }
+    }
+
+    fn list(&self) -> Result<&[(Item<'a>, &'a [u8])]> {
+        match self {
+            Self::List(items) => Ok(items),
+            Self::Bytes(_) => Err(invalid("expected RLP list, found string")),
+        }
+    }
+}
+
+fn invalid(message: &str) -> Error {
+    Error::invalid_param(format!("Invalid raw transaction: {}", message))
+}
+
+/// Decode a big-endian length prefix
+fn read_len(bytes: &[u8]) -> Result<usize> {
+    if bytes.len() > std::mem::size_of::<usize>() || bytes.first() == Some(&0) {
+        return Err(invalid("bad RLP length prefix"));
+    }
+    Ok(bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize))
+}
+
+/// Decode one RLP item from the front of `data`, returning it and the remainder
+fn decode_item(data: &[u8]) -> Result<(Item<'_>, &[u8], &[u8])> {
+    let prefix = *data
+        .first()
+        .ok_or_else(|| invalid("unexpected end of RLP"))?;
+    let (header, len, is_list) = match prefix {
+        0x00..=0x7f => return Ok((Item::Bytes(&data[..1]), &data[..1], &data[1..])),
+        0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+        0xb8..=0xbf => {
+            let n = (prefix - 0xb7) as usize;
+            let len = read_len(data.get(1..1 + n).ok_or_else(|| invalid("truncated RLP"))?)?;
+            (1 + n, len, false)
+        }
+        0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+        0xf8..=0xff => {
+            let n = (prefix - 0xf7) as usize;
+            let len = read_len(data.get(1..1 + n).ok_or_else(|| invalid("truncated RLP"))?)?;
+            (1 + n, len, true)
+        }
+    };
+
+    let end = header
+        .checked_add(len)
+        .filter(|end| *end <= data.len())
+        .ok_or_else(|| invalid("truncated RLP"))?;
+    let payload = &data[header..end];
+    let item = if is_list {
+        let mut items = Vec::new();
+        let mut rest = payload;
+        while !rest.is_empty() {
+            let (item, raw, next) = decode_item(rest)?;
+            items.push((item, raw));
+            rest = next;
+        }
+        Item::List(items)
+    } else {
+        Item::Bytes(payload)
+    };
+
+    Ok((item, &data[..end], &data[end..]))
+}
+
+/// RLP-encode a list from already-encoded items
+fn encode_list(items: &[&[u8]]) -> Vec<u8> {
+    let len: usize = items.iter().map(|i| i.len()).sum();
+    let mut out = Vec::with_capacity(len + 9);
+    if len < 56 {
+        out.push(0xc0 + len as u8);
+    } else {
+        let be = len.to_be_bytes();
+        let skip = be.iter().take_while(|b| **b == 0).count();
+        out.push(0xf7 + (be.len() - skip) as u8);
+        out.extend_from_slice(&be[skip..]);
+    }
+    for item in items {
+        out.extend_from_slice(item);
+    }
+    out
+}
+
+/// RLP-encode an unsigned integer
+fn encode_u64(value: u64) -> Vec<u8> {
+    let be = value.to_be_bytes();
+    let trimmed = &be[be.iter().take_while(|b| **b == 0).count()..];
+    match trimmed {
+        [] => vec![0x80],
+        [b] if *b < 0x80 => vec![*b],
+        _ => {
+            let mut out = vec![0x80 + trimmed.len() as u8];
+            out.extend_from_slice(trimmed);
+            out
+        }
+    }
+}
+
+fn to_u64(bytes: &[u8]) -> Result<u64> {
+    if bytes.len() > 8 {
+        return Err(invalid("integer does not fit in u64"));
+    }
+    Ok(bytes.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+fn to_u128(bytes: &[u8]) -> Result<u128> {
+    if bytes.len() > 16 {
+        return Err(invalid("integer does not fit in u128"));
+    }
+    Ok(bytes
+        .iter()
+        .fold(0u128, |acc, b| (acc << 8) | u128::from(*b)))
+}
+
+/// Hex-encode an integer without leading zeros (e.g., `0x0`, `0xde0b6b3a7640000`)
+fn to_hex_quantity(bytes: &[u8]) -> String {
+    let hex = encode_hex(bytes);
+    let digits = hex[2..].trim_start_matches('0');
+    if digits.is_empty() {
+        "0x0".to_string()
+    } else {
+        format!("0x{}", digits)
+    }
+}
+
+/// Recover the sender address from a signing hash and signature
+fn recover_sender(hash: &[u8; 32], y_parity: u8, r: &[u8], s: &[u8]) -> Result<String> {
+    if r.len() > 32 || s.len() > 32 {
+        return Err(invalid("signature component too long"));
+    }
+    let mut sig = [0u8; 64];
+    sig[32 - r.len()..32].copy_from_slice(r);
+    sig[64 - s.len()..].copy_from_slice(s);
+
+    let signature = Signature::from_slice(&sig).map_err(|_| invalid("malformed signature"))?;
+    let recovery_id =
+        RecoveryId::from_byte(y_parity).ok_or_else(|| invalid("bad signature parity"))?;
+    let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+        .map_err(|_| invalid("could not recover sender"))?;
+
+    let point = key.to_encoded_point(false);
+    let digest = keccak256(&point.as_bytes()[1..]);
+    Ok(encode_hex(&digest[12..]))
+}
+
+fn decode_access_list(item: &Item<'_>) -> Result<Vec<AccessListEntry>> {
+    item.list()?
+        .iter()
+        .map(|(entry, _)| {
+            let fields = entry.list()?;
+            if fields.len() != 2 {
+                return Err(invalid("malformed access list entry"));
+            }
+            let storage_keys = fields[1]
+                .0
+                .list()?
+                .iter()
+                .map(|(key, _)| key.bytes().map(encode_hex))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(AccessListEntry::new(encode_hex(fields[0].0.bytes()?)).storage_keys(storage_keys))
+        })
+        .collect()
+}
+
+/// Decode a signed raw transaction into a simulation request
+pub(crate) fn decode(network_id: &str, raw_tx: &str) -> Result<SimulationRequest> {
+    let data = decode_hex(raw_tx).ok_or_else(|| invalid("malformed hex"))?;
+    let first = *data.first().ok_or_else(|| invalid("empty transaction"))?;
+
+    let (tx_type, body) = if first >= 0xc0 {
+        (0u8, &data[..])
+    } else {
+        (first, &data[1..])
+    };
+
+    let (item, _, rest) = decode_item(body)?;
+    if !rest.is_empty() {
+        return Err(invalid("trailing bytes after transaction"));
+    }
+    let fields = item.list()?;
+    let field = |i: usize| fields[i].0.bytes();
+    let raw_fields: Vec<&[u8]> = fields.iter().map(|(_, raw)| *raw).collect();
+
+    let (nonce_idx, sig_idx) = match (tx_type, fields.len()) {
+        (0, 9) => (0, 6),
+        (1, 11) | (2, 12) => (1, fields.len() - 3),
+        (0..=2, _) => return Err(invalid("unexpected number of fields")),
+        _ => {
+            return Err(invalid(&format!(
+                "unsupported transaction type 0x{:02x}",
+                tx_type
+            )))
+        }
+    };
+
+    let (hash, y_parity) = if tx_type == 0 {
+        let v = to_u64(field(6)?)?;
+        match v {
+            27 | 28 => (keccak256(&encode_list(&raw_fields[..6])), (v - 27) as u8),
+            v if v >= 35 => {
+                let chain_id = (v - 35) / 2;
+                let chain = encode_u64(chain_id);
+                let mut signing = raw_fields[..6].to_vec();
+                signing.extend_from_slice(&[&chain, &[0x80], &[0x80]]);
+                (keccak256(&encode_list(&signing)), ((v - 35) % 2) as u8)
+            }
+            _ => return Err(invalid("bad signature v value")),
+        }
+    } else {
+        let mut payload = vec![tx_type];
+        payload.extend_from_slice(&encode_list(&raw_fields[..sig_idx]));
+        let parity = to_u64(field(sig_idx)?)?;
+        if parity > 1 {
+            return Err(invalid("bad signature parity"));
+        }
+        (keccak256(&payload), parity as u8)
+    };
+
+    let from = recover_sender(&hash, y_parity, field(sig_idx + 1)?, field(sig_idx + 2)?)?;
+
+    // Field offsets after the nonce: [fees..., gas, to, value, data, (access list)]
+    let fee_count = if tx_type == 2 { 2 } else { 1 };
+    let gas_idx = nonce_idx + 1 + fee_count;
+    let to = field(gas_idx + 1)?;
+    let value = field(gas_idx + 2)?;
+
+    let mut request = SimulationRequest::new(
+        from,
+        if to.is_empty() {
+            String::new()
+        } else {
+            encode_hex(to)
+        },
+        encode_hex(field(gas_idx + 3)?),
+    )
+    .network_id(network_id)
+    .nonce(to_u64(field(nonce_idx)?)?)
+    .gas(to_u64(field(gas_idx)?)?);
+
+    if !value.iter().all(|b| *b == 0) {
+        request.value = Some(to_hex_quantity(value));
+    }
+
+    if tx_type == 2 {
+        request.max_priority_fee_per_gas = Some(to_u128(field(nonce_idx + 1)?)?.to_string());
+        request.max_fee_per_gas = Some(to_u128(field(nonce_idx + 2)?)?.to_string());
+    } else {
+        request.gas_price = Some(to_u128(field(nonce_idx + 1)?)?.to_string());
+    }
+
+    if tx_type != 0 {
+        let access_list = decode_access_list(&fields[gas_idx + 4].0)?;
+        if !access_list.is_empty() {
+            request.access_list = Some(access_list);
+        }
+    }
+    request.transaction_type = Some(tx_type);
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // EIP-155 example transaction, signed with key 0x4646...46
+    const LEGACY_TX: &str = "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    const SENDER: &str = "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f";
+
+    // Same key, chain 1, one access list entry
+    const EIP2930_TX: &str = "0x01f8a20103843b9aca0082c3509435353535353535353535353535353535353535358203e882abcdf838f7941111111111111111111111111111111111111111e1a0000000000000000000000000000000000000000000000000000000000000000080a0bf796656ea6750785b40b6741d1a72abe8d29fde2632fc77503a3711c866b1d2a00339500e75e677697c3c9d78cac4b392cb66346fda106f64f14b5813d187fc61";
+
+    // Same key, chain 1, empty access list
+    const EIP1559_TX: &str = "0x02f86b010484773594008506fc23ac0082ea609435353535353535353535353535353535353535358080c080a0f8b60dc0582ac86f841b510493378c4a74205cd4de2baacb79ee991e864b2ba1a05d2d7c5b26187a5ade585e734a8255daed5d18f16bb0448c1955ace0ec7e8186";
+
+    #[test]
+    fn test_decode_legacy() {
+        let request = decode("1", LEGACY_TX).unwrap();
+
+        assert_eq!(request.from, SENDER);
+        assert_eq!(request.to, "0x3535353535353535353535353535353535353535");
+        assert_eq!(request.value, Some("0xde0b6b3a7640000".to_string()));
+        assert_eq!(request.input, "0x");
+        assert_eq!(request.gas, Some(21_000));
+        assert_eq!(request.gas_price, Some("20000000000".to_string()));
+        assert_eq!(request.nonce, Some(9));
+        assert_eq!(request.transaction_type, Some(0));
+        assert!(request.max_fee_per_gas.is_none());
+    }
+
+    #[test]
+    fn test_rejects_garbage() {
+        assert!(decode("1", "0x").is_err());
+        assert!(decode("1", "0xzz").is_err());
+        assert!(decode("1", "0x03c0").is_err());
+        assert!(decode("1", &LEGACY_TX[..LEGACY_TX.len() - 2]).is_err());
+    }
+
+    #[test]
+    fn test_decode_eip2930() {
+        let request = decode("1", EIP2930_TX).unwrap();
+
+        assert_eq!(request.from, SENDER);
+        assert_eq!(request.to, "0x3535353535353535353535353535353535353535");
+        assert_eq!(request.value, Some("0x3e8".to_string()));
+        assert_eq!(request.input, "0xabcd");
+        assert_eq!(request.gas, Some(50_000));
+        assert_eq!(request.gas_price, Some("1000000000".to_string()));
+        assert_eq!(request.nonce, Some(3));
+        assert_eq!(request.transaction_type, Some(1));
+
+        let access_list = request.access_list.unwrap();
+        assert_eq!(access_list.len(), 1);
+        assert_eq!(
+            access_list[0].address,
+            "0x1111111111111111111111111111111111111111"
+        );
+        assert_eq!(
+            access_list[0].storage_keys,
+            vec![format!("0x{}", "0".repeat(64))]
+        );
+    }
+
+    #[test]
+    fn test_decode_eip1559() {
+        let request = decode("1", EIP1559_TX).unwrap();
+
+        assert_eq!(request.from, SENDER);
+        assert_eq!(request.to, "0x3535353535353535353535353535353535353535");
+        assert!(request.value.is_none());
+        assert_eq!(request.input, "0x");
+        assert_eq!(request.gas, Some(60_000));
+        assert_eq!(
+            request.max_priority_fee_per_gas,
+            Some("2000000000".to_string())
+        );
+        assert_eq!(request.max_fee_per_gas, Some("30000000000".to_string()));
+        assert!(request.gas_price.is_none());
+        assert_eq!(request.nonce, Some(4));
+        assert_eq!(request.transaction_type, Some(2));
+        assert!(request.access_list.is_none());
+    }
+}
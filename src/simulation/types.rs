@@ -1,10 +1,24 @@
 //! Types for transaction simulation
 
+use crate::client::encode_path_segment;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Default gas limit for ERC-20 helper simulations
+pub const ERC20_DEFAULT_GAS: u64 = 100_000;
+
+/// Gas used by a plain ETH transfer
+pub const ETH_TRANSFER_GAS: u64 = 21_000;
+
+/// Gas limit applied to simulations that don't set one
+///
+/// Override per client with
+/// [`Config::with_default_gas`](crate::Config::with_default_gas).
+pub const DEFAULT_GAS_LIMIT: u64 = 30_000_000;
+
 /// Request for simulating a single transaction
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SimulationRequest {
     /// Network ID (e.g., "1" for mainnet)
     pub network_id: String,
@@ -12,7 +26,8 @@ pub struct SimulationRequest {
     /// Sender address
     pub from: String,
 
-    /// Recipient/contract address
+    /// Recipient/contract address (empty for contract creation)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub to: String,
 
     /// Encoded calldata
@@ -38,7 +53,8 @@ pub struct SimulationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_priority_fee_per_gas: Option<String>,
 
-    /// Transaction type (0 = legacy, 1 = access list, 2 = EIP-1559)
+    /// Transaction type (0 = legacy, 1 = access list, 2 = EIP-1559, 3 = blob,
+    /// 4 = set code)
     #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
     pub transaction_type: Option<u8>,
 
@@ -62,6 +78,15 @@ pub struct SimulationRequest {
     #[serde(default = "default_simulation_type")]
     pub simulation_type: String,
 
+    /// Where the simulation came from (e.g., a script or service name),
+    /// used to organize saved simulations in the dashboard
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Human-readable description shown with saved simulations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
     /// State overrides for accounts
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_objects: Option<HashMap<String, StateOverride>>,
@@ -86,6 +111,18 @@ pub struct SimulationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub access_list: Option<Vec<AccessListEntry>>,
 
+    /// Max fee per blob gas in wei (EIP-4844)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_blob_gas: Option<String>,
+
+    /// Versioned hashes of the blobs carried by the transaction (EIP-4844)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_versioned_hashes: Option<Vec<String>>,
+
+    /// Signed delegations to contract code (EIP-7702)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_list: Option<Vec<Authorization>>,
+
     // L2/Optimism parameters
     /// Latest L1 block number known to L2
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,10 +151,16 @@ pub struct SimulationRequest {
     /// Desired amount to be minted (string for large values)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount_to_mint: Option<String>,
+
+    /// Send without gas instead of the client's default gas limit
+    ///
+    /// Serialized only when set, so the opt-out survives a round trip; it is
+    /// cleared before the request is sent.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub skip_default_gas: bool,
 }
 
-// Used by serde(default = "...") attribute; rustc doesn't recognize serde's usage
-#[allow(dead_code)]
 fn default_simulation_type() -> String {
     "full".to_string()
 }
@@ -142,12 +185,17 @@ impl SimulationRequest {
             save: false,
             save_if_fails: false,
             simulation_type: "full".to_string(),
+            source: None,
+            description: None,
             state_objects: None,
             block_header: None,
             transaction_index: None,
             estimate_gas: None,
             generate_access_list: None,
             access_list: None,
+            max_fee_per_blob_gas: None,
+            blob_versioned_hashes: None,
+            authorization_list: None,
             l1_block_number: None,
             l1_timestamp: None,
             l1_message_sender: None,
@@ -155,9 +203,183 @@ impl SimulationRequest {
             system_tx: None,
             mint: None,
             amount_to_mint: None,
+            skip_default_gas: false,
         }
     }
 
+    /// Create a plain ETH transfer of `value` wei on `network_id`
+    ///
+    /// Sends empty calldata with a gas limit of [`ETH_TRANSFER_GAS`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tndrly::simulation::SimulationRequest;
+    ///
+    /// let request = SimulationRequest::eth_transfer(
+    ///     "1",
+    ///     "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+    ///     "0x000000000000000000000000000000000000dEaD",
+    ///     1_000_000_000_000_000_000,
+    /// );
+    /// assert_eq!(request.value.as_deref(), Some("0xde0b6b3a7640000"));
+    /// ```
+    #[must_use]
+    pub fn eth_transfer(
+        network_id: impl Into<String>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        value_wei: u128,
+    ) -> Self {
+        Self::new(from, to, "0x")
+            .network_id(network_id)
+            .value_wei(value_wei)
+            .gas(ETH_TRANSFER_GAS)
+    }
+
+    /// Create a read-only call to `to` on `network_id`
+    ///
+    /// Sent from the zero address with a gas limit of [`DEFAULT_GAS_LIMIT`],
+    /// so the result doesn't depend on any account's balance or nonce.
+    /// Read the return data with [`SimulationResponse::output`].
+    #[must_use]
+    pub fn contract_read(
+        network_id: impl Into<String>,
+        to: impl Into<String>,
+        calldata: impl Into<String>,
+    ) -> Self {
+        Self::new("0x0000000000000000000000000000000000000000", to, calldata)
+            .network_id(network_id)
+            .gas(DEFAULT_GAS_LIMIT)
+    }
+
+    /// Create an ERC-20 `transfer(address,uint256)` simulation
+    ///
+    /// Targets `token`, encodes the calldata, and sets a default gas limit of
+    /// [`ERC20_DEFAULT_GAS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParam`](crate::Error::InvalidParam) if `to` is
+    /// not a valid address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tndrly::simulation::SimulationRequest;
+    ///
+    /// let request = SimulationRequest::erc20_transfer(
+    ///     "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+    ///     "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+    ///     "0x000000000000000000000000000000000000dEaD",
+    ///     1_000_000u64,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(request.input.starts_with("0xa9059cbb"));
+    /// ```
+    #[cfg(feature = "abi")]
+    pub fn erc20_transfer(
+        from: impl Into<String>,
+        token: impl Into<String>,
+        to: &str,
+        amount: impl Into<crate::abi::U256>,
+    ) -> crate::error::Result<Self> {
+        use crate::abi::{encode_call, Token};
+
+        let input = encode_call(
+            "transfer(address,uint256)",
+            &[Token::address(to), Token::uint(amount)],
+        )?;
+        Ok(Self::new(from, token, input).gas(ERC20_DEFAULT_GAS))
+    }
+
+    /// Create an ERC-20 `approve(address,uint256)` simulation
+    ///
+    /// Targets `token`, encodes the calldata, and sets a default gas limit of
+    /// [`ERC20_DEFAULT_GAS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParam`](crate::Error::InvalidParam) if
+    /// `spender` is not a valid address.
+    #[cfg(feature = "abi")]
+    pub fn erc20_approve(
+        from: impl Into<String>,
+        token: impl Into<String>,
+        spender: &str,
+        amount: impl Into<crate::abi::U256>,
+    ) -> crate::error::Result<Self> {
+        use crate::abi::{encode_call, Token};
+
+        let input = encode_call(
+            "approve(address,uint256)",
+            &[Token::address(spender), Token::uint(amount)],
+        )?;
+        Ok(Self::new(from, token, input).gas(ERC20_DEFAULT_GAS))
+    }
+
+    /// Create a contract deployment request
+    ///
+    /// `to` is left empty (and omitted from the JSON) and `input` carries
+    /// the creation bytecode. Read the deployed address back with
+    /// [`SimulationResponse::created_address`].
+    #[must_use]
+    pub fn deploy(from: impl Into<String>, bytecode: impl Into<String>) -> Self {
+        Self::new(from, "", bytecode)
+    }
+
+    /// Create a request from a signed raw transaction
+    ///
+    /// Decodes the RLP envelope (legacy, EIP-2930 or EIP-1559) and recovers
+    /// the sender from the signature. Populates `from`, `to`, `value`,
+    /// `input`, `gas`, `nonce`, fee fields and access list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParam`](crate::Error::InvalidParam) if the
+    /// transaction cannot be decoded or the signature is invalid.
+    #[cfg(feature = "raw-tx")]
+    pub fn from_raw_transaction(
+        network_id: impl AsRef<str>,
+        raw_tx: impl AsRef<str>,
+    ) -> crate::error::Result<Self> {
+        super::raw::decode(network_id.as_ref(), raw_tx.as_ref())
+    }
+
+    /// Create a request replaying an on-chain transaction
+    ///
+    /// Fetches the transaction with `eth_getTransactionByHash` from `rpc`
+    /// (a VNet Admin RPC, or any node via [`AdminRpc::new`]) and fills
+    /// `from`, `to`, `value`, `input`, gas, fees, nonce, access list,
+    /// `block_number` and `transaction_index`. See
+    /// [`RpcTransaction::to_simulation`](crate::vnets::RpcTransaction::to_simulation).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`](crate::Error::NotFound) if the node does
+    /// not know the transaction.
+    ///
+    /// [`AdminRpc::new`]: crate::vnets::AdminRpc::new
+    pub async fn from_onchain_tx(
+        rpc: &crate::vnets::AdminRpc,
+        network_id: impl Into<String>,
+        tx_hash: &str,
+    ) -> crate::error::Result<Self> {
+        let tx = rpc
+            .get_transaction_by_hash(tx_hash)
+            .await?
+            .ok_or_else(|| crate::Error::not_found(format!("transaction {tx_hash}")))?;
+        Ok(tx.to_simulation(network_id))
+    }
+
+    /// Set the network from a well-known [`Chain`](crate::networks::Chain)
+    #[must_use]
+    pub fn network(mut self, chain: crate::networks::Chain) -> Self {
+        self.network_id = chain.network_id();
+        self
+    }
+
     /// Set the network ID
     #[must_use]
     pub fn network_id(mut self, id: impl Into<String>) -> Self {
@@ -186,6 +408,23 @@ impl SimulationRequest {
         self
     }
 
+    /// Set the gas limit to [`DEFAULT_GAS_LIMIT`] unless one is already set
+    #[must_use]
+    pub fn with_default_gas(mut self) -> Self {
+        self.gas.get_or_insert(DEFAULT_GAS_LIMIT);
+        self
+    }
+
+    /// Clear the gas limit and opt out of the client's default
+    ///
+    /// Tenderly then picks the gas limit itself.
+    #[must_use]
+    pub fn clear_gas(mut self) -> Self {
+        self.gas = None;
+        self.skip_default_gas = true;
+        self
+    }
+
     /// Set the gas price (legacy transactions)
     #[must_use]
     pub fn gas_price(mut self, price: u64) -> Self {
@@ -197,7 +436,7 @@ impl SimulationRequest {
     #[must_use]
     pub fn max_fee_per_gas(mut self, fee: impl Into<String>) -> Self {
         self.max_fee_per_gas = Some(fee.into());
-        self.transaction_type = Some(2);
+        self.mark_eip1559();
         self
     }
 
@@ -205,7 +444,7 @@ impl SimulationRequest {
     #[must_use]
     pub fn max_fee_per_gas_wei(mut self, fee: u64) -> Self {
         self.max_fee_per_gas = Some(format!("{}", fee));
-        self.transaction_type = Some(2);
+        self.mark_eip1559();
         self
     }
 
@@ -213,7 +452,7 @@ impl SimulationRequest {
     #[must_use]
     pub fn max_priority_fee_per_gas(mut self, fee: impl Into<String>) -> Self {
         self.max_priority_fee_per_gas = Some(fee.into());
-        self.transaction_type = Some(2);
+        self.mark_eip1559();
         self
     }
 
@@ -221,17 +460,124 @@ impl SimulationRequest {
     #[must_use]
     pub fn max_priority_fee_per_gas_wei(mut self, fee: u64) -> Self {
         self.max_priority_fee_per_gas = Some(format!("{}", fee));
-        self.transaction_type = Some(2);
+        self.mark_eip1559();
+        self
+    }
+
+    /// Set max fee per blob gas (EIP-4844)
+    ///
+    /// Automatically sets transaction type to 3.
+    #[must_use]
+    pub fn max_fee_per_blob_gas(mut self, fee: impl Into<String>) -> Self {
+        self.max_fee_per_blob_gas = Some(fee.into());
+        self.transaction_type = Some(3);
+        self
+    }
+
+    /// Set blob versioned hashes (EIP-4844)
+    ///
+    /// Automatically sets transaction type to 3.
+    #[must_use]
+    pub fn blob_versioned_hashes(mut self, hashes: Vec<String>) -> Self {
+        self.blob_versioned_hashes = Some(hashes);
+        self.transaction_type = Some(3);
+        self
+    }
+
+    /// Set the authorization list (EIP-7702)
+    ///
+    /// Automatically sets transaction type to 4.
+    #[must_use]
+    pub fn authorization_list(mut self, list: Vec<Authorization>) -> Self {
+        self.authorization_list = Some(list);
+        self.transaction_type = Some(4);
+        self
+    }
+
+    /// Add an authorization (EIP-7702)
+    ///
+    /// Automatically sets transaction type to 4.
+    #[must_use]
+    pub fn add_authorization(mut self, authorization: Authorization) -> Self {
+        self.authorization_list
+            .get_or_insert_with(Vec::new)
+            .push(authorization);
+        self.transaction_type = Some(4);
+        self
+    }
+
+    /// Mark as EIP-1559, keeping type 3 or 4 if blob or authorization
+    /// fields were already set
+    fn mark_eip1559(&mut self) {
+        if !matches!(self.transaction_type, Some(3 | 4)) {
+            self.transaction_type = Some(2);
+        }
+    }
+
+    /// Set EIP-1559 fees from a gas price bucket
+    ///
+    /// Automatically sets transaction type to 2.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let estimate = client.gas().gas_price("1").await?;
+    /// let request = SimulationRequest::new(from, to, data)
+    ///     .gas_fees(estimate.medium());
+    /// ```
+    #[must_use]
+    pub fn gas_fees(mut self, price: &crate::gas::GasPrice) -> Self {
+        self.max_fee_per_gas = Some(price.max_fee_per_gas.to_string());
+        self.max_priority_fee_per_gas = Some(price.max_priority_fee_per_gas.to_string());
+        self.mark_eip1559();
         self
     }
 
-    /// Set the transaction type (0 = legacy, 1 = access list, 2 = EIP-1559)
+    /// Set EIP-1559 fees from a base fee and priority fee
+    ///
+    /// Sets `max_fee_per_gas` to `base_fee * 2 + priority` (see
+    /// [`BASE_FEE_MULTIPLIER`](crate::gas::BASE_FEE_MULTIPLIER)) and
+    /// transaction type 2, keeping type 3 or 4 if blob or authorization
+    /// fields are set.
+    #[must_use]
+    pub fn eip1559_fees(self, base_fee: u128, priority: u128) -> Self {
+        self.gas_fees(&crate::gas::GasPrice::from_base_fee(base_fee, priority))
+    }
+
+    /// Set EIP-1559 fees with a custom base fee multiplier
+    #[must_use]
+    pub fn eip1559_fees_with_multiplier(
+        self,
+        base_fee: u128,
+        priority: u128,
+        multiplier: u128,
+    ) -> Self {
+        self.gas_fees(&crate::gas::GasPrice::from_base_fee_with_multiplier(
+            base_fee, priority, multiplier,
+        ))
+    }
+
+    /// Set the transaction type (0 = legacy, 1 = access list, 2 = EIP-1559,
+    /// 3 = blob, 4 = set code)
+    ///
+    /// Fee, access list, blob and authorization setters also set the type;
+    /// whichever call comes last wins, so call this after them to override.
     #[must_use]
     pub fn transaction_type(mut self, tx_type: u8) -> Self {
         self.transaction_type = Some(tx_type);
         self
     }
 
+    /// Clear the transaction type so the API infers it from the fields set
+    ///
+    /// Like [`transaction_type`](Self::transaction_type), this only affects
+    /// the type set before it.
+    #[must_use]
+    pub fn clear_transaction_type(mut self) -> Self {
+        self.transaction_type = None;
+        self
+    }
+
     /// Set the nonce
     #[must_use]
     pub fn nonce(mut self, nonce: u64) -> Self {
@@ -249,7 +595,8 @@ impl SimulationRequest {
     /// Set whether to save the simulation on success
     ///
     /// Note: This only controls saving on successful simulations.
-    /// Use `.save_if_fails(true)` to also save failed simulations.
+    /// Use `.save_if_fails(true)` to also save failed simulations, or
+    /// prefer [`save_policy`](Self::save_policy) to set both coherently.
     #[must_use]
     pub fn save(mut self, save: bool) -> Self {
         self.save = save;
@@ -259,7 +606,8 @@ impl SimulationRequest {
     /// Set whether to save the simulation even if it fails
     ///
     /// When `true`, failed simulations will be saved to your project.
-    /// This is useful for debugging transaction failures.
+    /// This is useful for debugging transaction failures. Prefer
+    /// [`save_policy`](Self::save_policy) over combining this with `save`.
     #[must_use]
     pub fn save_if_fails(mut self, save_if_fails: bool) -> Self {
         self.save_if_fails = save_if_fails;
@@ -276,6 +624,31 @@ impl SimulationRequest {
         self
     }
 
+    /// Set when the simulation is saved to the project
+    ///
+    /// Sets `save` and `save_if_fails` together so they cannot conflict.
+    #[must_use]
+    pub fn save_policy(mut self, policy: SavePolicy) -> Self {
+        let (save, save_if_fails) = policy.flags();
+        self.save = save;
+        self.save_if_fails = save_if_fails;
+        self
+    }
+
+    /// Tag the simulation with where it came from
+    #[must_use]
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Set a description for the saved simulation
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     /// Set simulation type
     #[must_use]
     pub fn simulation_type(mut self, sim_type: SimulationType) -> Self {
@@ -283,6 +656,16 @@ impl SimulationRequest {
         self
     }
 
+    /// Set simulation type from a raw string, without validation
+    ///
+    /// Escape hatch for values newer than [`SimulationType`]; prefer
+    /// [`simulation_type`](Self::simulation_type) otherwise.
+    #[must_use]
+    pub fn simulation_type_raw(mut self, sim_type: impl Into<String>) -> Self {
+        self.simulation_type = sim_type.into();
+        self
+    }
+
     /// Add state overrides
     #[must_use]
     pub fn state_overrides(mut self, overrides: HashMap<String, StateOverride>) -> Self {
@@ -330,6 +713,53 @@ impl SimulationRequest {
         self
     }
 
+    /// Add a nonce override for an address
+    ///
+    /// Useful for simulating replacement transactions.
+    #[must_use]
+    pub fn override_nonce(mut self, address: impl Into<String>, nonce: u64) -> Self {
+        let address = address.into().to_lowercase();
+        let overrides = self.state_objects.get_or_insert_with(HashMap::new);
+        let entry = overrides.entry(address).or_default();
+        entry.nonce = Some(nonce);
+        self
+    }
+
+    /// Add a prebuilt override for an address
+    ///
+    /// Merges with any overrides already set for the address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tndrly::simulation::{AccountOverride, SimulationRequest};
+    ///
+    /// let request = SimulationRequest::new("0x1234", "0x5678", "0x").override_account(
+    ///     "0xaaaa",
+    ///     AccountOverride::new().balance("0xde0b6b3a7640000").nonce(5),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn override_account(
+        mut self,
+        address: impl Into<String>,
+        account: AccountOverride,
+    ) -> Self {
+        let address = address.into().to_lowercase();
+        let overrides = self.state_objects.get_or_insert_with(HashMap::new);
+        overrides.entry(address).or_default().merge(account);
+        self
+    }
+
+    /// Add prebuilt overrides for several addresses
+    #[must_use]
+    pub fn override_accounts(mut self, accounts: HashMap<String, AccountOverride>) -> Self {
+        for (address, account) in accounts {
+            self = self.override_account(address, account);
+        }
+        self
+    }
+
     /// Override block timestamp
     #[must_use]
     pub fn block_timestamp(mut self, timestamp: u64) -> Self {
@@ -340,6 +770,26 @@ impl SimulationRequest {
         self
     }
 
+    /// Override the block's excess blob gas (Cancun and later)
+    #[must_use]
+    pub fn excess_blob_gas(mut self, gas: u64) -> Self {
+        let header = self
+            .block_header
+            .get_or_insert_with(BlockHeaderOverride::default);
+        header.excess_blob_gas = Some(format!("0x{:x}", gas));
+        self
+    }
+
+    /// Override the block's parent beacon block root (Cancun and later)
+    #[must_use]
+    pub fn parent_beacon_block_root(mut self, root: impl Into<String>) -> Self {
+        let header = self
+            .block_header
+            .get_or_insert_with(BlockHeaderOverride::default);
+        header.parent_beacon_block_root = Some(root.into());
+        self
+    }
+
     /// Set the transaction index within the block
     #[must_use]
     pub fn transaction_index(mut self, index: u64) -> Self {
@@ -362,10 +812,13 @@ impl SimulationRequest {
     }
 
     /// Set EIP-2930 access list for gas optimization
+    ///
+    /// Upgrades a legacy or unset transaction type to 1; typed transactions
+    /// (2, 3 and 4) already carry access lists and keep their type.
     #[must_use]
     pub fn access_list(mut self, list: Vec<AccessListEntry>) -> Self {
         self.access_list = Some(list);
-        self.transaction_type = Some(1); // EIP-2930
+        self.upgrade_to_access_list_type();
         self
     }
 
@@ -374,10 +827,17 @@ impl SimulationRequest {
     pub fn add_access_list_entry(mut self, entry: AccessListEntry) -> Self {
         let list = self.access_list.get_or_insert_with(Vec::new);
         list.push(entry);
-        self.transaction_type = Some(1); // EIP-2930
+        self.upgrade_to_access_list_type();
         self
     }
 
+    /// Switch legacy transactions to EIP-2930 so the access list is used
+    fn upgrade_to_access_list_type(&mut self) {
+        if matches!(self.transaction_type, None | Some(0)) {
+            self.transaction_type = Some(1);
+        }
+    }
+
     // L2/Optimism builder methods
 
     /// Set L1 block number (for L2 simulations)
@@ -428,6 +888,169 @@ impl SimulationRequest {
         self.amount_to_mint = Some(amount.into());
         self
     }
+
+    /// Build a request that sends this transaction on a Virtual TestNet
+    ///
+    /// Maps `from`, `to`, `input`, `value`, `gas`, fees, transaction type,
+    /// access list and blob fields. Simulation-only settings (state and
+    /// block overrides, block number, nonce, save flags and L2 parameters)
+    /// have no counterpart and are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = client.simulation().simulate(&request).await?;
+    /// if result.simulation.status {
+    ///     client.vnets().send_transaction(&vnet.id, &request.to_vnet_send()).await?;
+    /// }
+    /// ```
+    #[doc(alias = "into_vnet_send")]
+    #[must_use]
+    pub fn to_vnet_send(&self) -> crate::vnets::SendVNetTransactionRequest {
+        crate::vnets::SendVNetTransactionRequest {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            input: (!self.input.is_empty()).then(|| self.input.clone()),
+            value: self.value.clone(),
+            gas: self.gas,
+            gas_price: self.gas_price.clone(),
+            max_fee_per_gas: self.max_fee_per_gas.clone(),
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas.clone(),
+            access_list: self.access_list.as_ref().map(|list| {
+                list.iter()
+                    .map(|entry| crate::vnets::AccessListItem {
+                        address: entry.address.clone(),
+                        storage_keys: entry.storage_keys.clone(),
+                    })
+                    .collect()
+            }),
+            max_fee_per_blob_gas: self.max_fee_per_blob_gas.clone(),
+            blob_versioned_hashes: self.blob_versioned_hashes.clone(),
+            blobs: None,
+            authorization_list: self.authorization_list.clone(),
+            transaction_type: self.transaction_type,
+        }
+    }
+
+    /// Check that `from`/`to` are 20-byte hex addresses and `input` is hex
+    ///
+    /// An empty `to` is accepted for contract creation. Equivalent to
+    /// [`normalized(false)`](Self::normalized), discarding the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParam`](crate::Error::InvalidParam) naming
+    /// the offending field.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        self.normalized(false).map(drop)
+    }
+
+    /// Copy of the request with addresses lowercased and `0x`-prefixed
+    ///
+    /// Normalizes `from`, `to` and state override addresses, and checks
+    /// that `input` is hex. With `strict`, mixed-case addresses must carry a
+    /// valid EIP-55 checksum. Called by
+    /// [`SimulationApi::simulate`](super::SimulationApi::simulate) using
+    /// [`Config::strict_checksums`](crate::Config::strict_checksums).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParam`](crate::Error::InvalidParam) naming
+    /// the offending field, or the address when two state overrides differ
+    /// only in case.
+    pub fn normalized(&self, strict: bool) -> crate::error::Result<Self> {
+        use crate::error::Error;
+        use crate::utils::{decode_hex, parse_address};
+
+        if decode_hex(&self.input).is_none() {
+            return Err(Error::invalid_param(format!(
+                "Invalid `input` hex: {}",
+                self.input
+            )));
+        }
+        let mut request = self.clone();
+        request.from = parse_address("from", &self.from, strict)?;
+        if !self.to.is_empty() {
+            request.to = parse_address("to", &self.to, strict)?;
+        }
+        if let Some(overrides) = request.state_objects.take() {
+            let mut normalized = HashMap::with_capacity(overrides.len());
+            for (address, state) in overrides {
+                let key = parse_address("state_objects", &address, strict)?;
+                if normalized.contains_key(&key) {
+                    return Err(Error::invalid_param(format!(
+                        "Duplicate `state_objects` entries for {}",
+                        key
+                    )));
+                }
+                normalized.insert(key, state);
+            }
+            request.state_objects = Some(normalized);
+        }
+        Ok(request)
+    }
+}
+
+/// When a simulation should be saved to the project
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SavePolicy {
+    /// Never save
+    #[default]
+    Never,
+    /// Save regardless of outcome
+    Always,
+    /// Save only failed simulations
+    OnlyOnFailure,
+    /// Save only successful simulations
+    OnlyOnSuccess,
+}
+
+impl SavePolicy {
+    /// The `(save, save_if_fails)` flag pair for this policy
+    #[must_use]
+    pub fn flags(&self) -> (bool, bool) {
+        match self {
+            Self::Never => (false, false),
+            Self::Always => (true, true),
+            Self::OnlyOnFailure => (false, true),
+            Self::OnlyOnSuccess => (true, false),
+        }
+    }
+
+    /// Get the string representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Never => "never",
+            Self::Always => "always",
+            Self::OnlyOnFailure => "only_on_failure",
+            Self::OnlyOnSuccess => "only_on_success",
+        }
+    }
+}
+
+impl std::fmt::Display for SavePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for SavePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "never" => Ok(Self::Never),
+            "always" => Ok(Self::Always),
+            "only_on_failure" => Ok(Self::OnlyOnFailure),
+            "only_on_success" => Ok(Self::OnlyOnSuccess),
+            _ => Err(format!(
+                "Invalid save policy: {}. Expected: never, always, only_on_failure, or only_on_success",
+                s
+            )),
+        }
+    }
 }
 
 /// Simulation type
@@ -439,7 +1062,8 @@ pub enum SimulationType {
     Full,
     /// Quick simulation with raw results
     Quick,
-    /// ABI-only simulation
+    /// ABI-only simulation (decoded inputs/outputs without full trace)
+    #[doc(alias = "AbiOnly")]
     Abi,
 }
 
@@ -468,7 +1092,7 @@ impl std::str::FromStr for SimulationType {
         match s.to_lowercase().as_str() {
             "full" => Ok(Self::Full),
             "quick" => Ok(Self::Quick),
-            "abi" => Ok(Self::Abi),
+            "abi" | "abi_only" | "abi-only" => Ok(Self::Abi),
             _ => Err(format!(
                 "Invalid simulation type: {}. Expected: full, quick, or abi",
                 s
@@ -478,7 +1102,8 @@ impl std::str::FromStr for SimulationType {
 }
 
 /// EIP-2930 access list entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AccessListEntry {
     /// Contract address
     pub address: String,
@@ -513,8 +1138,53 @@ impl AccessListEntry {
     }
 }
 
+/// Signed EIP-7702 authorization delegating an account to contract code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Authorization {
+    /// Chain ID the authorization is valid on (0 = any chain)
+    pub chain_id: u64,
+
+    /// Contract whose code the authority delegates to
+    pub address: String,
+
+    /// Authority's nonce
+    pub nonce: u64,
+
+    /// Signature y-parity (0 or 1)
+    pub y_parity: u8,
+
+    /// Signature `r` (hex)
+    pub r: String,
+
+    /// Signature `s` (hex)
+    pub s: String,
+}
+
+impl Authorization {
+    /// Create a signed authorization
+    pub fn new(
+        chain_id: u64,
+        address: impl Into<String>,
+        nonce: u64,
+        y_parity: u8,
+        r: impl Into<String>,
+        s: impl Into<String>,
+    ) -> Self {
+        Self {
+            chain_id,
+            address: address.into(),
+            nonce,
+            y_parity,
+            r: r.into(),
+            s: s.into(),
+        }
+    }
+}
+
 /// State override for an account
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StateOverride {
     /// Balance override
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -527,15 +1197,82 @@ pub struct StateOverride {
     /// Code override
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
-}
 
-/// Block header overrides
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BlockHeaderOverride {
-    /// Timestamp override (hex)
+    /// Nonce override
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub nonce: Option<u64>,
+}
+
+/// Prebuilt override for a single account
+///
+/// An alias of [`StateOverride`], for use with
+/// [`SimulationRequest::override_account`].
+pub type AccountOverride = StateOverride;
+
+impl StateOverride {
+    /// Create an empty override
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the balance override
+    #[must_use]
+    pub fn balance(mut self, balance: impl Into<String>) -> Self {
+        self.balance = Some(balance.into());
+        self
+    }
+
+    /// Set the nonce override
+    #[must_use]
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Set the code override
+    #[must_use]
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Add a storage slot override
+    #[must_use]
+    pub fn storage(mut self, slot: impl Into<String>, value: impl Into<String>) -> Self {
+        self.storage
+            .get_or_insert_with(HashMap::new)
+            .insert(slot.into(), value.into());
+        self
+    }
+
+    /// Merge another override into this one, with `other` taking precedence
+    fn merge(&mut self, other: StateOverride) {
+        if other.balance.is_some() {
+            self.balance = other.balance;
+        }
+        if other.code.is_some() {
+            self.code = other.code;
+        }
+        if other.nonce.is_some() {
+            self.nonce = other.nonce;
+        }
+        if let Some(storage) = other.storage {
+            self.storage
+                .get_or_insert_with(HashMap::new)
+                .extend(storage);
+        }
+    }
+}
+
+/// Block header overrides
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeaderOverride {
+    /// Timestamp override (hex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
 
     /// Block number override (hex)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -608,10 +1345,24 @@ pub struct BlockHeaderOverride {
     /// Total difficulty override
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_difficulty: Option<String>,
+
+    /// Excess blob gas override (hex, EIP-4844)
+    ///
+    /// Needed for blob fee accuracy on Cancun and later: Ethereum mainnet
+    /// and its testnets, and OP Stack chains since Ecotone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excess_blob_gas: Option<String>,
+
+    /// Parent beacon block root override (EIP-4788)
+    ///
+    /// Read by the beacon roots contract on the same networks as
+    /// `excess_blob_gas`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_beacon_block_root: Option<String>,
 }
 
 /// Response from a simulation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SimulationResponse {
     /// The simulation result
     pub simulation: Simulation,
@@ -627,10 +1378,150 @@ pub struct SimulationResponse {
     /// Generated access list (when generate_access_list: true was set in request)
     #[serde(default)]
     pub generated_access_list: Option<Vec<AccessListEntry>>,
+
+    /// Dashboard URL for viewing the simulation, if the API provided one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dashboard_url: Option<String>,
+}
+
+impl SimulationResponse {
+    /// Dashboard URL for the simulation
+    ///
+    /// Uses the URL from the API when present, otherwise builds one from the
+    /// client's account and project slugs.
+    #[must_use]
+    pub fn dashboard_url(&self, client: &crate::Client) -> String {
+        self.dashboard_url.clone().unwrap_or_else(|| {
+            client.dashboard_url(&format!(
+                "/simulator/{}",
+                encode_path_segment(&self.simulation.id)
+            ))
+        })
+    }
+
+    /// Address of the contract deployed by a contract-creation simulation
+    ///
+    /// Returns `None` if the simulated transaction was not a deployment.
+    #[must_use]
+    pub fn created_address(&self) -> Option<&str> {
+        let transaction = self.transaction.as_ref()?;
+        let info = transaction.transaction_info.as_ref()?;
+        let call_type = info
+            .call_trace
+            .as_ref()
+            .and_then(|trace| trace.get("call_type"))
+            .and_then(|t| t.as_str());
+        let is_create = matches!(call_type, Some("CREATE" | "CREATE2"))
+            || transaction.to.as_deref().is_none_or(str::is_empty);
+        if !is_create {
+            return None;
+        }
+        info.contract_address.as_deref()
+    }
+
+    /// Decoded root call trace
+    ///
+    /// Prefers `transaction_info.call_trace` (the nested tree) and falls
+    /// back to the first entry of `transaction.call_trace`. Returns `None`
+    /// if there is no trace or it doesn't match the expected shape.
+    #[must_use]
+    pub fn call_trace(&self) -> Option<CallTrace> {
+        let transaction = self.transaction.as_ref()?;
+        let raw = transaction
+            .transaction_info
+            .as_ref()
+            .and_then(|info| info.call_trace.as_ref())
+            .or(transaction.call_trace.as_ref())?;
+        let root = match raw {
+            serde_json::Value::Array(calls) => calls.first()?,
+            other => other,
+        };
+        serde_json::from_value(root.clone()).ok()
+    }
+
+    /// State changes from `transaction_info.state_diff`
+    #[must_use]
+    pub fn state_diff(&self) -> &[StateDiff] {
+        self.transaction
+            .as_ref()
+            .and_then(|transaction| transaction.transaction_info.as_ref())
+            .map(|info| info.state_diff.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Raw storage slot changes, flattened across all state diff entries
+    pub fn storage_changes(&self) -> impl Iterator<Item = &StorageChange> {
+        self.state_diff().iter().flat_map(|diff| diff.raw.iter())
+    }
+
+    /// Storage slots whose final value differs from `other`
+    ///
+    /// Includes slots written by only one of the two runs. Slots are matched
+    /// by address (case-insensitively) and key, and returned sorted.
+    #[must_use]
+    pub fn state_diff_against(&self, other: &SimulationResponse) -> Vec<SlotDiff> {
+        fn final_values(
+            response: &SimulationResponse,
+        ) -> std::collections::BTreeMap<(String, String), &str> {
+            response
+                .storage_changes()
+                .map(|change| {
+                    let slot = (change.address.to_lowercase(), change.key.to_lowercase());
+                    (slot, change.dirty.as_str())
+                })
+                .collect()
+        }
+
+        let ours = final_values(self);
+        let theirs = final_values(other);
+        let mut slots: Vec<_> = ours.keys().chain(theirs.keys()).collect();
+        slots.sort();
+        slots.dedup();
+        slots
+            .into_iter()
+            .filter_map(|slot| {
+                let value = ours.get(slot).copied();
+                let other_value = theirs.get(slot).copied();
+                (value != other_value).then(|| SlotDiff {
+                    address: slot.0.clone(),
+                    key: slot.1.clone(),
+                    value: value.map(str::to_string),
+                    other_value: other_value.map(str::to_string),
+                })
+            })
+            .collect()
+    }
+
+    /// Return data of the top-level call (hex string)
+    #[must_use]
+    pub fn output(&self) -> Option<&str> {
+        let trace = self.transaction.as_ref()?.call_trace.as_ref()?;
+        // The call trace is either the root call or a flattened list of calls
+        let root = match trace {
+            serde_json::Value::Array(calls) => calls.first()?,
+            other => other,
+        };
+        root.get("output")?.as_str()
+    }
+
+    /// Decode the top-level return data against a function signature
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let values = result.decode_output("balanceOf(address)(uint256)")?;
+    /// ```
+    #[cfg(feature = "abi")]
+    pub fn decode_output(&self, signature: &str) -> crate::error::Result<Vec<crate::abi::Token>> {
+        let output = self.output().ok_or_else(|| {
+            crate::error::Error::invalid_param("Simulation response has no call output")
+        })?;
+        crate::abi::decode_output(signature, output)
+    }
 }
 
 /// Simulation details
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Simulation {
     /// Simulation ID
     pub id: String,
@@ -656,7 +1547,8 @@ pub struct Simulation {
     /// Sender address
     pub from: String,
 
-    /// Recipient address
+    /// Recipient address (empty for contract creation)
+    #[serde(default)]
     pub to: String,
 
     /// Input data
@@ -690,10 +1582,14 @@ pub struct Simulation {
     /// Whether simulation is shared
     #[serde(default)]
     pub shared: bool,
+
+    /// ID of the first simulation of the saved bundle this one belongs to
+    #[serde(default, alias = "root", skip_serializing_if = "Option::is_none")]
+    pub root_id: Option<String>,
 }
 
 /// Transaction information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TransactionInfo {
     /// Transaction hash
     #[serde(default)]
@@ -754,10 +1650,266 @@ pub struct TransactionInfo {
     /// Transaction logs
     #[serde(default)]
     pub logs: Option<Vec<serde_json::Value>>,
+
+    /// Execution details (decoded trace, logs and created contract)
+    #[serde(default)]
+    pub transaction_info: Option<ExecutionDetails>,
+}
+
+/// Execution details of a simulated transaction (`transaction_info`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionDetails {
+    /// Address of the called contract, or of the deployed contract for
+    /// contract creation
+    #[serde(default)]
+    pub contract_address: Option<String>,
+
+    /// Called method name
+    #[serde(default)]
+    pub method: Option<String>,
+
+    /// Root call trace
+    #[serde(default)]
+    pub call_trace: Option<serde_json::Value>,
+
+    /// Decoded logs
+    #[serde(default)]
+    pub logs: Option<Vec<serde_json::Value>>,
+
+    /// Storage changes made by the transaction
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
+    pub state_diff: Vec<StateDiff>,
+}
+
+/// Entry in `transaction_info.state_diff`
+///
+/// `original` and `dirty` are decoded values when the contract's storage
+/// layout is known; `raw` always holds the underlying slot changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDiff {
+    /// Contract address
+    #[serde(default)]
+    pub address: Option<String>,
+
+    /// Decoded value before the transaction
+    #[serde(default)]
+    pub original: serde_json::Value,
+
+    /// Decoded value after the transaction
+    #[serde(default)]
+    pub dirty: serde_json::Value,
+
+    /// Raw storage slot changes
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
+    pub raw: Vec<StorageChange>,
+}
+
+/// Raw storage slot change
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageChange {
+    /// Contract address
+    pub address: String,
+
+    /// Storage slot (32-byte hex)
+    pub key: String,
+
+    /// Slot value before the transaction
+    #[serde(default)]
+    pub original: String,
+
+    /// Slot value after the transaction
+    #[serde(default)]
+    pub dirty: String,
+}
+
+/// Storage slot whose final value differs between two simulations
+///
+/// Returned by [`SimulationResponse::state_diff_against`]. A `None` value
+/// means that run did not write the slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotDiff {
+    /// Contract address (lowercase)
+    pub address: String,
+
+    /// Storage slot
+    pub key: String,
+
+    /// Value written by `self`
+    pub value: Option<String>,
+
+    /// Value written by `other`
+    pub other_value: Option<String>,
+}
+
+/// Decoded call trace frame
+///
+/// Parsed from the raw `call_trace` JSON; see
+/// [`SimulationResponse::call_trace`]. `gas_used` is the gas reported for
+/// the frame, including its subcalls.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CallTrace {
+    /// Call type (e.g., "CALL", "DELEGATECALL", "CREATE")
+    #[serde(default)]
+    pub call_type: Option<String>,
+
+    /// Caller address
+    #[serde(default)]
+    pub from: Option<String>,
+
+    /// Callee address
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// Decoded function name, if known
+    #[serde(default)]
+    pub function_name: Option<String>,
+
+    /// Gas available to the frame
+    #[serde(default)]
+    pub gas: u64,
+
+    /// Gas used by the frame, including subcalls
+    #[serde(default)]
+    pub gas_used: u64,
+
+    /// Value transferred
+    #[serde(default)]
+    pub value: Option<String>,
+
+    /// Calldata
+    #[serde(default)]
+    pub input: Option<String>,
+
+    /// Return data
+    #[serde(default)]
+    pub output: Option<String>,
+
+    /// Revert reason, if the frame failed
+    #[serde(default)]
+    pub error: Option<String>,
+
+    /// Decoded call arguments, if the contract ABI is known
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
+    pub decoded_input: Vec<DecodedParam>,
+
+    /// Decoded return values, if the contract ABI is known
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
+    pub decoded_output: Vec<DecodedParam>,
+
+    /// Subcalls, in execution order
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
+    pub calls: Vec<CallTrace>,
+}
+
+impl CallTrace {
+    /// Gas used by this frame alone, excluding subcalls
+    #[must_use]
+    pub fn self_gas(&self) -> u64 {
+        let children: u64 = self.calls.iter().map(|call| call.gas_used).sum();
+        self.gas_used.saturating_sub(children)
+    }
+
+    /// Gas used by this frame and all subcalls, summed from each frame's
+    /// [`self_gas`](Self::self_gas)
+    ///
+    /// Matches `gas_used` when the trace is consistent.
+    #[must_use]
+    pub fn total_gas_used(&self) -> u64 {
+        self.self_gas()
+            + self
+                .calls
+                .iter()
+                .map(CallTrace::total_gas_used)
+                .sum::<u64>()
+    }
+
+    /// All frames in depth-first order, paired with their depth (root = 0)
+    ///
+    /// Useful for rendering flamegraphs.
+    #[must_use]
+    pub fn flatten(&self) -> Vec<(usize, &CallTrace)> {
+        let mut frames = Vec::new();
+        let mut stack = vec![(0, self)];
+        while let Some((depth, frame)) = stack.pop() {
+            frames.push((depth, frame));
+            stack.extend(frame.calls.iter().rev().map(|call| (depth + 1, call)));
+        }
+        frames
+    }
+
+    /// Whether this frame reverted
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Decoded ABI parameter
+///
+/// Deserializes from the flat `{name, type, value}` form and from the
+/// simulation API's `{soltype: {name, type}, value}` form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "DecodedParamRepr")]
+pub struct DecodedParam {
+    /// Parameter name (may be empty)
+    pub name: String,
+
+    /// Solidity type (e.g., "uint256")
+    #[serde(rename = "type")]
+    pub param_type: String,
+
+    /// Decoded value
+    pub value: serde_json::Value,
+}
+
+impl DecodedParam {
+    /// The value as a string, for addresses, integers and bytes
+    #[must_use]
+    pub fn value_str(&self) -> Option<&str> {
+        self.value.as_str()
+    }
+}
+
+#[derive(Deserialize)]
+struct DecodedParamRepr {
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "type")]
+    param_type: String,
+    #[serde(default)]
+    soltype: Option<SolType>,
+    #[serde(default)]
+    value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct SolType {
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "type")]
+    param_type: String,
+}
+
+impl From<DecodedParamRepr> for DecodedParam {
+    fn from(repr: DecodedParamRepr) -> Self {
+        let (name, param_type) = match repr.soltype {
+            Some(soltype) if repr.name.is_empty() && repr.param_type.is_empty() => {
+                (soltype.name, soltype.param_type)
+            }
+            _ => (repr.name, repr.param_type),
+        };
+        Self {
+            name,
+            param_type,
+            value: repr.value,
+        }
+    }
 }
 
 /// Request for simulating a bundle of transactions
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BundleSimulationRequest {
     /// List of simulations to run in sequence
     pub simulations: Vec<SimulationRequest>,
@@ -785,15 +1937,191 @@ impl BundleSimulationRequest {
     }
 }
 
+/// Builder for bundle simulation requests
+///
+/// With [`auto_nonce`](Self::auto_nonce) enabled, each sender's nonce is
+/// assigned sequentially across the bundle when it is built, tracked
+/// independently per `from` address. Senders start at the base set with
+/// [`base_nonce`](Self::base_nonce); a sender without one keeps unset nonces
+/// so the API uses its current on-chain nonce. A request that already has an
+/// explicit nonce keeps it, and the sender's following transactions continue
+/// from there.
+///
+/// # Example
+///
+/// ```ignore
+/// let bundle = BundleBuilder::new()
+///     .auto_nonce(true)
+///     .base_nonce("0xSender", 42)
+///     .add(SimulationRequest::new("0xSender", "0xToken", approve_data))
+///     .add(SimulationRequest::new("0xSender", "0xRouter", swap_data))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct BundleBuilder {
+    simulations: Vec<SimulationRequest>,
+    state_objects: Option<HashMap<String, StateOverride>>,
+    auto_nonce: bool,
+    base_nonces: HashMap<String, u64>,
+}
+
+impl BundleBuilder {
+    /// Create a new empty bundle builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Automatically assign sequential nonces per sender
+    pub fn auto_nonce(mut self, enabled: bool) -> Self {
+        self.auto_nonce = enabled;
+        self
+    }
+
+    /// Set the starting nonce for a sender
+    pub fn base_nonce(mut self, from: impl AsRef<str>, nonce: u64) -> Self {
+        self.base_nonces.insert(from.as_ref().to_lowercase(), nonce);
+        self
+    }
+
+    /// Add shared state overrides (applied to all simulations)
+    pub fn state_overrides(mut self, overrides: HashMap<String, StateOverride>) -> Self {
+        self.state_objects = Some(overrides);
+        self
+    }
+
+    /// Add a transaction to the bundle
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, request: SimulationRequest) -> Self {
+        self.simulations.push(request);
+        self
+    }
+
+    /// Build the bundle simulation request
+    pub fn build(mut self) -> BundleSimulationRequest {
+        if self.auto_nonce {
+            let mut next_nonces = self.base_nonces;
+            for request in &mut self.simulations {
+                let sender = request.from.to_lowercase();
+                let nonce = match request.nonce {
+                    Some(nonce) => nonce,
+                    None => match next_nonces.get(&sender) {
+                        Some(&next) => *request.nonce.insert(next),
+                        None => continue,
+                    },
+                };
+                // A sender at u64::MAX has no next nonce; leave the rest unset
+                match nonce.checked_add(1) {
+                    Some(next) => next_nonces.insert(sender, next),
+                    None => next_nonces.remove(&sender),
+                };
+            }
+        }
+        BundleSimulationRequest {
+            simulations: self.simulations,
+            state_objects: self.state_objects,
+        }
+    }
+}
+
 /// Response from a bundle simulation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Each transaction is executed on top of the state left by the previous one,
+/// so results are in bundle order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BundleSimulationResponse {
-    /// Results for each simulation in the bundle
-    pub simulation_results: Vec<SimulationResponse>,
+    /// Results for each transaction in the bundle, in execution order
+    #[serde(rename = "simulation_results", alias = "simulations")]
+    pub transactions: Vec<SimulationResponse>,
+}
+
+impl BundleSimulationResponse {
+    /// IDs of the bundle's simulations, in execution order
+    #[must_use]
+    pub fn simulation_ids(&self) -> Vec<&str> {
+        self.transactions
+            .iter()
+            .map(|tx| tx.simulation.id.as_str())
+            .collect()
+    }
+
+    /// ID linking the bundle's simulations once saved
+    ///
+    /// Uses the root reported by the API, falling back to the first
+    /// simulation's ID. Pass it to
+    /// [`get_bundle`](super::SimulationApi::get_bundle).
+    #[must_use]
+    pub fn root_id(&self) -> Option<&str> {
+        let first = &self.transactions.first()?.simulation;
+        Some(first.root_id.as_deref().unwrap_or(&first.id))
+    }
+
+    /// Total gas used across all transactions in the bundle
+    #[must_use]
+    pub fn total_gas_used(&self) -> u64 {
+        self.transactions
+            .iter()
+            .map(|tx| tx.simulation.gas_used)
+            .sum()
+    }
+
+    /// Find the first transaction that reverted, with its index in the bundle
+    #[must_use]
+    pub fn first_failure(&self) -> Option<(usize, &SimulationResponse)> {
+        self.transactions
+            .iter()
+            .enumerate()
+            .find(|(_, tx)| !tx.simulation.status)
+    }
+
+    /// Check if every transaction in the bundle succeeded
+    #[must_use]
+    pub fn all_succeeded(&self) -> bool {
+        self.first_failure().is_none()
+    }
+}
+
+/// A saved bundle returned by [`get_bundle`](super::SimulationApi::get_bundle)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedBundle {
+    /// ID of the bundle's first simulation
+    pub root_id: String,
+
+    /// The bundle's simulations, in execution order
+    #[serde(flatten)]
+    pub bundle: BundleSimulationResponse,
+}
+
+/// Result of [`simulate_with_optimal_access_list`](super::SimulationApi::simulate_with_optimal_access_list)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListSimulation {
+    /// First run, with `generate_access_list` enabled
+    pub initial: SimulationResponse,
+
+    /// Second run, with the generated access list applied
+    pub optimized: SimulationResponse,
+}
+
+impl AccessListSimulation {
+    /// The access list applied in the second run
+    #[must_use]
+    pub fn access_list(&self) -> &[AccessListEntry] {
+        self.initial
+            .generated_access_list
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    /// Gas saved by the access list (negative if it cost more)
+    #[must_use]
+    pub fn gas_saved(&self) -> i128 {
+        i128::from(self.initial.simulation.gas_used)
+            - i128::from(self.optimized.simulation.gas_used)
+    }
 }
 
 /// Summary of a saved simulation (for listing)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SimulationSummary {
     /// Simulation ID
     pub id: String,
@@ -828,14 +2156,269 @@ pub struct SimulationSummary {
 }
 
 /// Response for listing simulations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SimulationListResponse {
     /// List of simulations
     pub simulations: Vec<SimulationSummary>,
 }
 
+/// Options for sharing a simulation
+///
+/// Fields left unset are omitted from the request, so the default options
+/// produce a plain permanent share.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct ShareOptions {
+    /// Seconds until the public link expires
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<u64>,
+}
+
+impl ShareOptions {
+    /// Create default share options (permanent share)
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expire the public link after the given duration
+    ///
+    /// If the endpoint does not support expiry it is ignored and the
+    /// simulation stays shared until [`unshare`](super::SimulationApi::unshare)
+    /// is called.
+    #[must_use]
+    pub fn expires_in(mut self, duration: std::time::Duration) -> Self {
+        self.expires_in = Some(duration.as_secs());
+        self
+    }
+}
+
+/// A publicly shared simulation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedSimulation {
+    /// Simulation ID
+    pub id: String,
+
+    /// Public URL of the shared simulation
+    pub url: String,
+
+    /// Share token/ID returned by the API, if any
+    #[serde(default)]
+    pub shared_id: Option<String>,
+}
+
+/// Body of a share response (the endpoint usually returns no content)
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct ShareResponse {
+    #[serde(default, alias = "share_url", alias = "public_url")]
+    pub url: Option<String>,
+
+    #[serde(default, alias = "share_id", alias = "token", alias = "id")]
+    pub shared_id: Option<String>,
+}
+
+impl std::fmt::Display for SharedSimulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.url)
+    }
+}
+
+/// Simulation details returned by [`SimulationApi::info`](super::SimulationApi::info)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationInfo {
+    /// Simulated transaction
+    #[serde(default)]
+    pub transaction_info: Option<SimulationInfoTransaction>,
+
+    /// State overrides applied to the simulation
+    #[serde(default, deserialize_with = "crate::utils::deserialize_null_default")]
+    pub state_overrides: HashMap<String, StateOverride>,
+
+    /// Creation time (Unix milliseconds)
+    #[serde(default)]
+    pub created_at: Option<u64>,
+}
+
+/// Transaction section of [`SimulationInfo`]
+///
+/// The info endpoint reports numbers as decimal strings; they are parsed
+/// into integers here.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationInfoTransaction {
+    /// Simulation ID
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Network ID
+    #[serde(default)]
+    pub network_id: Option<String>,
+
+    /// Block number
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    pub block_number: Option<u64>,
+
+    /// Transaction index in the block
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    pub transaction_index: Option<u64>,
+
+    /// Sender address
+    #[serde(default)]
+    pub from: Option<String>,
+
+    /// Recipient address
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// Calldata
+    #[serde(default)]
+    pub input: Option<String>,
+
+    /// Nonce
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    pub nonce: Option<u64>,
+
+    /// Value in wei (decimal string)
+    #[serde(default, deserialize_with = "deserialize_flex_string")]
+    pub value: Option<String>,
+
+    /// Gas limit
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    pub gas: Option<u64>,
+
+    /// Gas price in wei (decimal string)
+    #[serde(default, deserialize_with = "deserialize_flex_string")]
+    pub gas_price: Option<String>,
+
+    /// Gas used
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    pub gas_used: Option<u64>,
+
+    /// Whether the transaction succeeded
+    #[serde(default)]
+    pub status: Option<bool>,
+
+    /// Error message (empty on success)
+    #[serde(default)]
+    pub error_message: Option<String>,
+}
+
+/// Trace of a mined transaction returned by
+/// [`SimulationApi::trace`](super::SimulationApi::trace)
+///
+/// Accepts both a bare transaction object and one nested under
+/// `transaction`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "TraceRepr")]
+pub struct TransactionTrace {
+    /// Transaction hash
+    pub hash: Option<String>,
+
+    /// Block number
+    pub block_number: Option<u64>,
+
+    /// Transaction index in the block
+    pub transaction_index: Option<u64>,
+
+    /// Sender address
+    pub from: Option<String>,
+
+    /// Recipient address
+    pub to: Option<String>,
+
+    /// Gas used
+    pub gas_used: Option<u64>,
+
+    /// Whether the transaction succeeded
+    pub status: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TraceRepr {
+    Nested { transaction: TraceFields },
+    Flat(TraceFields),
+}
+
+// Wire format of `TransactionTrace`; numbers may arrive as strings
+#[derive(Deserialize)]
+struct TraceFields {
+    #[serde(default)]
+    hash: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    block_number: Option<u64>,
+    #[serde(default, alias = "index", deserialize_with = "deserialize_flex_u64")]
+    transaction_index: Option<u64>,
+    #[serde(default)]
+    from: Option<String>,
+    #[serde(default)]
+    to: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_flex_u64")]
+    gas_used: Option<u64>,
+    #[serde(default)]
+    status: Option<bool>,
+}
+
+impl From<TraceRepr> for TransactionTrace {
+    fn from(repr: TraceRepr) -> Self {
+        let (TraceRepr::Nested { transaction: f } | TraceRepr::Flat(f)) = repr;
+        Self {
+            hash: f.hash,
+            block_number: f.block_number,
+            transaction_index: f.transaction_index,
+            from: f.from,
+            to: f.to,
+            gas_used: f.gas_used,
+            status: f.status,
+        }
+    }
+}
+
+/// Accept an integer as a JSON number, decimal string, or `0x` hex string
+fn deserialize_flex_u64<'de, D>(deserializer: D) -> std::result::Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flex {
+        Number(u64),
+        Text(String),
+    }
+
+    match Option::<Flex>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Flex::Number(n)) => Ok(Some(n)),
+        Some(Flex::Text(s)) if s.is_empty() => Ok(None),
+        Some(Flex::Text(s)) => match s.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => s.parse(),
+        }
+        .map(Some)
+        .map_err(serde::de::Error::custom),
+    }
+}
+
+/// Accept a quantity as a JSON number or string, keeping it as a string
+fn deserialize_flex_string<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flex {
+        Number(serde_json::Number),
+        Text(String),
+    }
+
+    Ok(Option::<Flex>::deserialize(deserializer)?.map(|v| match v {
+        Flex::Number(n) => n.to_string(),
+        Flex::Text(s) => s,
+    }))
+}
+
 /// Transaction trace request
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TraceRequest {
     /// Transaction hash to trace
     pub hash: String,
@@ -0,0 +1,484 @@
+//! Mock Tenderly server and assertions for downstream tests
+//!
+//! [`MockTenderly`] starts a local [`wiremock`] server with canned responses
+//! for simulation, VNet create/list/get and the VNet Admin RPC, and hands out
+//! a [`Client`] pointed at it. [`SimulationAssert`] checks simulation results
+//! with readable failure messages. Requires the `testkit` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use tndrly::simulation::SimulationRequest;
+//! use tndrly::testkit::MockTenderly;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> tndrly::Result<()> {
+//! let mock = MockTenderly::start().await;
+//! let client = mock.client();
+//!
+//! let request = SimulationRequest::new("0x1111111111111111111111111111111111111111", "0x2222222222222222222222222222222222222222", "0xa9059cbb");
+//! let response = client.simulation().simulate(&request).await?;
+//! assert!(response.simulation.status);
+//! assert_eq!(response.simulation.input, "0xa9059cbb");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::{Client, Config};
+use crate::simulation::SimulationResponse;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+/// Transaction hash returned for Admin RPC methods without a configured result
+pub const MOCK_TX_HASH: &str = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+
+/// Local mock of the Tenderly API
+///
+/// Canned endpoints:
+///
+/// - `POST /simulate` returns a successful simulation echoing the request's
+///   `from`, `to`, `input` and `network_id`
+/// - `POST /vnets` returns [`MockTenderly::vnet`] with the requested slug,
+///   display name and network
+/// - `GET /vnets` and `GET /vnets/{VNET_ID}` return [`MockTenderly::vnet`]
+/// - The Admin RPC URL listed on the VNet answers every method with the
+///   result set by [`rpc_result`](Self::rpc_result), falling back to a few
+///   defaults (`evm_snapshot`, `evm_revert`, `eth_chainId`,
+///   `eth_blockNumber`, `eth_call`, `eth_getLogs`) and then to
+///   [`MOCK_TX_HASH`]
+///
+/// Use [`server`](Self::server) to mount additional mocks.
+pub struct MockTenderly {
+    server: MockServer,
+    simulation: Arc<Mutex<Value>>,
+    vnet: Arc<Value>,
+    rpc_results: Arc<Mutex<HashMap<String, Value>>>,
+}
+
+impl MockTenderly {
+    /// Access key used by [`client`](Self::client)
+    pub const ACCESS_KEY: &'static str = "test-key";
+    /// Account slug used by [`client`](Self::client)
+    pub const ACCOUNT: &'static str = "account";
+    /// Project slug used by [`client`](Self::client)
+    pub const PROJECT: &'static str = "project";
+    /// ID of the canned VNet
+    pub const VNET_ID: &'static str = "mockvnet";
+
+    /// Start the server and mount the canned responses
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+        let vnet = json!({
+            "id": Self::VNET_ID,
+            "slug": "mock-vnet",
+            "display_name": "Mock VNet",
+            "fork_config": {"network_id": 1},
+            "virtual_network_config": {"chain_config": {"chain_id": 1}},
+            "status": "running",
+            "rpcs": [
+                {"name": "Admin RPC", "url": format!("{}/admin-rpc", server.uri())},
+                {"name": "Public RPC", "url": format!("{}/public-rpc", server.uri())}
+            ]
+        });
+        let mock = Self {
+            server,
+            simulation: Arc::new(Mutex::new(default_simulation())),
+            vnet: Arc::new(vnet),
+            rpc_results: Arc::new(Mutex::new(HashMap::new())),
+        };
+        mock.mount().await;
+        mock
+    }
+
+    /// A client pointed at the mock server
+    pub fn client(&self) -> Client {
+        Client::new(self.config()).expect("mock config is valid")
+    }
+
+    /// Configuration pointed at the mock server, for adding retries or middleware
+    pub fn config(&self) -> Config {
+        Config::new(Self::ACCESS_KEY, Self::ACCOUNT, Self::PROJECT).with_base_url(self.uri())
+    }
+
+    /// Base URL of the mock server
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// URL of the canned VNet's Admin RPC
+    pub fn admin_rpc_url(&self) -> String {
+        format!("{}/admin-rpc", self.server.uri())
+    }
+
+    /// The underlying wiremock server
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// The canned VNet returned by the VNet endpoints
+    pub fn vnet(&self) -> crate::vnets::VNet {
+        serde_json::from_value((*self.vnet).clone()).expect("canned VNet is valid")
+    }
+
+    /// Replace the body returned by `POST /simulate`
+    ///
+    /// The body is returned as-is, without echoing request fields.
+    pub fn simulation_response(&self, response: impl Serialize) {
+        *self.simulation.lock().unwrap() =
+            serde_json::to_value(response).expect("response must serialize to JSON");
+    }
+
+    /// Set the result returned for an Admin RPC method
+    pub fn rpc_result(&self, method: &str, result: impl Serialize) {
+        let result = serde_json::to_value(result).expect("result must serialize to JSON");
+        self.rpc_results
+            .lock()
+            .unwrap()
+            .insert(method.to_string(), result);
+    }
+
+    async fn mount(&self) {
+        let project = format!("/account/{}/project/{}", Self::ACCOUNT, Self::PROJECT);
+
+        let simulation = Arc::clone(&self.simulation);
+        let default = default_simulation();
+        Mock::given(method("POST"))
+            .and(path(format!("{project}/simulate")))
+            .respond_with(move |request: &Request| {
+                let mut body = simulation.lock().unwrap().clone();
+                if body == default {
+                    let sent: Value = serde_json::from_slice(&request.body).unwrap_or_default();
+                    for field in ["from", "to", "input", "network_id"] {
+                        if let Some(value) = sent.get(field) {
+                            body["simulation"][field] = value.clone();
+                        }
+                    }
+                }
+                ResponseTemplate::new(200).set_body_json(body)
+            })
+            .mount(&self.server)
+            .await;
+
+        let vnet = Arc::clone(&self.vnet);
+        Mock::given(method("POST"))
+            .and(path(format!("{project}/vnets")))
+            .respond_with(move |request: &Request| {
+                let mut body = (*vnet).clone();
+                let sent: Value = serde_json::from_slice(&request.body).unwrap_or_default();
+                for field in ["slug", "display_name", "fork_config"] {
+                    if let Some(value) = sent.get(field) {
+                        body[field] = value.clone();
+                    }
+                }
+                ResponseTemplate::new(200).set_body_json(body)
+            })
+            .mount(&self.server)
+            .await;
+
+        let vnet = Arc::clone(&self.vnet);
+        Mock::given(method("GET"))
+            .and(path(format!("{project}/vnets")))
+            .respond_with(move |_: &Request| ResponseTemplate::new(200).set_body_json([&*vnet]))
+            .mount(&self.server)
+            .await;
+
+        let vnet = Arc::clone(&self.vnet);
+        Mock::given(method("GET"))
+            .and(path(format!("{project}/vnets/{}", Self::VNET_ID)))
+            .respond_with(move |_: &Request| ResponseTemplate::new(200).set_body_json(&*vnet))
+            .mount(&self.server)
+            .await;
+
+        let results = Arc::clone(&self.rpc_results);
+        Mock::given(method("POST"))
+            .and(path("/admin-rpc"))
+            .respond_with(move |request: &Request| {
+                let results = results.lock().unwrap();
+                let body = match serde_json::from_slice::<Value>(&request.body) {
+                    Ok(Value::Array(calls)) => Value::Array(
+                        calls
+                            .iter()
+                            .map(|call| rpc_response(call, &results))
+                            .collect(),
+                    ),
+                    Ok(call) => rpc_response(&call, &results),
+                    Err(_) => return ResponseTemplate::new(400),
+                };
+                ResponseTemplate::new(200).set_body_json(body)
+            })
+            .mount(&self.server)
+            .await;
+    }
+}
+
+/// Assertions on a [`SimulationResponse`]
+///
+/// Each check returns `Err` with a message describing the actual result, so
+/// it can be unwrapped or returned from a test.
+///
+/// ```
+/// # use tndrly::simulation::SimulationResponse;
+/// use tndrly::testkit::SimulationAssert;
+///
+/// # fn check(response: &SimulationResponse) -> Result<(), String> {
+/// let check = SimulationAssert::new(response);
+/// check.succeeds()?;
+/// check.gas_under(100_000)?;
+/// check.emits("Transfer")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationAssert<'a> {
+    response: &'a SimulationResponse,
+}
+
+impl<'a> SimulationAssert<'a> {
+    /// Wrap a simulation response
+    pub fn new(response: &'a SimulationResponse) -> Self {
+        Self { response }
+    }
+
+    /// The simulated transaction succeeded
+    pub fn succeeds(&self) -> Result<(), String> {
+        if self.response.simulation.status {
+            return Ok(());
+        }
+        Err(match self.revert_reasons().first() {
+            Some(reason) => format!("expected success, but reverted with \"{reason}\""),
+            None => "expected success, but reverted".to_string(),
+        })
+    }
+
+    /// The simulated transaction reverted with a reason containing `reason`
+    ///
+    /// Matches against the error of every frame in the call trace, so a
+    /// revert bubbled up from a subcall is found too.
+    pub fn reverts_with(&self, reason: &str) -> Result<(), String> {
+        if self.response.simulation.status {
+            return Err(format!(
+                "expected revert with \"{reason}\", but the transaction succeeded"
+            ));
+        }
+        let reasons = self.revert_reasons();
+        if reasons.iter().any(|r| r.contains(reason)) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected revert with \"{reason}\", but got {reasons:?}"
+            ))
+        }
+    }
+
+    /// The simulation used less than `limit` gas
+    pub fn gas_under(&self, limit: u64) -> Result<(), String> {
+        let used = self.response.simulation.gas_used;
+        if used < limit {
+            Ok(())
+        } else {
+            Err(format!("expected gas under {limit}, but used {used}"))
+        }
+    }
+
+    /// The simulation emitted a decoded event named `event_name`
+    pub fn emits(&self, event_name: &str) -> Result<(), String> {
+        let events = self.event_names();
+        if events.contains(&event_name) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected event \"{event_name}\", but emitted {events:?}"
+            ))
+        }
+    }
+
+    /// Revert reasons from the call trace, outermost frame first
+    fn revert_reasons(&self) -> Vec<String> {
+        self.response
+            .call_trace()
+            .map(|trace| {
+                trace
+                    .flatten()
+                    .into_iter()
+                    .filter_map(|(_, frame)| frame.error.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Names of decoded logs, preferring `transaction_info.logs`
+    fn event_names(&self) -> Vec<&'a str> {
+        let Some(transaction) = self.response.transaction.as_ref() else {
+            return Vec::new();
+        };
+        transaction
+            .transaction_info
+            .as_ref()
+            .and_then(|info| info.logs.as_ref())
+            .or(transaction.logs.as_ref())
+            .map(|logs| {
+                logs.iter()
+                    .filter_map(|log| log.get("name").and_then(Value::as_str))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn default_simulation() -> Value {
+    json!({
+        "simulation": {
+            "id": "mocksim",
+            "network_id": "1",
+            "block_number": 19_000_000,
+            "from": "0x0000000000000000000000000000000000000000",
+            "to": "0x0000000000000000000000000000000000000000",
+            "input": "0x",
+            "gas": 8_000_000,
+            "gas_used": 21_000,
+            "value": "0",
+            "status": true
+        },
+        "transaction": {
+            "status": true,
+            "gas_used": 21_000,
+            "logs": []
+        }
+    })
+}
+
+fn rpc_response(call: &Value, results: &HashMap<String, Value>) -> Value {
+    let id = call.get("id").cloned().unwrap_or(Value::Null);
+    let method = call
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let result = results
+        .get(method)
+        .cloned()
+        .unwrap_or_else(|| match method {
+            "evm_snapshot" | "eth_chainId" | "eth_blockNumber" => json!("0x1"),
+            "evm_revert" => json!(true),
+            "eth_call" => json!("0x"),
+            "eth_getLogs" => json!([]),
+            _ => json!(MOCK_TX_HASH),
+        });
+    json!({"jsonrpc": "2.0", "id": id, "result": result})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vnets::CreateVNetRequest;
+
+    #[tokio::test]
+    async fn test_mock_vnets_and_admin_rpc() {
+        let mock = MockTenderly::start().await;
+        let client = mock.client();
+
+        let vnet = client
+            .vnets()
+            .create(&CreateVNetRequest::new("ci-run", "CI Run", 8453))
+            .await
+            .unwrap();
+        assert_eq!(vnet.slug, "ci-run");
+        assert_eq!(vnet.fork_config.network_id, 8453);
+
+        let vnets = client.vnets().list(None).await.unwrap();
+        assert_eq!(vnets.len(), 1);
+        assert_eq!(vnets[0].id, MockTenderly::VNET_ID);
+
+        let admin = client
+            .vnets()
+            .admin_rpc(MockTenderly::VNET_ID)
+            .await
+            .unwrap();
+        assert_eq!(admin.snapshot().await.unwrap(), "0x1");
+        assert!(admin.revert("0x1").await.unwrap());
+        assert_eq!(
+            admin
+                .set_balance("0x1111111111111111111111111111111111111111", "1000")
+                .await
+                .unwrap(),
+            MOCK_TX_HASH
+        );
+
+        mock.rpc_result("tenderly_setBalance", "0xbeef");
+        assert_eq!(
+            admin
+                .set_balance("0x1111111111111111111111111111111111111111", "1000")
+                .await
+                .unwrap(),
+            "0xbeef"
+        );
+    }
+
+    fn response(status: bool, transaction_info: Value) -> SimulationResponse {
+        let mut body = default_simulation();
+        body["simulation"]["status"] = status.into();
+        body["simulation"]["gas_used"] = 52_000.into();
+        body["transaction"]["transaction_info"] = transaction_info;
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn test_simulation_assert_success() {
+        let response = response(
+            true,
+            json!({
+                "call_trace": {"call_type": "CALL", "gas_used": 52_000},
+                "logs": [{"name": "Approval"}, {"name": "Transfer"}]
+            }),
+        );
+        let check = SimulationAssert::new(&response);
+
+        assert!(check.succeeds().is_ok());
+        assert!(check.gas_under(60_000).is_ok());
+        assert_eq!(
+            check.gas_under(50_000).unwrap_err(),
+            "expected gas under 50000, but used 52000"
+        );
+        assert!(check.emits("Transfer").is_ok());
+        assert_eq!(
+            check.emits("Deposit").unwrap_err(),
+            "expected event \"Deposit\", but emitted [\"Approval\", \"Transfer\"]"
+        );
+        assert!(check
+            .reverts_with("balance")
+            .unwrap_err()
+            .contains("succeeded"));
+    }
+
+    #[test]
+    fn test_simulation_assert_revert() {
+        let response = response(
+            false,
+            json!({
+                "call_trace": {
+                    "call_type": "CALL",
+                    "error": "execution reverted",
+                    "calls": [{
+                        "call_type": "CALL",
+                        "error": "ERC20: transfer amount exceeds balance"
+                    }]
+                }
+            }),
+        );
+        let check = SimulationAssert::new(&response);
+
+        assert_eq!(
+            check.succeeds().unwrap_err(),
+            "expected success, but reverted with \"execution reverted\""
+        );
+        assert!(check.reverts_with("exceeds balance").is_ok());
+        assert!(check
+            .reverts_with("paused")
+            .unwrap_err()
+            .contains("ERC20: transfer amount exceeds balance"));
+        assert!(check.emits("Transfer").is_err());
+    }
+}
@@ -0,0 +1,224 @@
+//! 256-bit unsigned integer
+
+use crate::error::{Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// 256-bit unsigned integer (big-endian)
+///
+/// Minimal type covering what the crate needs for wei amounts and ABI
+/// values: conversion from primitive integers, decimal/hex parsing, and
+/// decimal/hex formatting.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256([u8; 32]);
+
+impl U256 {
+    /// Zero
+    pub const ZERO: Self = Self([0; 32]);
+
+    /// Maximum value (2^256 - 1)
+    pub const MAX: Self = Self([0xff; 32]);
+
+    /// Create from big-endian bytes
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Create from a big-endian slice of at most 32 bytes
+    #[must_use]
+    pub fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > 32 {
+            return None;
+        }
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(bytes);
+        Some(Self(out))
+    }
+
+    /// Big-endian byte representation
+    #[must_use]
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Check if the value is zero
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|b| *b == 0)
+    }
+
+    /// Convert to `u64` if it fits
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        if self.0[..24].iter().any(|b| *b != 0) {
+            return None;
+        }
+        Some(u64::from_be_bytes(self.0[24..].try_into().ok()?))
+    }
+
+    /// Convert to `u128` if it fits
+    #[must_use]
+    pub fn as_u128(&self) -> Option<u128> {
+        if self.0[..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        Some(u128::from_be_bytes(self.0[16..].try_into().ok()?))
+    }
+
+    /// Two's complement representation of a signed integer
+    #[must_use]
+    pub fn from_i128(value: i128) -> Self {
+        let fill = if value < 0 { 0xff } else { 0 };
+        let mut out = [fill; 32];
+        out[16..].copy_from_slice(&value.to_be_bytes());
+        Self(out)
+    }
+
+    /// Parse a decimal string
+    pub fn from_dec_str(value: &str) -> Result<Self> {
+        if value.is_empty() {
+            return Err(Error::invalid_param("Empty integer string"));
+        }
+        let mut out = [0u8; 32];
+        for c in value.chars() {
+            let digit = c.to_digit(10).ok_or_else(|| {
+                Error::invalid_param(format!("Invalid decimal integer: {}", value))
+            })?;
+            let mut carry = digit;
+            for byte in out.iter_mut().rev() {
+                let v = u32::from(*byte) * 10 + carry;
+                *byte = v as u8;
+                carry = v >> 8;
+            }
+            if carry != 0 {
+                return Err(Error::invalid_param(format!(
+                    "Integer overflows 256 bits: {}",
+                    value
+                )));
+            }
+        }
+        Ok(Self(out))
+    }
+
+    /// Parse a hex string (with or without `0x` prefix)
+    pub fn from_hex_str(value: &str) -> Result<Self> {
+        let digits = value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+            .unwrap_or(value);
+        let padded = if digits.len() % 2 == 1 {
+            format!("0{}", digits)
+        } else {
+            digits.to_string()
+        };
+        crate::utils::decode_hex(&padded)
+            .filter(|_| !digits.is_empty())
+            .and_then(|bytes| {
+                let start = bytes.iter().take_while(|b| **b == 0).count();
+                Self::from_be_slice(&bytes[start..])
+            })
+            .ok_or_else(|| Error::invalid_param(format!("Invalid hex integer: {}", value)))
+    }
+}
+
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        let mut value = self.0;
+        let mut digits = Vec::new();
+        while value.iter().any(|b| *b != 0) {
+            let mut rem = 0u32;
+            for byte in value.iter_mut() {
+                let v = (rem << 8) | u32::from(*byte);
+                *byte = (v / 10) as u8;
+                rem = v % 10;
+            }
+            digits.push(b'0' + rem as u8);
+        }
+        digits.reverse();
+        f.write_str(std::str::from_utf8(&digits).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl fmt::Debug for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "U256({})", self)
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = crate::utils::encode_hex(&self.0);
+        let digits = hex[2..].trim_start_matches('0');
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        f.write_str(if digits.is_empty() { "0" } else { digits })
+    }
+}
+
+impl FromStr for U256 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.starts_with("0x") || s.starts_with("0X") {
+            Self::from_hex_str(s)
+        } else {
+            Self::from_dec_str(s)
+        }
+    }
+}
+
+macro_rules! impl_from_uint {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for U256 {
+                fn from(value: $t) -> Self {
+                    Self::from(u128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_uint!(u8, u16, u32, u64, bool);
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        let mut out = [0u8; 32];
+        out[16..].copy_from_slice(&value.to_be_bytes());
+        Self(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u256_decimal_roundtrip() {
+        let value = U256::from_dec_str("1000000000000000000").unwrap();
+        assert_eq!(value, U256::from(1_000_000_000_000_000_000u128));
+        assert_eq!(value.to_string(), "1000000000000000000");
+        assert_eq!(format!("{:#x}", value), "0xde0b6b3a7640000");
+
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(U256::from_dec_str(max).unwrap(), U256::MAX);
+        assert_eq!(U256::MAX.to_string(), max);
+        assert!(U256::from_dec_str(&format!("{}0", max)).is_err());
+        assert_eq!(U256::ZERO.to_string(), "0");
+    }
+
+    #[test]
+    fn test_u256_from_str() {
+        assert_eq!("0xff".parse::<U256>().unwrap(), U256::from(255u8));
+        assert_eq!("0x100".parse::<U256>().unwrap(), U256::from(256u16));
+        assert_eq!("42".parse::<U256>().unwrap().as_u64(), Some(42));
+        assert!("0x".parse::<U256>().is_err());
+        assert!("12a".parse::<U256>().is_err());
+        assert_eq!(U256::from_i128(-1), U256::MAX);
+    }
+}
@@ -55,6 +55,113 @@ pub fn normalize_address(address: &str) -> Option<String> {
     Some(address.to_lowercase())
 }
 
+/// Converts an address to its EIP-55 mixed-case checksum form.
+///
+/// Returns `None` if the address is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use tndrly::utils::to_checksum_address;
+///
+/// assert_eq!(
+///     to_checksum_address("0xd8da6bf26964af9d7eed9e03e53415d37aa96045").as_deref(),
+///     Some("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045")
+/// );
+/// ```
+#[cfg(any(feature = "abi", feature = "raw-tx"))]
+#[must_use]
+pub fn to_checksum_address(address: &str) -> Option<String> {
+    let lower = normalize_address(address)?;
+    let hex = &lower[2..];
+    let hash = keccak256(hex.as_bytes());
+    let checksummed = hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect::<String>();
+    Some(format!("0x{}", checksummed))
+}
+
+/// Validates an address's EIP-55 checksum.
+///
+/// All-lowercase and all-uppercase addresses carry no checksum and are
+/// accepted; mixed-case addresses must match their checksum exactly.
+///
+/// # Examples
+///
+/// ```
+/// use tndrly::utils::is_valid_checksum;
+///
+/// assert!(is_valid_checksum("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"));
+/// assert!(is_valid_checksum("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"));
+/// assert!(!is_valid_checksum("0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045"));
+/// ```
+#[cfg(any(feature = "abi", feature = "raw-tx"))]
+#[must_use]
+pub fn is_valid_checksum(address: &str) -> bool {
+    if !is_valid_address(address) {
+        return false;
+    }
+    let hex = &address[2..];
+    if !is_mixed_case(hex) {
+        return true;
+    }
+    to_checksum_address(address).is_some_and(|expected| expected[2..] == *hex)
+}
+
+fn is_mixed_case(hex: &str) -> bool {
+    hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// Normalize a user-supplied address for a request field
+///
+/// Trims whitespace, adds a missing `0x` prefix and lowercases. With
+/// `strict`, mixed-case input must carry a valid EIP-55 checksum.
+pub(crate) fn parse_address(
+    field: &str,
+    address: &str,
+    strict: bool,
+) -> crate::error::Result<String> {
+    use crate::error::Error;
+
+    let invalid = |reason: &str| {
+        Error::invalid_param(format!(
+            "Invalid `{}` address: {} ({})",
+            field, address, reason
+        ))
+    };
+    let trimmed = address.trim();
+    let hex = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+    if hex.len() != 40 {
+        return Err(invalid("expected 20 bytes"));
+    }
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid("not hex"));
+    }
+    if strict && is_mixed_case(hex) {
+        #[cfg(any(feature = "abi", feature = "raw-tx"))]
+        if !is_valid_checksum(&format!("0x{}", hex)) {
+            return Err(invalid("bad EIP-55 checksum"));
+        }
+        #[cfg(not(any(feature = "abi", feature = "raw-tx")))]
+        return Err(Error::unsupported(
+            "checksum validation requires the `abi` feature",
+        ));
+    }
+    Ok(format!("0x{}", hex.to_lowercase()))
+}
+
 /// Validates a transaction hash format.
 ///
 /// Returns `true` if the hash:
@@ -84,6 +191,65 @@ pub fn is_valid_tx_hash(hash: &str) -> bool {
     hex_part.chars().all(|c| c.is_ascii_hexdigit())
 }
 
+/// Encode bytes as a `0x`-prefixed lowercase hex string
+#[allow(dead_code)]
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(2 + bytes.len() * 2);
+    out.push_str("0x");
+    for b in bytes {
+        out.push_str(&format!("{:02x}", b));
+    }
+    out
+}
+
+/// Decode a hex string (with or without `0x` prefix) into bytes
+#[allow(dead_code)]
+pub(crate) fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    let hex = value.trim();
+    let hex = hex
+        .strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex);
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Keccak-256 hash
+#[cfg(any(feature = "abi", feature = "raw-tx"))]
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let mut hasher = Keccak::v256();
+    let mut out = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut out);
+    out
+}
+
+/// Parse a hex quantity (with or without `0x` prefix) into a `u64`
+pub(crate) fn parse_hex_u64(value: &str) -> Option<u64> {
+    let hex = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    u64::from_str_radix(hex, 16).ok()
+}
+
+/// Deserialize `null` as the type's default value
+pub(crate) fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + serde::Deserialize<'de>,
+{
+    let opt = <Option<T> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(opt.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +293,47 @@ mod tests {
         assert_eq!(normalize_address("invalid"), None);
     }
 
+    #[test]
+    fn test_parse_address() {
+        let lower = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+
+        // Lowercased and prefixed
+        assert_eq!(
+            parse_address("from", "0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045", false).unwrap(),
+            lower
+        );
+        assert_eq!(
+            parse_address("from", " d8dA6BF26964aF9D7eEd9e03E53415D37aA96045", false).unwrap(),
+            lower
+        );
+
+        // Wrong length
+        let err = parse_address("to", "0x1234", false).unwrap_err();
+        assert!(err.to_string().contains("`to`"));
+        assert!(err.to_string().contains("20 bytes"));
+        assert!(parse_address("to", &format!("{}00", lower), false).is_err());
+        assert!(parse_address("to", "0xGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGGG", false).is_err());
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn test_parse_address_strict_checksum() {
+        let checksummed = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        let bad = "0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+
+        assert_eq!(
+            to_checksum_address("0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045").as_deref(),
+            Some(checksummed)
+        );
+        assert!(parse_address("from", checksummed, true).is_ok());
+        // Unchecksummed input carries no checksum to verify
+        assert!(parse_address("from", &checksummed.to_lowercase(), true).is_ok());
+        // A mixed-case typo is only caught in strict mode
+        assert!(parse_address("from", bad, false).is_ok());
+        let err = parse_address("from", bad, true).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+    }
+
     #[test]
     fn test_valid_tx_hashes() {
         assert!(is_valid_tx_hash(
@@ -143,4 +350,40 @@ mod tests {
         assert!(!is_valid_tx_hash("invalid"));
         assert!(!is_valid_tx_hash("")); // No prefix
     }
+
+    #[test]
+    fn test_hex_roundtrip() {
+        assert_eq!(encode_hex(&[0xde, 0xad, 0x00]), "0xdead00");
+        assert_eq!(decode_hex("0xdead00"), Some(vec![0xde, 0xad, 0x00]));
+        assert_eq!(decode_hex("DEAD"), Some(vec![0xde, 0xad]));
+        assert_eq!(decode_hex("0x"), Some(vec![]));
+        assert_eq!(decode_hex("0xabc"), None);
+        assert_eq!(decode_hex("0xzz"), None);
+    }
+
+    #[test]
+    fn test_parse_hex_u64() {
+        assert_eq!(parse_hex_u64("0x0"), Some(0));
+        assert_eq!(parse_hex_u64("0x5208"), Some(21_000));
+        assert_eq!(parse_hex_u64("0X64"), Some(100));
+        assert_eq!(parse_hex_u64("170abab"), Some(24_161_195));
+        assert_eq!(parse_hex_u64("0xffffffffffffffff"), Some(u64::MAX));
+        assert_eq!(parse_hex_u64("0x10000000000000000"), None);
+        assert_eq!(parse_hex_u64("0xzz"), None);
+        assert_eq!(parse_hex_u64("0x"), None);
+    }
+
+    #[test]
+    fn test_deserialize_null_default() {
+        #[derive(serde::Deserialize)]
+        struct Wrapper {
+            #[serde(default, deserialize_with = "deserialize_null_default")]
+            items: Vec<u8>,
+        }
+
+        let parse = |json| serde_json::from_str::<Wrapper>(json).unwrap().items;
+        assert!(parse(r#"{"items": null}"#).is_empty());
+        assert!(parse("{}").is_empty());
+        assert_eq!(parse(r#"{"items": [1, 2]}"#), vec![1, 2]);
+    }
 }
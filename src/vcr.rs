@@ -0,0 +1,453 @@
+//! Record and replay API interactions (VCR-style)
+//!
+//! A [`Cassette`] registered with [`Config::with_vcr`](crate::Config::with_vcr)
+//! either records every request/response pair to a JSON file, or replays a
+//! recorded file without touching the network. Requires the `vcr` feature.
+//!
+//! Interactions are keyed by method, URL and a hash of the request body.
+//! For API requests the client's base URL (including any `/api/v1` prefix)
+//! is stripped from the URL, so a cassette recorded against the real API
+//! replays against any base URL. Admin RPC calls made through
+//! [`VNetsApi::admin_rpc`](crate::vnets::VNetsApi::admin_rpc) share the
+//! client's cassette and are keyed by their full URL; attach a cassette to a
+//! standalone [`AdminRpc`](crate::vnets::AdminRpc) with
+//! [`AdminRpc::with_vcr`](crate::vnets::AdminRpc::with_vcr). Request headers
+//! are not part of the key and are never written, so cassettes contain no
+//! access keys.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use tndrly::vcr::Cassette;
+//! use tndrly::{Client, Config};
+//!
+//! # fn example() -> tndrly::Result<()> {
+//! // Record once against the real API...
+//! let cassette = Arc::new(Cassette::record("tests/cassettes/vnets.json"));
+//! let client = Client::new(Config::from_env()?.with_vcr(cassette))?;
+//!
+//! // ...then replay offline
+//! let cassette = Arc::new(Cassette::replay("tests/cassettes/vnets.json")?);
+//! let client = Client::new(Config::new("unused", "account", "project").with_vcr(cassette))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// Whether a [`Cassette`] records or replays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Mode {
+    /// Send requests and append each interaction to the cassette file
+    Record,
+    /// Serve responses from the cassette file; nothing is sent
+    Replay,
+}
+
+impl Mode {
+    /// Get the string representation
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Record => "record",
+            Self::Replay => "replay",
+        }
+    }
+}
+
+impl std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "record" => Ok(Self::Record),
+            "replay" => Ok(Self::Replay),
+            _ => Err(format!(
+                "Invalid VCR mode: {}. Expected: record or replay",
+                s
+            )),
+        }
+    }
+}
+
+/// A file of recorded request/response pairs
+///
+/// When replaying, repeated identical requests (e.g., polling a VNet's
+/// status) get the matching recordings in order; once those run out the
+/// last one is repeated.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mode,
+    state: Mutex<State>,
+    /// Serializes file writes so the last write holds every interaction
+    write: tokio::sync::Mutex<()>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    interactions: Vec<Interaction>,
+    /// Replay position per key
+    served: HashMap<Key, usize>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// One recorded request/response pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    #[serde(flatten)]
+    key: Key,
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct Key {
+    method: String,
+    /// URL relative to the client's base URL, or the full URL otherwise
+    path: String,
+    body_hash: String,
+}
+
+impl Key {
+    /// Key `request`, dropping `base_url` from the front of its URL
+    fn from_request(request: &reqwest::Request, base_url: &str) -> Self {
+        let url = request.url().as_str();
+        let base = base_url.trim_end_matches('/');
+        let path = url
+            .strip_prefix(base)
+            .filter(|rest| !base.is_empty() && (rest.is_empty() || rest.starts_with(['/', '?'])))
+            .unwrap_or(url)
+            .to_string();
+        let body = request
+            .body()
+            .and_then(|b| b.as_bytes())
+            .unwrap_or_default();
+        Self {
+            method: request.method().to_string(),
+            path,
+            body_hash: format!("{:016x}", fnv1a(body)),
+        }
+    }
+}
+
+impl Cassette {
+    /// Record interactions to `path`, replacing any existing file
+    ///
+    /// The file is written after every response, so a test that fails
+    /// midway still leaves the interactions recorded so far.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: Mode::Record,
+            state: Mutex::default(),
+            write: tokio::sync::Mutex::default(),
+        }
+    }
+
+    /// Replay interactions recorded in `path`
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| Error::from(e).context(format!("reading cassette {}", path.display())))?;
+        let file: CassetteFile = serde_json::from_str(&contents)?;
+        Ok(Self {
+            path,
+            mode: Mode::Replay,
+            state: Mutex::new(State {
+                interactions: file.interactions,
+                served: HashMap::new(),
+            }),
+            write: tokio::sync::Mutex::default(),
+        })
+    }
+
+    /// Path of the cassette file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether this cassette records or replays
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Number of interactions recorded or loaded
+    pub fn len(&self) -> usize {
+        self.lock().interactions.len()
+    }
+
+    /// Whether the cassette has no interactions
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replay `request`, or send it with `send` and record the response
+    ///
+    /// `base_url` is stripped from the request URL to form the key.
+    pub(crate) async fn send<F, Fut>(
+        &self,
+        request: reqwest::Request,
+        base_url: &str,
+        send: F,
+    ) -> Result<reqwest::Response>
+    where
+        F: FnOnce(reqwest::Request) -> Fut,
+        Fut: Future<Output = Result<reqwest::Response>>,
+    {
+        match self.mode {
+            Mode::Replay => self.replay_response(&request, base_url),
+            Mode::Record => {
+                let key = Key::from_request(&request, base_url);
+                let response = send(request).await?;
+                self.record_response(key, response).await
+            }
+        }
+    }
+
+    /// Look up the response for `request`
+    fn replay_response(
+        &self,
+        request: &reqwest::Request,
+        base_url: &str,
+    ) -> Result<reqwest::Response> {
+        let key = Key::from_request(request, base_url);
+        let mut state = self.lock();
+        let matches: Vec<usize> = state
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.key == key)
+            .map(|(index, _)| index)
+            .collect();
+        let Some(&last) = matches.last() else {
+            return Err(Error::config(format!(
+                "cassette {} has no interaction for {} {}",
+                self.path.display(),
+                key.method,
+                key.path
+            )));
+        };
+        let served = state.served.entry(key).or_default();
+        let index = matches.get(*served).copied().unwrap_or(last);
+        *served += 1;
+        state.interactions[index].to_response()
+    }
+
+    /// Read `response`, record it under `key` and return an equivalent response
+    async fn record_response(
+        &self,
+        key: Key,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = String::from_utf8_lossy(&response.bytes().await?).into_owned();
+        let interaction = Interaction {
+            key,
+            status,
+            headers,
+            body,
+        };
+        let response = interaction.to_response()?;
+        self.lock().interactions.push(interaction);
+
+        // Snapshot under the write lock so concurrent writes land in order
+        let _write = self.write.lock().await;
+        let file = CassetteFile {
+            interactions: self.lock().interactions.clone(),
+        };
+        tokio::fs::write(&self.path, serde_json::to_vec_pretty(&file)?)
+            .await
+            .map_err(|e| {
+                Error::from(e).context(format!("writing cassette {}", self.path.display()))
+            })?;
+        Ok(response)
+    }
+}
+
+impl Interaction {
+    fn to_response(&self) -> Result<reqwest::Response> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(self.body.clone())
+            .map_err(|e| Error::config(format!("invalid recorded response: {}", e)))?;
+        Ok(response.into())
+    }
+}
+
+/// 64-bit FNV-1a, stable across platforms and Rust versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Client, Config};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let admin_url = format!("{}/admin-rpc", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/api/v1/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "vnet1",
+                "slug": "test-vnet",
+                "display_name": "Test VNet",
+                "fork_config": {"network_id": 1},
+                "virtual_network_config": {"chain_config": {"chain_id": 1}},
+                "rpcs": [{"name": "Admin RPC", "url": admin_url}]
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/account/acc/project/proj/items"))
+            .and(body_json(serde_json::json!({"n": 1})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"n": 1})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/account/acc/project/proj/items"))
+            .and(body_json(serde_json::json!({"n": 2})))
+            .respond_with(ResponseTemplate::new(404).set_body_string("not found"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/admin-rpc"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({"jsonrpc": "2.0", "id": 1, "result": "0x10"}),
+                ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("cassette.json");
+
+        // Record with a versioned base URL, as against the real API
+        let cassette = Arc::new(Cassette::record(&file));
+        let client = Client::new(
+            Config::new("secret-key", "acc", "proj")
+                .with_base_url(format!("{}/api/v1", server.uri()))
+                .with_vcr(Arc::clone(&cassette)),
+        )
+        .unwrap();
+        let vnet: serde_json::Value = client.get("/vnets/vnet1").await.unwrap();
+        let one: serde_json::Value = client
+            .post("/items", &serde_json::json!({"n": 1}))
+            .await
+            .unwrap();
+        let err = client
+            .post::<serde_json::Value, _>("/items", &serde_json::json!({"n": 2}))
+            .await
+            .unwrap_err();
+        assert!(err.is_not_found());
+        let admin = client.vnets().admin_rpc("vnet1").await.unwrap();
+        let block: String = admin
+            .request("eth_blockNumber", serde_json::json!([]))
+            .await
+            .unwrap();
+        assert_eq!(block, "0x10");
+        assert_eq!(cassette.len(), 5);
+
+        let recorded = std::fs::read_to_string(&file).unwrap();
+        assert!(!recorded.contains("secret-key"));
+
+        drop(server);
+
+        // Replay against an unreachable, unversioned base URL: nothing may
+        // hit the network, including Admin RPC calls
+        let cassette = Arc::new(Cassette::replay(&file).unwrap());
+        assert_eq!(cassette.mode(), Mode::Replay);
+        let client = Client::new(
+            Config::new("other-key", "acc", "proj")
+                .with_base_url("http://127.0.0.1:1")
+                .with_vcr(cassette),
+        )
+        .unwrap();
+        let replayed: serde_json::Value = client.get("/vnets/vnet1").await.unwrap();
+        assert_eq!(replayed, vnet);
+        let replayed: serde_json::Value = client
+            .post("/items", &serde_json::json!({"n": 1}))
+            .await
+            .unwrap();
+        assert_eq!(replayed, one);
+        let err = client
+            .post::<serde_json::Value, _>("/items", &serde_json::json!({"n": 2}))
+            .await
+            .unwrap_err();
+        assert!(err.is_not_found());
+        let admin = client.vnets().admin_rpc("vnet1").await.unwrap();
+        let block: String = admin
+            .request("eth_blockNumber", serde_json::json!([]))
+            .await
+            .unwrap();
+        assert_eq!(block, "0x10");
+
+        // Unrecorded body
+        let err = client
+            .post::<serde_json::Value, _>("/items", &serde_json::json!({"n": 3}))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+    }
+
+    #[test]
+    fn test_mode_display_round_trip() {
+        for mode in [Mode::Record, Mode::Replay] {
+            assert_eq!(mode.to_string().parse::<Mode>().unwrap(), mode);
+        }
+        assert_eq!("REPLAY".parse::<Mode>().unwrap(), Mode::Replay);
+        assert!("rewind".parse::<Mode>().is_err());
+    }
+
+    #[test]
+    fn test_fnv1a_is_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
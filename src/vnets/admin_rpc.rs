@@ -23,22 +23,29 @@
 //! admin.revert(&snapshot_id).await?;
 //! ```
 
+use super::types::TraceResponse;
+#[cfg(feature = "abi")]
+use crate::abi::{AbiEvent, DecodedEvent};
 use crate::error::{Error, Result};
+use crate::simulation::{SimulationRequest, StateOverride};
+use crate::utils::{is_valid_address, parse_hex_u64};
+use crate::U256;
 use reqwest::Client as HttpClient;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// JSON-RPC request structure
 #[derive(Debug, Serialize)]
-struct JsonRpcRequest<P: Serialize> {
+struct JsonRpcRequest<'a, P: Serialize> {
     jsonrpc: &'static str,
-    method: &'static str,
+    method: &'a str,
     params: P,
     id: u64,
 }
 
-impl<P: Serialize> JsonRpcRequest<P> {
-    fn new(method: &'static str, params: P, id: u64) -> Self {
+impl<'a, P: Serialize> JsonRpcRequest<'a, P> {
+    fn new(method: &'a str, params: P, id: u64) -> Self {
         Self {
             jsonrpc: "2.0",
             method,
@@ -64,7 +71,7 @@ struct JsonRpcResponse<T> {
 struct JsonRpcError {
     code: i64,
     message: String,
-    #[allow(dead_code)]
+    #[serde(default)]
     data: Option<serde_json::Value>,
 }
 
@@ -75,6 +82,8 @@ pub struct AdminRpc {
     http: HttpClient,
     url: String,
     request_id: AtomicU64,
+    #[cfg(feature = "vcr")]
+    vcr: Option<std::sync::Arc<crate::vcr::Cassette>>,
 }
 
 impl AdminRpc {
@@ -90,23 +99,167 @@ impl AdminRpc {
             http,
             url: url.into(),
             request_id: AtomicU64::new(1),
+            #[cfg(feature = "vcr")]
+            vcr: None,
         })
     }
 
+    /// Record or replay every call with a cassette
+    ///
+    /// [`VNetsApi::admin_rpc`](super::VNetsApi::admin_rpc) attaches the
+    /// client's cassette automatically. See [`vcr`](crate::vcr).
+    #[cfg(feature = "vcr")]
+    #[must_use]
+    pub fn with_vcr(mut self, cassette: std::sync::Arc<crate::vcr::Cassette>) -> Self {
+        self.vcr = Some(cassette);
+        self
+    }
+
     /// Get the next request ID
     fn next_id(&self) -> u64 {
         self.request_id.fetch_add(1, Ordering::SeqCst)
     }
 
     /// Make a JSON-RPC call
-    async fn call<P: Serialize, R: DeserializeOwned>(
+    async fn rpc<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<R> {
+        self.send(method, params)
+            .await?
+            .result
+            .ok_or_else(|| Error::api(0, "No result in RPC response"))
+    }
+
+    /// Call an arbitrary JSON-RPC method, decoding the result into `R`
+    ///
+    /// Typed escape hatch for methods this client does not wrap yet. A
+    /// missing or `null` result is an error; a JSON-RPC `error` object is
+    /// returned as [`Error::JsonRpc`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let balance: String = admin.request("eth_getBalance", (address, "latest")).await?;
+    /// ```
+    pub async fn request<P: Serialize, R: DeserializeOwned>(
         &self,
-        method: &'static str,
+        method: &str,
         params: P,
     ) -> Result<R> {
+        self.rpc(method, params).await
+    }
+
+    /// Call an arbitrary JSON-RPC method, returning raw JSON
+    ///
+    /// Escape hatch for `tenderly_*` methods this client does not wrap yet.
+    /// Returns the `result` field as-is (`null` if absent); a JSON-RPC
+    /// `error` object is returned as [`Error::JsonRpc`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = admin
+    ///     .call_raw("tenderly_setNextBlockBaseFee", serde_json::json!(["0x3b9aca00"]))
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "dynamic-json")]
+    pub async fn call_raw(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let response: JsonRpcResponse<serde_json::Value> = self.send(method, params).await?;
+        Ok(response.result.unwrap_or(serde_json::Value::Null))
+    }
+
+    /// Send a JSON-RPC request and check for an error response
+    async fn send<P: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<JsonRpcResponse<R>> {
         let request = JsonRpcRequest::new(method, params, self.next_id());
+        let rpc_response: JsonRpcResponse<R> = self.post(&request).await?;
+
+        if let Some(error) = rpc_response.error {
+            return Err(Error::json_rpc(error.code, error.message, error.data));
+        }
+
+        Ok(rpc_response)
+    }
+
+    /// Call several JSON-RPC methods in a single HTTP request (JSON-RPC batch)
+    ///
+    /// Returns each call's `result` in request order. If any call returns an
+    /// error object, the first one is returned as [`Error::JsonRpc`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let results = admin
+    ///     .call_batch(&[
+    ///         ("eth_blockNumber", serde_json::json!([])),
+    ///         ("eth_chainId", serde_json::json!([])),
+    ///     ])
+    ///     .await?;
+    /// ```
+    pub async fn call_batch(
+        &self,
+        calls: &[(&str, serde_json::Value)],
+    ) -> Result<Vec<serde_json::Value>> {
+        let results = self
+            .batch::<_, Option<serde_json::Value>>(calls.iter().map(|(m, p)| (*m, p)))
+            .await?;
+        Ok(results
+            .into_iter()
+            .map(|r| r.unwrap_or(serde_json::Value::Null))
+            .collect())
+    }
+
+    /// Send a JSON-RPC batch and return results in request order
+    async fn batch<'a, P: Serialize, R: DeserializeOwned>(
+        &self,
+        calls: impl IntoIterator<Item = (&'a str, P)>,
+    ) -> Result<Vec<R>> {
+        self.batch_each(calls).await?.into_iter().collect()
+    }
+
+    /// Send a JSON-RPC batch, keeping each call's result or error separately
+    async fn batch_each<'a, P: Serialize, R: DeserializeOwned>(
+        &self,
+        calls: impl IntoIterator<Item = (&'a str, P)>,
+    ) -> Result<Vec<Result<R>>> {
+        let requests: Vec<_> = calls
+            .into_iter()
+            .map(|(method, params)| JsonRpcRequest::new(method, params, self.next_id()))
+            .collect();
+        if requests.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        let response = self.http.post(&self.url).json(&request).send().await?;
+        let mut responses: Vec<JsonRpcResponse<R>> = self.post(&requests).await?;
+        // Servers may answer batch entries in any order
+        responses.sort_by_key(|r| r.id);
+
+        if responses.len() != requests.len()
+            || responses.iter().zip(&requests).any(|(r, q)| r.id != q.id)
+        {
+            return Err(Error::api(0, "Mismatched JSON-RPC batch response"));
+        }
+
+        Ok(responses
+            .into_iter()
+            .map(|response| match response.error {
+                Some(error) => Err(Error::json_rpc(error.code, error.message, error.data)),
+                None => response
+                    .result
+                    .ok_or_else(|| Error::api(0, "No result in RPC response")),
+            })
+            .collect())
+    }
+
+    /// POST a JSON body to the RPC URL and decode the response
+    async fn post<B: Serialize, T: DeserializeOwned>(&self, body: &B) -> Result<T> {
+        let request = self.http.post(&self.url).json(body).build()?;
+        let response = self.execute(request).await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -117,18 +270,26 @@ impl AdminRpc {
             return Err(Error::api(status.as_u16(), message));
         }
 
-        let rpc_response: JsonRpcResponse<R> = response.json().await?;
+        crate::client::decode(&response.bytes().await?)
+    }
 
-        if let Some(error) = rpc_response.error {
-            return Err(Error::api(
-                error.code as u16,
-                format!("RPC error: {}", error.message),
-            ));
+    /// Send a request, or replay/record it if a VCR cassette is attached
+    #[cfg(feature = "vcr")]
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        match &self.vcr {
+            Some(cassette) => {
+                cassette
+                    .send(request, "", |r| async { Ok(self.http.execute(r).await?) })
+                    .await
+            }
+            None => Ok(self.http.execute(request).await?),
         }
+    }
 
-        rpc_response
-            .result
-            .ok_or_else(|| Error::api(0, "No result in RPC response"))
+    /// Send a request
+    #[cfg(not(feature = "vcr"))]
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        Ok(self.http.execute(request).await?)
     }
 
     // =========================================================================
@@ -148,7 +309,7 @@ impl AdminRpc {
     /// Block hash of the newly generated block
     pub async fn increase_time(&self, seconds: u64) -> Result<String> {
         let hex_seconds = format!("0x{:x}", seconds);
-        self.call("evm_increaseTime", [hex_seconds]).await
+        self.rpc("evm_increaseTime", [hex_seconds]).await
     }
 
     /// Set the timestamp for the next block and create an empty block
@@ -162,8 +323,7 @@ impl AdminRpc {
     /// Transaction hash
     pub async fn set_next_block_timestamp(&self, timestamp: u64) -> Result<String> {
         let hex_timestamp = format!("0x{:x}", timestamp);
-        self.call("evm_setNextBlockTimestamp", [hex_timestamp])
-            .await
+        self.rpc("evm_setNextBlockTimestamp", [hex_timestamp]).await
     }
 
     /// Set the timestamp for the next block without creating an empty block
@@ -177,7 +337,7 @@ impl AdminRpc {
     /// Transaction hash
     pub async fn set_next_block_timestamp_no_mine(&self, timestamp: u64) -> Result<String> {
         let hex_timestamp = format!("0x{:x}", timestamp);
-        self.call("tenderly_setNextBlockTimestamp", [hex_timestamp])
+        self.rpc("tenderly_setNextBlockTimestamp", [hex_timestamp])
             .await
     }
 
@@ -192,7 +352,7 @@ impl AdminRpc {
     /// Block hash of the newly generated block
     pub async fn increase_blocks(&self, blocks: u64) -> Result<String> {
         let hex_blocks = format!("0x{:x}", blocks);
-        self.call("evm_increaseBlocks", [hex_blocks]).await
+        self.rpc("evm_increaseBlocks", [hex_blocks]).await
     }
 
     // =========================================================================
@@ -211,24 +371,31 @@ impl AdminRpc {
     /// Block hash of the state-changing transaction
     pub async fn set_balance(&self, address: &str, amount: &str) -> Result<String> {
         let hex_amount = to_hex_wei(amount);
-        self.call("tenderly_setBalance", (address, hex_amount))
-            .await
+        self.rpc("tenderly_setBalance", (address, hex_amount)).await
     }
 
     /// Set the ETH balance of multiple accounts
     ///
+    /// Sends one `tenderly_setBalance` per account in a single JSON-RPC
+    /// batch. Every address is validated before anything is sent.
+    ///
     /// # Arguments
     ///
-    /// * `addresses` - List of account addresses
-    /// * `amount` - The balance in wei (as hex string or decimal)
+    /// * `accounts` - List of account addresses
+    /// * `amount` - The balance in wei
     ///
     /// # Returns
     ///
-    /// Block hash of the state-changing transaction
-    pub async fn set_balances(&self, addresses: &[&str], amount: &str) -> Result<String> {
-        let hex_amount = to_hex_wei(amount);
-        self.call("tenderly_setBalance", (addresses, hex_amount))
-            .await
+    /// Transaction hashes, in the same order as `accounts`
+    pub async fn set_balances(&self, accounts: &[&str], amount: U256) -> Result<Vec<String>> {
+        validate_addresses(accounts)?;
+        let hex_amount = format!("{:#x}", amount);
+        self.batch(
+            accounts
+                .iter()
+                .map(|account| ("tenderly_setBalance", (*account, hex_amount.as_str()))),
+        )
+        .await
     }
 
     /// Add to the ETH balance of an account
@@ -243,8 +410,7 @@ impl AdminRpc {
     /// Block hash of the state-changing transaction
     pub async fn add_balance(&self, address: &str, amount: &str) -> Result<String> {
         let hex_amount = to_hex_wei(amount);
-        self.call("tenderly_addBalance", (address, hex_amount))
-            .await
+        self.rpc("tenderly_addBalance", (address, hex_amount)).await
     }
 
     /// Add to the ETH balance of multiple accounts
@@ -259,7 +425,7 @@ impl AdminRpc {
     /// Block hash of the state-changing transaction
     pub async fn add_balances(&self, addresses: &[&str], amount: &str) -> Result<String> {
         let hex_amount = to_hex_wei(amount);
-        self.call("tenderly_addBalance", (addresses, hex_amount))
+        self.rpc("tenderly_addBalance", (addresses, hex_amount))
             .await
     }
 
@@ -281,13 +447,45 @@ impl AdminRpc {
         amount: &str,
     ) -> Result<String> {
         let hex_amount = to_hex_wei(amount);
-        self.call(
+        self.rpc(
             "tenderly_setErc20Balance",
             (token_address, wallet, hex_amount),
         )
         .await
     }
 
+    /// Set the ERC20 token balance for multiple wallets
+    ///
+    /// Sends one `tenderly_setErc20Balance` per wallet in a single JSON-RPC
+    /// batch. Every address is validated before anything is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The ERC20 token contract address
+    /// * `wallets` - List of wallet addresses
+    /// * `amount` - The token balance (in smallest unit)
+    ///
+    /// # Returns
+    ///
+    /// Transaction hashes, in the same order as `wallets`
+    pub async fn set_erc20_balances(
+        &self,
+        token_address: &str,
+        wallets: &[&str],
+        amount: U256,
+    ) -> Result<Vec<String>> {
+        validate_addresses(&[token_address])?;
+        validate_addresses(wallets)?;
+        let hex_amount = format!("{:#x}", amount);
+        self.batch(wallets.iter().map(|wallet| {
+            (
+                "tenderly_setErc20Balance",
+                (token_address, *wallet, hex_amount.as_str()),
+            )
+        }))
+        .await
+    }
+
     /// Set the maximum possible ERC20 token balance for a wallet
     ///
     /// Tops up the wallet with the maximum possible token balance.
@@ -301,10 +499,147 @@ impl AdminRpc {
     ///
     /// Transaction hash
     pub async fn set_max_erc20_balance(&self, token_address: &str, wallet: &str) -> Result<String> {
-        self.call("tenderly_setMaxErc20Balance", (token_address, wallet))
+        self.rpc("tenderly_setMaxErc20Balance", (token_address, wallet))
             .await
     }
 
+    /// Set the ERC20 allowance granted by `owner` to `spender`
+    ///
+    /// Tenderly has no dedicated allowance method, so this writes the
+    /// `allowance[owner][spender]` storage slot directly, assuming the
+    /// OpenZeppelin layout where the allowances mapping lives at slot
+    /// [`OZ_ERC20_ALLOWANCES_SLOT`]. Tokens with a different layout (e.g.,
+    /// proxies with namespaced storage, or USDC/DAI-style custom layouts)
+    /// need [`set_erc20_allowance_at`](Self::set_erc20_allowance_at) with
+    /// the correct mapping slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The ERC20 token contract address
+    /// * `owner` - The account granting the allowance
+    /// * `spender` - The account allowed to spend
+    /// * `amount` - The allowance (hex or decimal, in smallest unit)
+    ///
+    /// # Returns
+    ///
+    /// Transaction hash
+    #[cfg(feature = "abi")]
+    pub async fn set_erc20_allowance(
+        &self,
+        token_address: &str,
+        owner: &str,
+        spender: &str,
+        amount: &str,
+    ) -> Result<String> {
+        self.set_erc20_allowance_at(
+            token_address,
+            owner,
+            spender,
+            amount,
+            OZ_ERC20_ALLOWANCES_SLOT,
+        )
+        .await
+    }
+
+    /// Set the ERC20 allowance using a custom allowances mapping slot
+    ///
+    /// See [`set_erc20_allowance`](Self::set_erc20_allowance).
+    #[cfg(feature = "abi")]
+    pub async fn set_erc20_allowance_at(
+        &self,
+        token_address: &str,
+        owner: &str,
+        spender: &str,
+        amount: &str,
+        mapping_slot: u64,
+    ) -> Result<String> {
+        let slot = erc20_allowance_slot(owner, spender, mapping_slot)?;
+        self.set_storage_at(token_address, &slot, amount).await
+    }
+
+    /// Read an ERC20 balance by calling `balanceOf(holder)` at the latest block
+    ///
+    /// Useful for checking [`set_erc20_balance`](Self::set_erc20_balance).
+    #[cfg(feature = "abi")]
+    pub async fn erc20_balance_of(&self, token_address: &str, holder: &str) -> Result<U256> {
+        use crate::abi::{decode_output, encode_call, Token};
+
+        validate_addresses(&[token_address, holder])?;
+        let data = encode_call("balanceOf(address)", &[Token::address(holder)])?;
+        let output = self
+            .call(CallRequest::new(token_address).data(data), BlockId::Latest)
+            .await?;
+        match decode_output("uint256", &output)?.as_slice() {
+            [Token::Uint(balance)] => Ok(*balance),
+            _ => Err(Error::invalid_param(format!(
+                "Unexpected balanceOf output: {}",
+                output
+            ))),
+        }
+    }
+
+    /// Read an ERC20 token's `name()`, `symbol()` and `decimals()`
+    ///
+    /// The three `eth_call`s are sent as one batch. A method that reverts or
+    /// returns nothing leaves its field `None`; `bytes32` names and symbols
+    /// (e.g., MKR) are decoded as strings.
+    #[cfg(feature = "abi")]
+    pub async fn erc20_metadata(&self, token_address: &str) -> Result<Erc20Metadata> {
+        use crate::abi::{decode_output, Token};
+
+        validate_addresses(&[token_address])?;
+        let call = |selector: &str| {
+            (
+                "eth_call",
+                (
+                    CallRequest::new(token_address).data(selector),
+                    BlockId::Latest,
+                ),
+            )
+        };
+        let results = self
+            .batch_each::<_, String>([
+                call("0x06fdde03"), // name()
+                call("0x95d89b41"), // symbol()
+                call("0x313ce567"), // decimals()
+            ])
+            .await?;
+        let mut outputs = Vec::with_capacity(3);
+        for result in results {
+            outputs.push(match result {
+                // Missing methods revert, which comes back as a JSON-RPC error
+                Err(Error::JsonRpc { .. }) => None,
+                Err(e) => return Err(e),
+                Ok(output) => Some(output).filter(|o| o.len() > 2),
+            });
+        }
+        let decode = |index: usize, types: &str| {
+            let output = outputs.get(index)?.as_deref()?;
+            decode_output(types, output).ok()?.pop()
+        };
+        let text = |index: usize| match decode(index, "string") {
+            Some(Token::String(s)) => Some(s),
+            _ => match decode(index, "bytes32") {
+                Some(Token::FixedBytes(bytes)) => {
+                    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                    String::from_utf8(bytes[..end].to_vec()).ok()
+                }
+                _ => None,
+            },
+        };
+        let name = text(0);
+        let symbol = text(1);
+        let decimals = match decode(2, "uint256") {
+            Some(Token::Uint(value)) => value.as_u64().and_then(|v| u8::try_from(v).ok()),
+            _ => None,
+        };
+        Ok(Erc20Metadata {
+            name,
+            symbol,
+            decimals,
+        })
+    }
+
     // =========================================================================
     // Storage Manipulation
     // =========================================================================
@@ -323,7 +658,7 @@ impl AdminRpc {
     pub async fn set_storage_at(&self, address: &str, slot: &str, value: &str) -> Result<String> {
         let padded_slot = to_hex_32_bytes(slot);
         let padded_value = to_hex_32_bytes(value);
-        self.call(
+        self.rpc(
             "tenderly_setStorageAt",
             (address, padded_slot, padded_value),
         )
@@ -341,7 +676,7 @@ impl AdminRpc {
     ///
     /// Transaction hash
     pub async fn set_code(&self, address: &str, bytecode: &str) -> Result<String> {
-        self.call("tenderly_setCode", (address, bytecode)).await
+        self.rpc("tenderly_setCode", (address, bytecode)).await
     }
 
     // =========================================================================
@@ -356,7 +691,7 @@ impl AdminRpc {
     ///
     /// Snapshot ID (32-byte hash)
     pub async fn snapshot(&self) -> Result<String> {
-        self.call::<[(); 0], String>("evm_snapshot", []).await
+        self.rpc::<[(); 0], String>("evm_snapshot", []).await
     }
 
     /// Revert the state to a previous snapshot
@@ -369,7 +704,7 @@ impl AdminRpc {
     ///
     /// `true` if successful
     pub async fn revert(&self, snapshot_id: &str) -> Result<bool> {
-        self.call("evm_revert", [snapshot_id]).await
+        self.rpc("evm_revert", [snapshot_id]).await
     }
 
     // =========================================================================
@@ -382,7 +717,14 @@ impl AdminRpc {
     ///
     /// Block information including block number, hash, and transaction hash
     pub async fn get_latest(&self) -> Result<LatestBlock> {
-        self.call::<[(); 0], LatestBlock>("evm_getLatest", []).await
+        self.rpc::<[(); 0], LatestBlock>("evm_getLatest", []).await
+    }
+
+    /// Get the current block number (`eth_blockNumber`)
+    pub async fn block_number(&self) -> Result<u64> {
+        let block: String = self.rpc::<[(); 0], String>("eth_blockNumber", []).await?;
+        parse_hex_u64(&block)
+            .ok_or_else(|| Error::invalid_param(format!("Invalid hex number: {}", block)))
     }
 
     /// Send an unsigned transaction
@@ -395,7 +737,102 @@ impl AdminRpc {
     ///
     /// Transaction hash
     pub async fn send_transaction(&self, tx: &SendTransactionParams) -> Result<String> {
-        self.call("eth_sendTransaction", [tx]).await
+        self.rpc("eth_sendTransaction", [tx]).await
+    }
+
+    /// Allow unsigned transactions from `address` (`hardhat_impersonateAccount`)
+    ///
+    /// Tenderly VNets already accept `eth_sendTransaction` from any address,
+    /// so this is only needed on nodes that require explicit impersonation
+    /// (e.g., Hardhat or Anvil behind the same client).
+    pub async fn impersonate_account(&self, address: &str) -> Result<()> {
+        self.send::<_, serde::de::IgnoredAny>("hardhat_impersonateAccount", [address])
+            .await
+            .map(drop)
+    }
+
+    /// Stop impersonating `address` (`hardhat_stopImpersonatingAccount`)
+    pub async fn stop_impersonating_account(&self, address: &str) -> Result<()> {
+        self.send::<_, serde::de::IgnoredAny>("hardhat_stopImpersonatingAccount", [address])
+            .await
+            .map(drop)
+    }
+
+    /// Execute a call without creating a transaction (`eth_call`)
+    ///
+    /// Returns the raw return data as hex. State overrides set on the
+    /// request are sent as the third `eth_call` parameter.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let call = CallRequest::new(token).data("0x18160ddd"); // totalSupply()
+    /// let output = admin.call(call, BlockId::Latest).await?;
+    /// ```
+    pub async fn call(&self, tx: CallRequest, block: BlockId) -> Result<String> {
+        if tx.state_overrides.is_empty() {
+            self.rpc("eth_call", (&tx, &block)).await
+        } else {
+            let overrides = tx.eth_call_overrides();
+            self.rpc("eth_call", (&tx, &block, overrides)).await
+        }
+    }
+
+    /// Get logs matching a filter (`eth_getLogs`)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let filter = LogFilter::new()
+    ///     .from_block(100)
+    ///     .address(token)
+    ///     .topic(0, TRANSFER_TOPIC);
+    /// let logs = admin.get_logs(filter).await?;
+    /// ```
+    pub async fn get_logs(&self, filter: LogFilter) -> Result<Vec<ReceiptLog>> {
+        filter.validate()?;
+        self.rpc("eth_getLogs", [filter]).await
+    }
+
+    /// Get a transaction by hash (`eth_getTransactionByHash`)
+    ///
+    /// Returns `None` if the node does not know the transaction.
+    pub async fn get_transaction_by_hash(&self, tx_hash: &str) -> Result<Option<RpcTransaction>> {
+        Ok(self
+            .send("eth_getTransactionByHash", [tx_hash])
+            .await?
+            .result)
+    }
+
+    /// Trace a mined transaction
+    ///
+    /// Calls `tenderly_traceTransaction` and returns the decoded call trace
+    /// and logs.
+    pub async fn trace_transaction(&self, tx_hash: &str) -> Result<TraceResponse> {
+        self.rpc("tenderly_traceTransaction", [tx_hash]).await
+    }
+
+    /// Trace a call against a block without mining it
+    ///
+    /// Calls `debug_traceCall`. The result shape depends on the tracer
+    /// selected in `opts`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let call = SendTransactionParams::new(from).to(token).data(calldata);
+    /// let trace = admin
+    ///     .trace_call(&call, "latest", &TraceCallOptions::call_tracer())
+    ///     .await?;
+    /// ```
+    pub async fn trace_call(
+        &self,
+        call: &SendTransactionParams,
+        block: &str,
+        opts: &TraceCallOptions,
+    ) -> Result<NodeTrace> {
+        let value: serde_json::Value = self.rpc("debug_traceCall", (call, block, opts)).await?;
+        NodeTrace::from_value(opts, value)
     }
 
     /// Create an access list for a transaction
@@ -415,7 +852,7 @@ impl AdminRpc {
         tx: &SendTransactionParams,
         block: &str,
     ) -> Result<AccessListResult> {
-        self.call("eth_createAccessList", (tx, block)).await
+        self.rpc("eth_createAccessList", (tx, block)).await
     }
 }
 
@@ -497,105 +934,910 @@ impl SendTransactionParams {
     }
 }
 
-/// Result from creating an access list
-#[derive(Debug, Clone, Deserialize)]
-pub struct AccessListResult {
-    /// The access list
-    #[serde(rename = "accessList")]
-    pub access_list: Vec<AccessListEntry>,
+/// Block selector for state-reading RPC calls
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BlockId {
+    /// Latest mined block
+    #[default]
+    Latest,
+    /// Pending state
+    Pending,
+    /// Genesis block
+    Earliest,
+    /// Latest safe block
+    Safe,
+    /// Latest finalized block
+    Finalized,
+    /// Block by number
+    Number(u64),
+    /// Block by hash
+    Hash(String),
+}
 
-    /// Estimated gas used
-    #[serde(rename = "gasUsed")]
-    pub gas_used: String,
+impl From<u64> for BlockId {
+    fn from(number: u64) -> Self {
+        Self::Number(number)
+    }
 }
 
-/// Entry in an access list
-#[derive(Debug, Clone, Deserialize)]
-pub struct AccessListEntry {
-    /// Address being accessed
-    pub address: String,
+impl std::fmt::Display for BlockId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Latest => f.write_str("latest"),
+            Self::Pending => f.write_str("pending"),
+            Self::Earliest => f.write_str("earliest"),
+            Self::Safe => f.write_str("safe"),
+            Self::Finalized => f.write_str("finalized"),
+            Self::Number(n) => write!(f, "{n:#x}"),
+            Self::Hash(hash) => f.write_str(hash),
+        }
+    }
+}
 
-    /// Storage keys being accessed
-    #[serde(rename = "storageKeys")]
-    pub storage_keys: Vec<String>,
+impl std::str::FromStr for BlockId {
+    type Err = String;
+
+    /// Parse a tag, a decimal or `0x` block number, or a 32-byte block hash
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "latest" => Ok(Self::Latest),
+            "pending" => Ok(Self::Pending),
+            "earliest" => Ok(Self::Earliest),
+            "safe" => Ok(Self::Safe),
+            "finalized" => Ok(Self::Finalized),
+            _ if crate::utils::is_valid_tx_hash(s) => Ok(Self::Hash(s.to_string())),
+            _ => match s.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => s.parse(),
+            }
+            .map(Self::Number)
+            .map_err(|_| {
+                format!(
+                    "Invalid block: {}. Expected: latest, pending, earliest, safe, finalized, a block number, or a block hash",
+                    s
+                )
+            }),
+        }
+    }
 }
 
-/// Latest block/transaction info returned by `evm_getLatest`
-#[derive(Debug, Clone, Deserialize)]
-pub struct LatestBlock {
-    /// Block number (hex)
-    #[serde(rename = "blockNumber")]
-    pub block_number: Option<String>,
+impl Serialize for BlockId {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Latest => serializer.serialize_str("latest"),
+            Self::Pending => serializer.serialize_str("pending"),
+            Self::Earliest => serializer.serialize_str("earliest"),
+            Self::Safe => serializer.serialize_str("safe"),
+            Self::Finalized => serializer.serialize_str("finalized"),
+            Self::Number(n) => serializer.serialize_str(&format!("{n:#x}")),
+            Self::Hash(hash) => {
+                use serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("blockHash", hash)?;
+                map.end()
+            }
+        }
+    }
+}
 
-    /// Block hash
-    #[serde(rename = "blockHash")]
-    pub block_hash: Option<String>,
+/// Parameters for [`AdminRpc::call`]
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CallRequest {
+    /// Sender address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
 
-    /// Transaction hash (if applicable)
-    #[serde(rename = "transactionHash")]
-    pub transaction_hash: Option<String>,
+    /// Contract address
+    pub to: String,
 
-    /// Additional fields captured as raw JSON
-    #[serde(flatten)]
-    pub extra: std::collections::HashMap<String, serde_json::Value>,
-}
+    /// Calldata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
 
-// =========================================================================
-// Helper functions
-// =========================================================================
+    /// Value in wei (hex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Gas limit (hex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas: Option<String>,
 
-/// Parse a hex string to u64
-#[allow(dead_code)]
-fn parse_hex_u64(s: &str) -> Result<u64> {
-    let s = s.strip_prefix("0x").unwrap_or(s);
-    u64::from_str_radix(s, 16)
-        .map_err(|e| Error::invalid_param(format!("Invalid hex number: {}", e)))
+    /// Per-account state overrides, keyed by address
+    #[serde(skip)]
+    pub state_overrides: HashMap<String, StateOverride>,
 }
 
-/// Convert a decimal or hex string to hex wei format
-fn to_hex_wei(amount: &str) -> String {
-    // If already hex, return as-is
-    if amount.starts_with("0x") {
-        return amount.to_string();
-    }
-    // Otherwise parse as decimal and convert to hex
-    if let Ok(n) = amount.parse::<u128>() {
-        format!("0x{:x}", n)
-    } else {
-        // Return as-is if we can't parse (let the RPC handle the error)
-        amount.to_string()
+impl CallRequest {
+    /// Create a call to the given contract
+    pub fn new(to: impl Into<String>) -> Self {
+        Self {
+            to: to.into(),
+            ..Default::default()
+        }
     }
-}
 
-/// Convert a decimal or hex string to 32-byte hex format
-fn to_hex_32_bytes(amount: &str) -> String {
-    // If already hex with 0x prefix, ensure it's 32 bytes (64 chars)
-    if let Some(hex_part) = amount.strip_prefix("0x") {
-        return format!("0x{:0>64}", hex_part);
+    /// Set the sender address
+    #[must_use]
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
     }
-    // Parse as decimal and convert to 32-byte hex
-    if let Ok(n) = amount.parse::<u128>() {
-        format!("0x{:064x}", n)
-    } else {
-        // Return as-is if we can't parse
-        amount.to_string()
+
+    /// Set the calldata
+    #[must_use]
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.data = Some(data.into());
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Set the value in wei (accepts hex or decimal, auto-converts to hex)
+    #[must_use]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(to_hex_wei(&value.into()));
+        self
+    }
 
-    // =========================================================================
-    // Helper function tests
-    // =========================================================================
+    /// Set the gas limit
+    #[must_use]
+    pub fn gas(mut self, gas: u64) -> Self {
+        self.gas = Some(format!("{gas:#x}"));
+        self
+    }
 
-    #[test]
-    fn test_to_hex_wei() {
-        assert_eq!(to_hex_wei("0x1"), "0x1");
-        assert_eq!(to_hex_wei("1000000000000000000"), "0xde0b6b3a7640000");
-        assert_eq!(to_hex_wei("100"), "0x64");
-        assert_eq!(to_hex_wei("0"), "0x0");
+    /// Override an account's state for this call
+    #[must_use]
+    pub fn override_account(mut self, address: impl Into<String>, state: StateOverride) -> Self {
+        self.state_overrides.insert(address.into(), state);
+        self
+    }
+
+    /// Convert overrides to the `eth_call` state override set
+    fn eth_call_overrides(&self) -> serde_json::Map<String, serde_json::Value> {
+        self.state_overrides
+            .iter()
+            .map(|(address, state)| {
+                let mut account = serde_json::Map::new();
+                if let Some(balance) = &state.balance {
+                    account.insert("balance".into(), to_hex_wei(balance).into());
+                }
+                if let Some(nonce) = state.nonce {
+                    account.insert("nonce".into(), format!("{nonce:#x}").into());
+                }
+                if let Some(code) = &state.code {
+                    account.insert("code".into(), code.clone().into());
+                }
+                if let Some(storage) = &state.storage {
+                    account.insert("stateDiff".into(), serde_json::json!(storage));
+                }
+                (address.clone(), account.into())
+            })
+            .collect()
+    }
+}
+
+/// Filter for [`AdminRpc::get_logs`]
+///
+/// Each topic position holds a list of alternatives that are OR-ed
+/// together; an empty position matches any topic. A filter selects either a
+/// block range or a single block by hash (EIP-234), not both.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// First block to include
+    pub from_block: Option<BlockId>,
+
+    /// Last block to include
+    pub to_block: Option<BlockId>,
+
+    /// Only include logs from the block with this hash
+    pub block_hash: Option<String>,
+
+    /// Emitting contract addresses (any of)
+    pub addresses: Vec<String>,
+
+    /// Topic alternatives per position
+    pub topics: Vec<Vec<String>>,
+}
+
+impl LogFilter {
+    /// Create an empty filter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the first block to include
+    #[must_use]
+    pub fn from_block(mut self, block: impl Into<BlockId>) -> Self {
+        self.from_block = Some(block.into());
+        self
+    }
+
+    /// Set the last block to include
+    #[must_use]
+    pub fn to_block(mut self, block: impl Into<BlockId>) -> Self {
+        self.to_block = Some(block.into());
+        self
+    }
+
+    /// Only include logs from the block with this hash
+    ///
+    /// Clears any block range, since the two are mutually exclusive.
+    #[must_use]
+    pub fn block_hash(mut self, hash: impl Into<String>) -> Self {
+        self.block_hash = Some(hash.into());
+        self.from_block = None;
+        self.to_block = None;
+        self
+    }
+
+    /// Check that the filter can be sent to `eth_getLogs`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParam`] if the range uses [`BlockId::Hash`]
+    /// (use [`block_hash`](Self::block_hash) instead), or if a block hash is
+    /// combined with a range.
+    pub fn validate(&self) -> Result<()> {
+        let range = [&self.from_block, &self.to_block];
+        if range.iter().any(|b| matches!(b, Some(BlockId::Hash(_)))) {
+            return Err(Error::invalid_param(
+                "log filter ranges take block numbers or tags; use block_hash for a hash",
+            ));
+        }
+        if self.block_hash.is_some() && range.iter().any(|b| b.is_some()) {
+            return Err(Error::invalid_param(
+                "log filter block_hash cannot be combined with from_block/to_block",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Match logs emitted by this address (may be called repeatedly)
+    #[must_use]
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.addresses.push(address.into());
+        self
+    }
+
+    /// Add an alternative for the topic at `position`
+    ///
+    /// Calling this repeatedly for the same position matches any of the
+    /// given topics.
+    #[must_use]
+    pub fn topic(mut self, position: usize, topic: impl Into<String>) -> Self {
+        if self.topics.len() <= position {
+            self.topics.resize(position + 1, Vec::new());
+        }
+        self.topics[position].push(topic.into());
+        self
+    }
+}
+
+impl Serialize for LogFilter {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        // A single alternative is sent bare; several as an OR array
+        fn one_or_many(values: &[String]) -> serde_json::Value {
+            match values {
+                [] => serde_json::Value::Null,
+                [one] => one.as_str().into(),
+                many => many.into(),
+            }
+        }
+
+        self.validate().map_err(serde::ser::Error::custom)?;
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(hash) = &self.block_hash {
+            map.serialize_entry("blockHash", hash)?;
+        }
+        if let Some(from) = &self.from_block {
+            map.serialize_entry("fromBlock", from)?;
+        }
+        if let Some(to) = &self.to_block {
+            map.serialize_entry("toBlock", to)?;
+        }
+        if !self.addresses.is_empty() {
+            map.serialize_entry("address", &one_or_many(&self.addresses))?;
+        }
+        if !self.topics.is_empty() {
+            let topics: Vec<_> = self.topics.iter().map(|t| one_or_many(t)).collect();
+            map.serialize_entry("topics", &topics)?;
+        }
+        map.end()
+    }
+}
+
+/// Log entry as returned by `eth_getLogs` and transaction receipts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptLog {
+    /// Emitting contract address
+    pub address: String,
+
+    /// Indexed topics, event signature hash first
+    #[serde(default)]
+    pub topics: Vec<String>,
+
+    /// Non-indexed data (hex)
+    #[serde(default)]
+    pub data: String,
+
+    /// Block number (hex)
+    #[serde(default)]
+    pub block_number: Option<String>,
+
+    /// Block hash
+    #[serde(default)]
+    pub block_hash: Option<String>,
+
+    /// Transaction hash
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+
+    /// Transaction index in the block (hex)
+    #[serde(default)]
+    pub transaction_index: Option<String>,
+
+    /// Log index in the block (hex)
+    #[serde(default)]
+    pub log_index: Option<String>,
+
+    /// Whether the log was removed by a reorg
+    #[serde(default)]
+    pub removed: bool,
+}
+
+impl ReceiptLog {
+    /// Parse block_number from hex string to u64
+    #[must_use]
+    pub fn block_number_as_u64(&self) -> Option<u64> {
+        self.block_number.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Parse log_index from hex string to u64
+    #[must_use]
+    pub fn log_index_as_u64(&self) -> Option<u64> {
+        self.log_index.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Decode this log with the first event whose signature topic matches
+    ///
+    /// Anonymous events have no signature topic and are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let transfer = AbiEvent::parse("Transfer(address indexed from, address indexed to, uint256 value)")?;
+    /// if let Some(event) = log.decode_with(&[transfer]) {
+    ///     println!("{:?}", event.get("value"));
+    /// }
+    /// ```
+    #[cfg(feature = "abi")]
+    #[must_use]
+    pub fn decode_with(&self, events: &[AbiEvent]) -> Option<DecodedEvent> {
+        let topic0 = self.topics.first()?;
+        events
+            .iter()
+            .filter(|event| !event.anonymous && event.topic0().eq_ignore_ascii_case(topic0))
+            .find_map(|event| event.decode_log(&self.topics, &self.data).ok())
+    }
+}
+
+/// Result from creating an access list
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccessListResult {
+    /// The access list
+    #[serde(rename = "accessList")]
+    pub access_list: Vec<AccessListEntry>,
+
+    /// Estimated gas used
+    #[serde(rename = "gasUsed")]
+    pub gas_used: String,
+}
+
+/// Transaction as returned by `eth_getTransactionByHash`
+///
+/// Quantities are hex strings; block fields are `None` while pending.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransaction {
+    /// Transaction hash
+    pub hash: String,
+
+    /// Sender address
+    pub from: String,
+
+    /// Recipient address (`None` for contract creation)
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// Calldata
+    #[serde(default)]
+    pub input: String,
+
+    /// Value in wei (hex)
+    #[serde(default)]
+    pub value: Option<String>,
+
+    /// Gas limit (hex)
+    #[serde(default)]
+    pub gas: Option<String>,
+
+    /// Gas price in wei (hex); the effective price for EIP-1559 transactions
+    #[serde(default)]
+    pub gas_price: Option<String>,
+
+    /// Max fee per gas (hex, EIP-1559)
+    #[serde(default)]
+    pub max_fee_per_gas: Option<String>,
+
+    /// Max priority fee per gas (hex, EIP-1559)
+    #[serde(default)]
+    pub max_priority_fee_per_gas: Option<String>,
+
+    /// Sender nonce (hex)
+    #[serde(default)]
+    pub nonce: Option<String>,
+
+    /// Block number (hex)
+    #[serde(default)]
+    pub block_number: Option<String>,
+
+    /// Block hash
+    #[serde(default)]
+    pub block_hash: Option<String>,
+
+    /// Transaction index in the block (hex)
+    #[serde(default)]
+    pub transaction_index: Option<String>,
+
+    /// Transaction type (hex, e.g. `"0x2"`)
+    #[serde(default, rename = "type")]
+    pub transaction_type: Option<String>,
+
+    /// Access list (EIP-2930 and later)
+    #[serde(default)]
+    pub access_list: Option<Vec<AccessListEntry>>,
+}
+
+impl RpcTransaction {
+    /// Parse gas from hex string to u64
+    #[must_use]
+    pub fn gas_as_u64(&self) -> Option<u64> {
+        self.gas.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Parse nonce from hex string to u64
+    #[must_use]
+    pub fn nonce_as_u64(&self) -> Option<u64> {
+        self.nonce.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Parse block_number from hex string to u64
+    #[must_use]
+    pub fn block_number_as_u64(&self) -> Option<u64> {
+        self.block_number.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Parse transaction_index from hex string to u64
+    #[must_use]
+    pub fn transaction_index_as_u64(&self) -> Option<u64> {
+        self.transaction_index.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Parse the transaction type from hex
+    #[must_use]
+    pub fn transaction_type_as_u8(&self) -> Option<u8> {
+        self.transaction_type
+            .as_deref()
+            .and_then(parse_hex_u64)
+            .and_then(|t| u8::try_from(t).ok())
+    }
+
+    /// Build a simulation request replaying this transaction on `network_id`
+    ///
+    /// Pins the block number and transaction index, so the simulation runs
+    /// against the state the original transaction saw. Legacy transactions
+    /// keep their gas price; EIP-1559 transactions keep their fee caps.
+    #[must_use]
+    pub fn to_simulation(&self, network_id: impl Into<String>) -> SimulationRequest {
+        let mut request = SimulationRequest::new(
+            self.from.clone(),
+            self.to.clone().unwrap_or_default(),
+            self.input.clone(),
+        )
+        .network_id(network_id);
+        request.value = self.value.clone();
+        request.gas = self.gas_as_u64();
+        request.transaction_type = self.transaction_type_as_u8();
+        if self.max_fee_per_gas.is_some() {
+            request.max_fee_per_gas = self.max_fee_per_gas.clone();
+            request.max_priority_fee_per_gas = self.max_priority_fee_per_gas.clone();
+        } else {
+            request.gas_price = self.gas_price.clone();
+        }
+        request.nonce = self.nonce_as_u64();
+        request.block_number = self.block_number_as_u64();
+        request.transaction_index = self.transaction_index_as_u64();
+        request.access_list = self.access_list.as_ref().map(|list| {
+            list.iter()
+                .map(|entry| crate::simulation::AccessListEntry {
+                    address: entry.address.clone(),
+                    storage_keys: entry.storage_keys.clone(),
+                })
+                .collect()
+        });
+        request
+    }
+}
+
+/// Entry in an access list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessListEntry {
+    /// Address being accessed
+    pub address: String,
+
+    /// Storage keys being accessed
+    #[serde(rename = "storageKeys")]
+    pub storage_keys: Vec<String>,
+}
+
+/// ERC20 token metadata returned by [`AdminRpc::erc20_metadata`]
+///
+/// Each field is `None` if the token does not implement the method.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Erc20Metadata {
+    /// Token name
+    pub name: Option<String>,
+
+    /// Token symbol
+    pub symbol: Option<String>,
+
+    /// Number of decimals
+    pub decimals: Option<u8>,
+}
+
+/// Latest block/transaction info returned by `evm_getLatest`
+#[derive(Debug, Clone, Deserialize)]
+pub struct LatestBlock {
+    /// Block number (hex)
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<String>,
+
+    /// Block hash
+    #[serde(rename = "blockHash")]
+    pub block_hash: Option<String>,
+
+    /// Transaction hash (if applicable)
+    #[serde(rename = "transactionHash")]
+    pub transaction_hash: Option<String>,
+
+    /// Additional fields captured as raw JSON
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Built-in node tracer for `debug_traceCall`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum Tracer {
+    /// Nested call frames with gas, input, and output
+    #[default]
+    CallTracer,
+    /// Account state touched by the call
+    PrestateTracer,
+}
+
+impl Tracer {
+    /// Get the tracer name as sent to the node
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::CallTracer => "callTracer",
+            Self::PrestateTracer => "prestateTracer",
+        }
+    }
+}
+
+impl std::fmt::Display for Tracer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Tracer {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "calltracer" | "call" => Ok(Self::CallTracer),
+            "prestatetracer" | "prestate" => Ok(Self::PrestateTracer),
+            _ => Err(format!(
+                "Invalid tracer: {}. Expected: callTracer or prestateTracer",
+                s
+            )),
+        }
+    }
+}
+
+/// Options for [`AdminRpc::trace_call`]
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCallOptions {
+    /// Tracer to run
+    pub tracer: Tracer,
+
+    /// Tracer-specific configuration
+    #[serde(skip_serializing_if = "TracerConfig::is_empty")]
+    pub tracer_config: TracerConfig,
+}
+
+/// Tracer-specific configuration
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracerConfig {
+    /// Only trace the top-level call (`callTracer`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_top_call: Option<bool>,
+
+    /// Return pre and post state instead of prestate only (`prestateTracer`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_mode: Option<bool>,
+}
+
+impl TracerConfig {
+    fn is_empty(&self) -> bool {
+        self.only_top_call.is_none() && self.diff_mode.is_none()
+    }
+}
+
+impl TraceCallOptions {
+    /// Options for the call tracer
+    #[must_use]
+    pub fn call_tracer() -> Self {
+        Self::default()
+    }
+
+    /// Options for the prestate tracer
+    #[must_use]
+    pub fn prestate_tracer() -> Self {
+        Self {
+            tracer: Tracer::PrestateTracer,
+            ..Default::default()
+        }
+    }
+
+    /// Only trace the top-level call
+    #[must_use]
+    pub fn only_top_call(mut self, only_top_call: bool) -> Self {
+        self.tracer_config.only_top_call = Some(only_top_call);
+        self
+    }
+
+    /// Return pre and post state for the prestate tracer
+    #[must_use]
+    pub fn diff_mode(mut self, diff_mode: bool) -> Self {
+        self.tracer_config.diff_mode = Some(diff_mode);
+        self
+    }
+}
+
+/// Trace returned by [`AdminRpc::trace_call`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum NodeTrace {
+    /// Root call frame from the call tracer
+    Call(CallFrame),
+    /// Touched accounts from the prestate tracer
+    Prestate(HashMap<String, PrestateAccount>),
+    /// Account state before and after the call (prestate tracer, diff mode)
+    PrestateDiff {
+        /// State before the call
+        pre: HashMap<String, PrestateAccount>,
+        /// State after the call (changed fields only)
+        post: HashMap<String, PrestateAccount>,
+    },
+}
+
+impl NodeTrace {
+    fn from_value(opts: &TraceCallOptions, value: serde_json::Value) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Diff {
+            #[serde(default)]
+            pre: HashMap<String, PrestateAccount>,
+            #[serde(default)]
+            post: HashMap<String, PrestateAccount>,
+        }
+
+        let trace = match opts.tracer {
+            Tracer::CallTracer => serde_json::from_value(value.clone()).map(Self::Call),
+            Tracer::PrestateTracer if opts.tracer_config.diff_mode == Some(true) => {
+                serde_json::from_value(value.clone()).map(|d: Diff| Self::PrestateDiff {
+                    pre: d.pre,
+                    post: d.post,
+                })
+            }
+            Tracer::PrestateTracer => serde_json::from_value(value.clone()).map(Self::Prestate),
+        };
+        trace.map_err(|e| Error::decode(e, value.to_string().as_bytes()))
+    }
+
+    /// Get the root call frame, if this is a call trace
+    #[must_use]
+    pub fn as_call(&self) -> Option<&CallFrame> {
+        match self {
+            Self::Call(frame) => Some(frame),
+            _ => None,
+        }
+    }
+}
+
+/// Call frame from the `callTracer`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    /// Call type (e.g., "CALL", "DELEGATECALL")
+    #[serde(rename = "type")]
+    pub call_type: String,
+
+    /// Caller address
+    pub from: String,
+
+    /// Callee address
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// Value transferred (hex)
+    #[serde(default)]
+    pub value: Option<String>,
+
+    /// Gas available (hex)
+    #[serde(default)]
+    pub gas: Option<String>,
+
+    /// Gas used (hex)
+    #[serde(default)]
+    pub gas_used: Option<String>,
+
+    /// Calldata
+    #[serde(default)]
+    pub input: Option<String>,
+
+    /// Return data
+    #[serde(default)]
+    pub output: Option<String>,
+
+    /// Error, if the call failed
+    #[serde(default)]
+    pub error: Option<String>,
+
+    /// Decoded revert reason, if any
+    #[serde(default)]
+    pub revert_reason: Option<String>,
+
+    /// Subcalls
+    #[serde(default)]
+    pub calls: Vec<CallFrame>,
+}
+
+/// Account state from the `prestateTracer`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PrestateAccount {
+    /// Balance (hex)
+    #[serde(default)]
+    pub balance: Option<String>,
+
+    /// Nonce
+    #[serde(default)]
+    pub nonce: Option<u64>,
+
+    /// Contract code
+    #[serde(default)]
+    pub code: Option<String>,
+
+    /// Storage slots
+    #[serde(default)]
+    pub storage: HashMap<String, String>,
+}
+
+// =========================================================================
+// Helper functions
+// =========================================================================
+
+/// Storage slot of the allowances mapping in OpenZeppelin's ERC20
+pub const OZ_ERC20_ALLOWANCES_SLOT: u64 = 1;
+
+/// Compute the storage slot of `allowance[owner][spender]`
+///
+/// For `mapping(address => mapping(address => uint256))` declared at
+/// `mapping_slot`, the slot is
+/// `keccak256(spender . keccak256(owner . mapping_slot))` with each
+/// component left-padded to 32 bytes.
+#[cfg(feature = "abi")]
+pub fn erc20_allowance_slot(owner: &str, spender: &str, mapping_slot: u64) -> Result<String> {
+    use crate::utils::{encode_hex, keccak256};
+
+    let word = |address: &str| -> Result<[u8; 32]> {
+        let bytes = crate::utils::decode_hex(address)
+            .filter(|b| b.len() == 20)
+            .ok_or_else(|| Error::invalid_param(format!("Invalid address: {}", address)))?;
+        let mut out = [0u8; 32];
+        out[12..].copy_from_slice(&bytes);
+        Ok(out)
+    };
+
+    let mut slot = [0u8; 32];
+    slot[24..].copy_from_slice(&mapping_slot.to_be_bytes());
+
+    let inner = keccak256(&[word(owner)?, slot].concat());
+    Ok(encode_hex(&keccak256(&[word(spender)?, inner].concat())))
+}
+
+/// Check that every address is well-formed
+fn validate_addresses(addresses: &[&str]) -> Result<()> {
+    match addresses.iter().find(|a| !is_valid_address(a)) {
+        Some(invalid) => Err(Error::invalid_param(format!(
+            "Invalid address: {}",
+            invalid
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Convert a decimal or hex string to hex wei format
+fn to_hex_wei(amount: &str) -> String {
+    // If already hex, return as-is
+    if amount.starts_with("0x") {
+        return amount.to_string();
+    }
+    // Otherwise parse as decimal and convert to hex
+    if let Ok(n) = amount.parse::<u128>() {
+        format!("0x{:x}", n)
+    } else {
+        // Return as-is if we can't parse (let the RPC handle the error)
+        amount.to_string()
+    }
+}
+
+/// Convert a decimal or hex string to 32-byte hex format
+fn to_hex_32_bytes(amount: &str) -> String {
+    // If already hex with 0x prefix, ensure it's 32 bytes (64 chars)
+    if let Some(hex_part) = amount.strip_prefix("0x") {
+        return format!("0x{:0>64}", hex_part);
+    }
+    // Parse as decimal and convert to 32-byte hex
+    if let Ok(n) = amount.parse::<u128>() {
+        format!("0x{:064x}", n)
+    } else {
+        // Return as-is if we can't parse
+        amount.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // =========================================================================
+    // Helper function tests
+    // =========================================================================
+
+    #[test]
+    fn test_to_hex_wei() {
+        assert_eq!(to_hex_wei("0x1"), "0x1");
+        assert_eq!(to_hex_wei("1000000000000000000"), "0xde0b6b3a7640000");
+        assert_eq!(to_hex_wei("100"), "0x64");
+        assert_eq!(to_hex_wei("0"), "0x0");
     }
 
     #[test]
@@ -647,22 +1889,6 @@ mod tests {
         assert_eq!(to_hex_32_bytes(full), full);
     }
 
-    #[test]
-    fn test_parse_hex_u64() {
-        assert_eq!(parse_hex_u64("0x64").unwrap(), 100);
-        assert_eq!(parse_hex_u64("64").unwrap(), 100);
-        assert_eq!(parse_hex_u64("0x0").unwrap(), 0);
-        assert_eq!(parse_hex_u64("0xffffffffffffffff").unwrap(), u64::MAX);
-    }
-
-    #[test]
-    fn test_parse_hex_u64_error() {
-        assert!(parse_hex_u64("0xzz").is_err());
-        assert!(parse_hex_u64("not_hex").is_err());
-        // Overflow
-        assert!(parse_hex_u64("0x10000000000000000").is_err());
-    }
-
     // =========================================================================
     // Builder pattern tests
     // =========================================================================
@@ -879,6 +2105,46 @@ mod tests {
     // Integration-style tests (testing method param construction)
     // =========================================================================
 
+    #[tokio::test]
+    async fn test_impersonation_request_shapes() {
+        use wiremock::matchers::{body_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "hardhat_impersonateAccount",
+                "params": ["0x1111111111111111111111111111111111111111"],
+                "id": 1
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0", "id": 1, "result": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "hardhat_stopImpersonatingAccount",
+                "params": ["0x1111111111111111111111111111111111111111"],
+                "id": 2
+            })))
+            // Anvil answers with a null result
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0", "id": 2, "result": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let address = "0x1111111111111111111111111111111111111111";
+        admin.impersonate_account(address).await.unwrap();
+        admin.stop_impersonating_account(address).await.unwrap();
+    }
+
     #[test]
     fn test_increase_time_param_format() {
         // Verify the hex conversion for time increase
@@ -904,6 +2170,62 @@ mod tests {
         assert_eq!(hex, "0x3b9aca00");
     }
 
+    #[cfg(feature = "abi")]
+    #[test]
+    fn test_erc20_allowance_slot() {
+        let owner = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        let spender = "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D";
+
+        assert_eq!(
+            erc20_allowance_slot(owner, spender, OZ_ERC20_ALLOWANCES_SLOT).unwrap(),
+            "0x565149e9568aba8ab273e2fb1afe5156a82e345ca373a8066215d21249d99dd5"
+        );
+        assert_eq!(
+            erc20_allowance_slot(owner, spender, 2).unwrap(),
+            "0x53618deada7911e04548462b8f79d50ff88cefa5b5b5c8a8f973c33f214df76f"
+        );
+        assert!(erc20_allowance_slot("0x1234", spender, 1).is_err());
+    }
+
+    #[cfg(feature = "abi")]
+    #[tokio::test]
+    async fn test_set_erc20_allowance_params() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "tenderly_setStorageAt",
+                "params": [
+                    "0xtoken",
+                    "0x565149e9568aba8ab273e2fb1afe5156a82e345ca373a8066215d21249d99dd5",
+                    "0x00000000000000000000000000000000000000000000000000000000000f4240"
+                ]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": "0xhash",
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let hash = admin
+            .set_erc20_allowance(
+                "0xtoken",
+                "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+                "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D",
+                "1000000",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(hash, "0xhash");
+    }
+
     #[test]
     fn test_storage_slot_format() {
         // Slot 0
@@ -920,4 +2242,854 @@ mod tests {
             "0x0000000000000000000000000000000000000000000000000000000000000001"
         );
     }
+
+    // =========================================================================
+    // Raw call tests
+    // =========================================================================
+
+    #[cfg(feature = "dynamic-json")]
+    #[tokio::test]
+    async fn test_call_raw_envelope() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "tenderly_someNewMethod",
+                "params": ["0x1", {"flag": true}],
+                "id": 1
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {"ok": true},
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let result = admin
+            .call_raw(
+                "tenderly_someNewMethod",
+                serde_json::json!(["0x1", {"flag": true}]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, serde_json::json!({"ok": true}));
+    }
+
+    #[cfg(feature = "dynamic-json")]
+    #[tokio::test]
+    async fn test_call_raw_error_and_null_result() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "tenderly_fails"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "error": {"code": -32601, "message": "method not found"},
+                "id": 1
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "tenderly_returnsNull"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": null,
+                "id": 2
+            })))
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+
+        let err = admin
+            .call_raw("tenderly_fails", serde_json::json!([]))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some(-32601));
+        assert!(err.to_string().contains("method not found"));
+
+        let result = admin
+            .call_raw("tenderly_returnsNull", serde_json::json!([]))
+            .await
+            .unwrap();
+        assert!(result.is_null());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_params_error() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "error": {
+                    "code": -32602,
+                    "message": "invalid params",
+                    "data": "expected hex string"
+                },
+                "id": 1
+            })))
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let err = admin.set_balance("0x1234", "oops").await.unwrap_err();
+
+        match err {
+            Error::JsonRpc {
+                code,
+                ref message,
+                ref data,
+            } => {
+                assert_eq!(code, -32602);
+                assert_eq!(message, "invalid params");
+                assert_eq!(data.as_ref().unwrap(), "expected hex string");
+            }
+            other => panic!("expected JSON-RPC error, got {:?}", other),
+        }
+        assert_eq!(err.code(), Some(-32602));
+    }
+
+    #[tokio::test]
+    async fn test_set_balances_batch() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+        // Echo one result per request, in reverse order, tagged with the address
+        struct EchoBatch;
+        impl Respond for EchoBatch {
+            fn respond(&self, request: &Request) -> ResponseTemplate {
+                let calls: Vec<serde_json::Value> = request.body_json().unwrap();
+                let results: Vec<_> = calls
+                    .iter()
+                    .rev()
+                    .map(|call| {
+                        assert_eq!(call["method"], "tenderly_setBalance");
+                        assert_eq!(call["params"][1], "0xde0b6b3a7640000");
+                        serde_json::json!({
+                            "jsonrpc": "2.0",
+                            "id": call["id"],
+                            "result": format!("0xhash-{}", call["params"][0].as_str().unwrap()),
+                        })
+                    })
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(results)
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(EchoBatch)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let accounts = [
+            "0x1111111111111111111111111111111111111111",
+            "0x2222222222222222222222222222222222222222",
+            "0x3333333333333333333333333333333333333333",
+        ];
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let hashes = admin
+            .set_balances(&accounts, U256::from(1_000_000_000_000_000_000u128))
+            .await
+            .unwrap();
+
+        assert_eq!(hashes.len(), 3);
+        for (hash, account) in hashes.iter().zip(accounts) {
+            assert_eq!(hash, &format!("0xhash-{}", account));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_balances_rejects_invalid_address() {
+        // No server: validation must fail before any request is sent
+        let admin = AdminRpc::new("http://127.0.0.1:9").unwrap();
+        let err = admin
+            .set_erc20_balances(
+                "0x1111111111111111111111111111111111111111",
+                &["0x2222222222222222222222222222222222222222", "0xnope"],
+                U256::from(1u8),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::InvalidParam(_)));
+        assert!(err.to_string().contains("0xnope"));
+    }
+
+    // =========================================================================
+    // Tracing tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_trace_transaction_envelope() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "tenderly_traceTransaction",
+                "params": ["0xabc"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {"status": true, "gasUsed": "0x5208", "trace": [], "logs": []},
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let trace = admin.trace_transaction("0xabc").await.unwrap();
+        assert_eq!(trace.status, Some(true));
+        assert_eq!(trace.gas_used_as_u64(), Some(21000));
+    }
+
+    #[test]
+    fn test_tracer_display_round_trip() {
+        for tracer in [Tracer::CallTracer, Tracer::PrestateTracer] {
+            assert_eq!(tracer.to_string().parse::<Tracer>().unwrap(), tracer);
+        }
+        assert_eq!(
+            "prestate_tracer".parse::<Tracer>().unwrap(),
+            Tracer::PrestateTracer
+        );
+        assert_eq!("call".parse::<Tracer>().unwrap(), Tracer::CallTracer);
+        assert!("4byteTracer".parse::<Tracer>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_trace_call_call_tracer_envelope() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "debug_traceCall",
+                "params": [
+                    {"from": "0x1111111111111111111111111111111111111111", "data": "0x1234"},
+                    "latest",
+                    {"tracer": "callTracer", "tracerConfig": {"onlyTopCall": true}}
+                ]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "type": "CALL",
+                    "from": "0x1111111111111111111111111111111111111111",
+                    "to": "0x2222222222222222222222222222222222222222",
+                    "gasUsed": "0x5208",
+                    "output": "0x",
+                    "calls": [{"type": "STATICCALL", "from": "0x2222222222222222222222222222222222222222"}]
+                },
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let call =
+            SendTransactionParams::new("0x1111111111111111111111111111111111111111").data("0x1234");
+        let opts = TraceCallOptions::call_tracer().only_top_call(true);
+        let trace = admin.trace_call(&call, "latest", &opts).await.unwrap();
+
+        let frame = trace.as_call().unwrap();
+        assert_eq!(frame.call_type, "CALL");
+        assert_eq!(frame.gas_used.as_deref(), Some("0x5208"));
+        assert_eq!(frame.calls[0].call_type, "STATICCALL");
+    }
+
+    #[tokio::test]
+    async fn test_trace_call_prestate_tracer_envelope() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "debug_traceCall",
+                "params": [
+                    {"from": "0x1111111111111111111111111111111111111111"},
+                    "0x10",
+                    {"tracer": "prestateTracer"}
+                ]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "0x1111111111111111111111111111111111111111": {"balance": "0x10", "nonce": 3}
+                },
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let call = SendTransactionParams::new("0x1111111111111111111111111111111111111111");
+        let trace = admin
+            .trace_call(&call, "0x10", &TraceCallOptions::prestate_tracer())
+            .await
+            .unwrap();
+
+        let NodeTrace::Prestate(accounts) = trace else {
+            panic!("expected prestate trace");
+        };
+        let account = &accounts["0x1111111111111111111111111111111111111111"];
+        assert_eq!(account.balance.as_deref(), Some("0x10"));
+        assert_eq!(account.nonce, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_trace_call_prestate_diff_envelope() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "debug_traceCall",
+                "params": [
+                    {"from": "0x1111111111111111111111111111111111111111"},
+                    "latest",
+                    {"tracer": "prestateTracer", "tracerConfig": {"diffMode": true}}
+                ]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "pre": {"0x1111111111111111111111111111111111111111": {"nonce": 3}},
+                    "post": {"0x1111111111111111111111111111111111111111": {"nonce": 4}}
+                },
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let call = SendTransactionParams::new("0x1111111111111111111111111111111111111111");
+        let opts = TraceCallOptions::prestate_tracer().diff_mode(true);
+        let trace = admin.trace_call(&call, "latest", &opts).await.unwrap();
+
+        let NodeTrace::PrestateDiff { pre, post } = trace else {
+            panic!("expected prestate diff");
+        };
+        let addr = "0x1111111111111111111111111111111111111111";
+        assert_eq!(pre[addr].nonce, Some(3));
+        assert_eq!(post[addr].nonce, Some(4));
+    }
+
+    // =========================================================================
+    // eth_call tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_call_params_without_overrides() {
+        use wiremock::matchers::{body_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "eth_call",
+                "params": [
+                    {
+                        "to": "0x2222222222222222222222222222222222222222",
+                        "data": "0x18160ddd",
+                        "gas": "0x5208"
+                    },
+                    "0x10"
+                ],
+                "id": 1
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": "0x01",
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let call = CallRequest::new("0x2222222222222222222222222222222222222222")
+            .data("0x18160ddd")
+            .gas(21000);
+        let output = admin.call(call, BlockId::from(16)).await.unwrap();
+        assert_eq!(output, "0x01");
+    }
+
+    #[tokio::test]
+    async fn test_call_params_with_overrides() {
+        use wiremock::matchers::{body_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "eth_call",
+                "params": [
+                    {
+                        "from": "0x1111111111111111111111111111111111111111",
+                        "to": "0x2222222222222222222222222222222222222222",
+                        "value": "0x3e8"
+                    },
+                    "latest",
+                    {
+                        "0x1111111111111111111111111111111111111111": {
+                            "balance": "0xde0b6b3a7640000",
+                            "nonce": "0x7",
+                            "stateDiff": {"0x0": "0x1"}
+                        }
+                    }
+                ],
+                "id": 1
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": "0x",
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let call = CallRequest::new("0x2222222222222222222222222222222222222222")
+            .from("0x1111111111111111111111111111111111111111")
+            .value("1000")
+            .override_account(
+                "0x1111111111111111111111111111111111111111",
+                StateOverride::new()
+                    .balance("1000000000000000000")
+                    .nonce(7)
+                    .storage("0x0", "0x1"),
+            );
+        assert_eq!(admin.call(call, BlockId::Latest).await.unwrap(), "0x");
+    }
+
+    #[test]
+    fn test_block_id_serialization() {
+        assert_eq!(serde_json::to_value(BlockId::Safe).unwrap(), "safe");
+        assert_eq!(serde_json::to_value(BlockId::from(255)).unwrap(), "0xff");
+        assert_eq!(
+            serde_json::to_value(BlockId::Hash("0xabc".into())).unwrap(),
+            serde_json::json!({"blockHash": "0xabc"})
+        );
+    }
+
+    #[test]
+    fn test_block_id_display_round_trip() {
+        let hash = "0x88e96d4537bea4d9c05d12549907b32561d3bf31f45aae734cdc119f13406cb6";
+        for block in [
+            BlockId::Latest,
+            BlockId::Pending,
+            BlockId::Earliest,
+            BlockId::Safe,
+            BlockId::Finalized,
+            BlockId::Number(0),
+            BlockId::Number(u64::MAX),
+            BlockId::Hash(hash.to_string()),
+        ] {
+            assert_eq!(block.to_string().parse::<BlockId>().unwrap(), block);
+        }
+        assert_eq!(BlockId::Number(255).to_string(), "0xff");
+        assert_eq!("255".parse::<BlockId>().unwrap(), BlockId::Number(255));
+        assert_eq!("Latest".parse::<BlockId>().unwrap(), BlockId::Latest);
+        assert!("0x".parse::<BlockId>().is_err());
+        assert!("head".parse::<BlockId>().is_err());
+    }
+
+    // =========================================================================
+    // eth_getLogs tests
+    // =========================================================================
+
+    #[test]
+    fn test_log_filter_block_range_serialization() {
+        let filter = LogFilter::new()
+            .from_block(16)
+            .to_block(BlockId::Latest)
+            .address("0x2222222222222222222222222222222222222222");
+
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({
+                "fromBlock": "0x10",
+                "toBlock": "latest",
+                "address": "0x2222222222222222222222222222222222222222"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(LogFilter::new()).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn test_log_filter_block_hash() {
+        let hash = "0x88e96d4537bea4d9c05d12549907b32561d3bf31f45aae734cdc119f13406cb6";
+        let filter = LogFilter::new()
+            .from_block(16)
+            .block_hash(hash)
+            .address("0x2222222222222222222222222222222222222222");
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({
+                "blockHash": hash,
+                "address": "0x2222222222222222222222222222222222222222"
+            })
+        );
+
+        // A hash inside the range would nest `{"blockHash": ...}` in fromBlock
+        let filter = LogFilter::new().from_block(BlockId::Hash(hash.to_string()));
+        assert!(matches!(filter.validate(), Err(Error::InvalidParam(_))));
+        assert!(serde_json::to_value(&filter).is_err());
+
+        let mut filter = LogFilter::new().block_hash(hash);
+        filter.to_block = Some(BlockId::Latest);
+        assert!(filter.validate().is_err());
+    }
+
+    #[test]
+    fn test_log_filter_topic_or_and_addresses() {
+        let filter = LogFilter::new()
+            .address("0x1111111111111111111111111111111111111111")
+            .address("0x2222222222222222222222222222222222222222")
+            .topic(0, "0xaa")
+            .topic(2, "0xbb")
+            .topic(2, "0xcc");
+
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({
+                "address": [
+                    "0x1111111111111111111111111111111111111111",
+                    "0x2222222222222222222222222222222222222222"
+                ],
+                "topics": ["0xaa", null, ["0xbb", "0xcc"]]
+            })
+        );
+    }
+
+    /// Serve `eth_call` batches, answering each selector from `outputs`
+    /// (missing selectors revert)
+    #[cfg(feature = "abi")]
+    async fn erc20_server(outputs: Vec<(&'static str, String)>) -> wiremock::MockServer {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(move |request: &Request| {
+                let calls: Vec<serde_json::Value> = serde_json::from_slice(&request.body).unwrap();
+                let responses: Vec<_> = calls
+                    .iter()
+                    .map(|call| {
+                        let data = call["params"][0]["data"].as_str().unwrap();
+                        match outputs.iter().find(|(selector, _)| *selector == data) {
+                            Some((_, output)) => serde_json::json!({
+                                "jsonrpc": "2.0", "id": call["id"], "result": output
+                            }),
+                            None => serde_json::json!({
+                                "jsonrpc": "2.0",
+                                "id": call["id"],
+                                "error": {"code": -32000, "message": "execution reverted"}
+                            }),
+                        }
+                    })
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(responses)
+            })
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[cfg(feature = "abi")]
+    #[tokio::test]
+    async fn test_erc20_metadata() {
+        use crate::abi::{encode, ParamType, Token};
+        use crate::utils::encode_hex;
+
+        let string = |s: &str| {
+            encode_hex(&encode(&[ParamType::String], &[Token::String(s.into())]).unwrap())
+        };
+        let server = erc20_server(vec![
+            ("0x06fdde03", string("USD Coin")),
+            ("0x95d89b41", string("USDC")),
+            (
+                "0x313ce567",
+                "0x0000000000000000000000000000000000000000000000000000000000000006".into(),
+            ),
+        ])
+        .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let metadata = admin
+            .erc20_metadata("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+            .await
+            .unwrap();
+        assert_eq!(
+            metadata,
+            Erc20Metadata {
+                name: Some("USD Coin".into()),
+                symbol: Some("USDC".into()),
+                decimals: Some(6),
+            }
+        );
+    }
+
+    #[cfg(feature = "abi")]
+    #[tokio::test]
+    async fn test_erc20_metadata_missing_name() {
+        // No name(), and a bytes32 symbol like MKR
+        let server = erc20_server(vec![
+            (
+                "0x95d89b41",
+                "0x4d4b520000000000000000000000000000000000000000000000000000000000".into(),
+            ),
+            (
+                "0x313ce567",
+                "0x0000000000000000000000000000000000000000000000000000000000000012".into(),
+            ),
+        ])
+        .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let metadata = admin
+            .erc20_metadata("0x9f8f72aa9304c8b593d555f12ef6589cc3a579a2")
+            .await
+            .unwrap();
+        assert_eq!(metadata.name, None);
+        assert_eq!(metadata.symbol.as_deref(), Some("MKR"));
+        assert_eq!(metadata.decimals, Some(18));
+    }
+
+    #[tokio::test]
+    async fn test_get_logs_envelope() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "eth_getLogs",
+                "params": [{"fromBlock": "0x1", "topics": [["0xaa", "0xbb"]]}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": [{
+                    "address": "0x2222222222222222222222222222222222222222",
+                    "topics": ["0xaa"],
+                    "data": "0x",
+                    "blockNumber": "0x1",
+                    "logIndex": "0x3",
+                    "removed": false
+                }],
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let filter = LogFilter::new()
+            .from_block(1)
+            .topic(0, "0xaa")
+            .topic(0, "0xbb");
+        let logs = admin.get_logs(filter).await.unwrap();
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].block_number_as_u64(), Some(1));
+        assert_eq!(logs[0].log_index_as_u64(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_simulation_request_from_onchain_tx() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "eth_getTransactionByHash",
+                "params": ["0xabc"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "hash": "0xabc",
+                    "from": "0x1111111111111111111111111111111111111111",
+                    "to": "0x2222222222222222222222222222222222222222",
+                    "input": "0xa9059cbb",
+                    "value": "0xde0b6b3a7640000",
+                    "gas": "0x5208",
+                    "gasPrice": "0x3b9aca00",
+                    "maxFeePerGas": "0x77359400",
+                    "maxPriorityFeePerGas": "0x3b9aca00",
+                    "nonce": "0x7",
+                    "blockNumber": "0x121eac0",
+                    "blockHash": "0xdef",
+                    "transactionIndex": "0x3",
+                    "type": "0x2",
+                    "accessList": []
+                },
+                "id": 1
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"params": ["0xmissing"]}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": null,
+                "id": 1
+            })))
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let request = SimulationRequest::from_onchain_tx(&admin, "1", "0xabc")
+            .await
+            .unwrap();
+
+        assert_eq!(request.network_id, "1");
+        assert_eq!(request.from, "0x1111111111111111111111111111111111111111");
+        assert_eq!(request.to, "0x2222222222222222222222222222222222222222");
+        assert_eq!(request.input, "0xa9059cbb");
+        assert_eq!(request.value.as_deref(), Some("0xde0b6b3a7640000"));
+        assert_eq!(request.gas, Some(21_000));
+        assert_eq!(request.max_fee_per_gas.as_deref(), Some("0x77359400"));
+        assert_eq!(
+            request.max_priority_fee_per_gas.as_deref(),
+            Some("0x3b9aca00")
+        );
+        assert_eq!(request.gas_price, None);
+        assert_eq!(request.transaction_type, Some(2));
+        assert_eq!(request.nonce, Some(7));
+        assert_eq!(request.block_number, Some(19_000_000));
+        assert_eq!(request.transaction_index, Some(3));
+
+        let err = SimulationRequest::from_onchain_tx(&admin, "1", "0xmissing")
+            .await
+            .unwrap_err();
+        assert!(err.is_not_found());
+    }
+
+    #[test]
+    fn test_rpc_transaction_legacy_to_simulation() {
+        let tx: RpcTransaction = serde_json::from_value(serde_json::json!({
+            "hash": "0xabc",
+            "from": "0x1111111111111111111111111111111111111111",
+            "to": null,
+            "input": "0x6080",
+            "gasPrice": "0x3b9aca00",
+            "type": "0x0"
+        }))
+        .unwrap();
+
+        let request = tx.to_simulation("137");
+        assert!(request.to.is_empty());
+        assert_eq!(request.gas_price.as_deref(), Some("0x3b9aca00"));
+        assert_eq!(request.max_fee_per_gas, None);
+        assert_eq!(request.block_number, None);
+        assert_eq!(request.transaction_type, Some(0));
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn test_receipt_log_decode_transfer() {
+        use crate::abi::{AbiEvent, Token};
+
+        let log: ReceiptLog = serde_json::from_value(serde_json::json!({
+            "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "topics": [
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                "0x0000000000000000000000001111111111111111111111111111111111111111",
+                "0x0000000000000000000000002222222222222222222222222222222222222222"
+            ],
+            "data": "0x00000000000000000000000000000000000000000000000000000000000003e8",
+            "blockNumber": "0x10",
+            "logIndex": "0x0"
+        }))
+        .unwrap();
+
+        let approval = AbiEvent::parse(
+            "Approval(address indexed owner, address indexed spender, uint256 value)",
+        )
+        .unwrap();
+        let transfer =
+            AbiEvent::parse("Transfer(address indexed from, address indexed to, uint256 value)")
+                .unwrap();
+
+        assert!(log.decode_with(std::slice::from_ref(&approval)).is_none());
+        let event = log.decode_with(&[approval, transfer]).unwrap();
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(
+            event.get("from").and_then(Token::as_address),
+            Some("0x1111111111111111111111111111111111111111")
+        );
+        assert_eq!(
+            event.get("to").and_then(Token::as_address),
+            Some("0x2222222222222222222222222222222222222222")
+        );
+        assert_eq!(event.get("value"), Some(&Token::uint(1000u64)));
+    }
+
+    #[tokio::test]
+    async fn test_request_typed() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "eth_getBalance",
+                "params": ["0x1111111111111111111111111111111111111111", "latest"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": "0x10",
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let admin = AdminRpc::new(server.uri()).unwrap();
+        let balance: String = admin
+            .request(
+                "eth_getBalance",
+                ("0x1111111111111111111111111111111111111111", "latest"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(balance, "0x10");
+    }
 }
@@ -1,19 +1,33 @@
 //! Virtual TestNets API operations
 
-use super::admin_rpc::AdminRpc;
+use super::admin_rpc::{AdminRpc, SendTransactionParams};
 use super::types::*;
-use crate::client::{encode_path_segment, Client};
-use crate::error::Result;
+use crate::client::{encode_path_segment, ApiVersion, Client};
+use crate::error::{Error, Result};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::ops::ControlFlow;
 
 /// Virtual TestNets API client
 pub struct VNetsApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> VNetsApi<'a> {
+    /// Delay between status checks in [`create_and_wait`](Self::create_and_wait)
+    pub const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+    /// Most pages of 100 VNets read when searching by slug
+    pub const MAX_SCAN_PAGES: u32 = 100;
+
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new VNets API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// Create a new Virtual TestNet
@@ -28,8 +42,95 @@ impl<'a> VNetsApi<'a> {
     /// let vnet = client.vnets().create(&request).await?;
     /// println!("Created VNet: {}", vnet.id);
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AlreadyExists`] if the slug is already taken. Use
+    /// [`create_or_get`](Self::create_or_get) to treat that case as success.
     pub async fn create(&self, request: &CreateVNetRequest) -> Result<VNet> {
-        self.client.post("/vnets", request).await
+        self.client
+            .post("/vnets", request)
+            .await
+            .map_err(Error::into_already_exists)
+    }
+
+    /// Create a Virtual TestNet and wait until it is running
+    ///
+    /// Polls [`get`](Self::get) every [`POLL_INTERVAL`](Self::POLL_INTERVAL)
+    /// while the VNet reports a status other than `running`. A VNet without
+    /// a status is treated as ready. Returns [`Error::Timeout`] with the last
+    /// seen status if `timeout` elapses first.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let request = CreateVNetRequest::new("ci-testnet", "CI TestNet", 1);
+    /// let vnet = client
+    ///     .vnets()
+    ///     .create_and_wait(&request, Duration::from_secs(60))
+    ///     .await?;
+    /// ```
+    pub async fn create_and_wait(
+        &self,
+        request: &CreateVNetRequest,
+        timeout: std::time::Duration,
+    ) -> Result<VNet> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut vnet = self.create(request).await?;
+        loop {
+            match &vnet.status {
+                None | Some(VNetStatus::Running) => return Ok(vnet),
+                Some(status) => {
+                    let now = tokio::time::Instant::now();
+                    if now >= deadline {
+                        return Err(Error::timeout(
+                            format!("VNet {} to start", vnet.id),
+                            Some(status.to_string()),
+                        ));
+                    }
+                    tokio::time::sleep(Self::POLL_INTERVAL.min(deadline - now)).await;
+                }
+            }
+            vnet = self.get(&vnet.id).await?;
+        }
+    }
+
+    /// Create a Virtual TestNet, or fetch the existing one if its slug is taken
+    ///
+    /// Makes repeated setup (e.g., in CI) idempotent.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let request = CreateVNetRequest::new("ci-testnet", "CI TestNet", 1);
+    /// let vnet = client.vnets().create_or_get(&request).await?;
+    /// ```
+    pub async fn create_or_get(&self, request: &CreateVNetRequest) -> Result<VNet> {
+        match self.create(request).await {
+            Err(e) if e.is_already_exists() => self
+                .get_by_slug(&request.slug)
+                .await?
+                .ok_or_else(|| Error::not_found(format!("VNet with slug {}", request.slug))),
+            result => result,
+        }
+    }
+
+    /// Find a Virtual TestNet by its exact slug
+    ///
+    /// Returns `None` if no VNet has that slug. The API matches slugs
+    /// partially, so this pages through the matches until it finds an exact one.
+    pub async fn get_by_slug(&self, slug: &str) -> Result<Option<VNet>> {
+        let mut found = None;
+        self.scan_slug_matches(slug, |vnet| {
+            if vnet.slug == slug {
+                found = Some(vnet);
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .await?;
+        Ok(found)
     }
 
     /// List Virtual TestNets
@@ -77,6 +178,81 @@ impl<'a> VNetsApi<'a> {
         self.client.delete_with_body("/vnets", &request).await
     }
 
+    /// Delete a Virtual TestNet by its exact slug
+    ///
+    /// Returns `false` if no VNet has that slug.
+    pub async fn delete_by_slug(&self, slug: &str) -> Result<bool> {
+        match self.get_by_slug(slug).await? {
+            Some(vnet) => {
+                self.delete(&vnet.id).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Delete every Virtual TestNet whose slug starts with `slug_prefix`
+    ///
+    /// Returns the number of VNets deleted. Useful for tearing down test fleets.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let deleted = client.vnets().delete_all_matching("ci-").await?;
+    /// ```
+    pub async fn delete_all_matching(&self, slug_prefix: &str) -> Result<usize> {
+        let mut ids = Vec::new();
+        self.scan_slug_matches(slug_prefix, |vnet| {
+            if vnet.slug.starts_with(slug_prefix) {
+                ids.push(vnet.id);
+            }
+            ControlFlow::Continue(())
+        })
+        .await?;
+
+        let count = ids.len();
+        if count > 0 {
+            self.delete_many(ids).await?;
+        }
+        Ok(count)
+    }
+
+    /// Page through VNets whose slug partially matches `slug`
+    ///
+    /// Calls `visit` once per distinct VNet until it breaks. Stops at a short
+    /// page, a page with no new VNets (a server ignoring `page`), or after
+    /// [`MAX_SCAN_PAGES`](Self::MAX_SCAN_PAGES).
+    async fn scan_slug_matches(
+        &self,
+        slug: &str,
+        mut visit: impl FnMut(VNet) -> ControlFlow<()>,
+    ) -> Result<()> {
+        const PER_PAGE: u32 = 100;
+
+        let mut seen = HashSet::new();
+        for page in 1..=Self::MAX_SCAN_PAGES {
+            let query = ListVNetsQuery::new()
+                .slug(slug)
+                .page(page)
+                .per_page(PER_PAGE);
+            let vnets = self.list(Some(query)).await?;
+            let last_page = vnets.len() < PER_PAGE as usize;
+            let mut any_new = false;
+            for vnet in vnets {
+                if seen.insert(vnet.id.clone()) {
+                    any_new = true;
+                    if visit(vnet).is_break() {
+                        return Ok(());
+                    }
+                }
+            }
+            if last_page || !any_new {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     /// Fork a Virtual TestNet
     ///
     /// Creates a new VNet based on the state of an existing one.
@@ -93,6 +269,77 @@ impl<'a> VNetsApi<'a> {
         self.client.post("/vnets/fork", request).await
     }
 
+    /// Stop a Virtual TestNet to save resources
+    ///
+    /// Returns the updated VNet with its new `status`. Plans without
+    /// stop/start support yield [`Error::Unsupported`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let vnet = client.vnets().stop("vnet-123").await?;
+    /// println!("Status: {:?}", vnet.status);
+    /// ```
+    pub async fn stop(&self, vnet_id: &str) -> Result<VNet> {
+        self.client
+            .post(
+                &format!("/vnets/{}/stop", encode_path_segment(vnet_id)),
+                &serde_json::json!({}),
+            )
+            .await
+            .map_err(Error::into_unsupported)
+    }
+
+    /// Start a stopped Virtual TestNet
+    ///
+    /// Returns the updated VNet with its new `status`. Plans without
+    /// stop/start support yield [`Error::Unsupported`].
+    pub async fn start(&self, vnet_id: &str) -> Result<VNet> {
+        self.client
+            .post(
+                &format!("/vnets/{}/start", encode_path_segment(vnet_id)),
+                &serde_json::json!({}),
+            )
+            .await
+            .map_err(Error::into_unsupported)
+    }
+
+    /// Get the current block number of a network that VNets can fork from
+    ///
+    /// Useful for pinning a new VNet to the parent network's head so that
+    /// the fork block is known up front. Sends `eth_blockNumber` to the URL
+    /// set with [`Config::with_node_rpc_url`](crate::Config::with_node_rpc_url),
+    /// or to Tenderly's public gateway for the network.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let block = client.vnets().latest_block(1).await?;
+    /// let request = CreateVNetRequest::new("pinned", "Pinned", 1).block_number(block);
+    /// let vnet = client.vnets().create(&request).await?;
+    /// ```
+    pub async fn latest_block(&self, network_id: u64) -> Result<u64> {
+        let network_id = network_id.to_string();
+        let url = match self.client.config().node_rpc_urls.get(&network_id) {
+            Some(url) => url.clone(),
+            None => {
+                let network = crate::networks::NetworksApi::new(&self.client)
+                    .get(&network_id)
+                    .await?
+                    .ok_or_else(|| Error::not_found(format!("Network {}", network_id)))?;
+                let slug = &network.network_slugs.node_rpc_slug;
+                if !network.node_supported() || slug.is_empty() {
+                    return Err(Error::unsupported(format!(
+                        "Node RPC for network {}",
+                        network_id
+                    )));
+                }
+                public_gateway_url(slug)
+            }
+        };
+        self.admin_rpc_for_url(&url)?.block_number().await
+    }
+
     /// List transactions on a Virtual TestNet
     ///
     /// Returns transactions as a raw array (API returns JSON array directly).
@@ -111,31 +358,94 @@ impl<'a> VNetsApi<'a> {
         query: Option<ListVNetTransactionsQuery>,
     ) -> Result<Vec<VNetTransaction>> {
         let path = format!("/vnets/{}/transactions", encode_path_segment(vnet_id));
-        match query {
-            Some(q) => self.client.get_with_query(&path, &q).await,
-            None => self.client.get(&path).await,
-        }
+        let response: ListVNetTransactionsResponse = match query {
+            Some(q) => self.client.get_with_query(&path, &q).await?,
+            None => self.client.get(&path).await?,
+        };
+        Ok(response.into())
     }
 
     /// Simulate a transaction on a Virtual TestNet
     ///
     /// Unlike the main Simulation API, this simulates against the VNet's state.
+    /// A [`SimulationRequest`](crate::simulation::SimulationRequest) can be
+    /// converted with `VNetSimulationRequest::from(&request)`.
+    ///
+    /// If the request has a `network_id`, the VNet is fetched first and an
+    /// [`Error::InvalidParam`] is returned when its chain ID differs, unless
+    /// `allow_chain_mismatch` is set.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let request = SimulationRequest::new(from, to, data);
+    /// let result = client.vnets().simulate("vnet-123", &(&request).into()).await?;
+    /// ```
     pub async fn simulate(
         &self,
         vnet_id: &str,
         request: &VNetSimulationRequest,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<VNetSimulationResponse> {
+        let request = request.normalized(self.client.config().strict_checksums)?;
+        if let Some(network_id) = request.network_id.as_deref() {
+            if !request.allow_chain_mismatch {
+                self.check_chain_id(vnet_id, network_id).await?;
+            }
+        }
         self.client
             .post(
                 &format!(
                     "/vnets/{}/transactions/simulate",
                     encode_path_segment(vnet_id)
                 ),
-                request,
+                &request,
             )
             .await
     }
 
+    /// Fail if the VNet's chain ID differs from `network_id`
+    async fn check_chain_id(&self, vnet_id: &str, network_id: &str) -> Result<()> {
+        let expected: u64 = network_id.parse().map_err(|_| {
+            Error::invalid_param(format!("network_id must be a chain ID, got {network_id}"))
+        })?;
+        let vnet = self.get(vnet_id).await?;
+        let chain_id = vnet
+            .virtual_network_config
+            .chain_id()
+            .unwrap_or(vnet.fork_config.network_id);
+        if chain_id != expected {
+            return Err(Error::invalid_param(format!(
+                "network_id {expected} does not match VNet {vnet_id} chain ID {chain_id}; \
+                 use allow_chain_mismatch(true) to simulate anyway"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Trace a transaction on a Virtual TestNet
+    ///
+    /// VNets expose tracing only over the Admin RPC, so this resolves the
+    /// VNet's admin URL and calls `tenderly_traceTransaction`. Reuse
+    /// [`admin_rpc`](Self::admin_rpc) when tracing many transactions.
+    /// The configured address book is attached for [`TraceResponse::pretty`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let trace = client.vnets().trace("vnet-123", "0xabc...").await?;
+    /// if let Some(err) = trace.error() {
+    ///     println!("reverted: {err}");
+    /// }
+    /// ```
+    pub async fn trace(&self, vnet_id: &str, tx_hash: &str) -> Result<TraceResponse> {
+        let trace = self
+            .admin_rpc(vnet_id)
+            .await?
+            .trace_transaction(tx_hash)
+            .await?;
+        Ok(trace.with_address_book(self.client.config().address_book.clone()))
+    }
+
     /// Get the RPC URLs for a Virtual TestNet
     pub async fn rpc_urls(&self, vnet_id: &str) -> Result<VNetRpcs> {
         let vnet = self.get(vnet_id).await?;
@@ -172,7 +482,44 @@ impl<'a> VNetsApi<'a> {
         let admin_url = rpcs.admin().ok_or_else(|| {
             crate::error::Error::not_found("Admin RPC URL not available for this VNet")
         })?;
-        AdminRpc::new(admin_url)
+        self.admin_rpc_for_url(admin_url)
+    }
+
+    /// Admin RPC client sharing this client's VCR cassette, if any
+    fn admin_rpc_for_url(&self, url: &str) -> Result<AdminRpc> {
+        let rpc = AdminRpc::new(url)?;
+        #[cfg(feature = "vcr")]
+        let rpc = match &self.client.config().vcr {
+            Some(cassette) => rpc.with_vcr(std::sync::Arc::clone(cassette)),
+            None => rpc,
+        };
+        Ok(rpc)
+    }
+
+    /// Subscribe to new blocks or logs over the VNet's websocket RPC
+    ///
+    /// Requires the `ws` feature. See [`Subscription`](super::Subscription)
+    /// for reconnection behavior.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut heads = client.vnets().subscribe("vnet-123", SubscriptionKind::NewHeads).await?;
+    /// while let Some(event) = heads.recv().await {
+    ///     println!("{:?}", event?);
+    /// }
+    /// ```
+    #[cfg(feature = "ws")]
+    pub async fn subscribe(
+        &self,
+        vnet_id: &str,
+        kind: super::SubscriptionKind,
+    ) -> Result<super::Subscription> {
+        let rpcs = self.rpc_urls(vnet_id).await?;
+        let url = rpcs.websocket().ok_or_else(|| {
+            crate::error::Error::not_found("WebSocket RPC URL not available for this VNet")
+        })?;
+        super::Subscription::connect(url, kind).await
     }
 
     /// Get an Admin RPC client from an existing VNet object
@@ -193,7 +540,7 @@ impl<'a> VNetsApi<'a> {
         let admin_url = rpcs.admin().ok_or_else(|| {
             crate::error::Error::not_found("Admin RPC URL not available for this VNet")
         })?;
-        AdminRpc::new(admin_url)
+        self.admin_rpc_for_url(admin_url)
     }
 
     /// Update a Virtual TestNet
@@ -231,16 +578,87 @@ impl<'a> VNetsApi<'a> {
         vnet_id: &str,
         request: &SendVNetTransactionRequest,
     ) -> Result<VNetTransaction> {
+        let request = request.normalized(self.client.config().strict_checksums)?;
         self.client
             .post(
                 &format!("/vnets/{}/transactions", encode_path_segment(vnet_id)),
-                request,
+                &request,
             )
             .await
     }
 
+    /// Fund an account and send a transaction from it via Admin RPC
+    ///
+    /// Sets the balance of `from` to `amount` wei (hex or decimal) with
+    /// `tenderly_setBalance`, then sends `request` with `eth_sendTransaction`
+    /// from that account (VNets accept unsigned transactions from any
+    /// address). `request.from` is replaced with `from`. Returns the
+    /// transaction hash.
+    ///
+    /// # Errors
+    ///
+    /// If the send fails, the error is wrapped with [`Error::context`]
+    /// noting that the account was already funded.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let tx = SendTransactionParams::new(whale).to(token).data(calldata);
+    /// let hash = client
+    ///     .vnets()
+    ///     .fund_and_send("vnet-123", whale, "1000000000000000000", &tx)
+    ///     .await?;
+    /// ```
+    pub async fn fund_and_send(
+        &self,
+        vnet_id: &str,
+        from: &str,
+        amount: &str,
+        request: &SendTransactionParams,
+    ) -> Result<String> {
+        let admin = self.admin_rpc(vnet_id).await?;
+        admin.set_balance(from, amount).await?;
+
+        let request = SendTransactionParams {
+            from: from.to_string(),
+            ..request.clone()
+        };
+        admin.send_transaction(&request).await.map_err(|err| {
+            err.context(format!(
+                "Funded {} but sending the transaction failed",
+                from
+            ))
+        })
+    }
+
+    /// Read an ERC20 balance on a Virtual TestNet
+    ///
+    /// See [`AdminRpc::erc20_balance_of`].
+    #[cfg(feature = "abi")]
+    pub async fn erc20_balance_of(
+        &self,
+        vnet_id: &str,
+        token: &str,
+        holder: &str,
+    ) -> Result<crate::U256> {
+        self.admin_rpc(vnet_id)
+            .await?
+            .erc20_balance_of(token, holder)
+            .await
+    }
+
+    /// Read an ERC20 token's name, symbol and decimals on a Virtual TestNet
+    ///
+    /// See [`AdminRpc::erc20_metadata`].
+    #[cfg(feature = "abi")]
+    pub async fn erc20_metadata(&self, vnet_id: &str, token: &str) -> Result<super::Erc20Metadata> {
+        self.admin_rpc(vnet_id).await?.erc20_metadata(token).await
+    }
+
     /// Get a specific transaction from a Virtual TestNet
     ///
+    /// Returns [`Error::NotFound`] if the VNet has no such transaction.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -260,10 +678,52 @@ impl<'a> VNetsApi<'a> {
     }
 }
 
+/// Tenderly's keyless Node RPC endpoint for a network
+fn public_gateway_url(node_rpc_slug: &str) -> String {
+    format!("https://{}.gateway.tenderly.co", node_rpc_slug)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_vnets_stay_on_v1_when_config_is_v2() {
+        use crate::client::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new(
+            crate::Config::new("key", "acc", "proj")
+                .with_base_url(format!("{}/api/v2", server.uri()))
+                .with_api_version(ApiVersion::V2),
+        )
+        .unwrap();
+        assert!(client.vnets().list(None).await.unwrap().is_empty());
+
+        // Same with the default host: the module's URLs use /api/v1
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_api_version(ApiVersion::V2))
+                .unwrap();
+        let vnets = client.vnets();
+        assert_eq!(
+            vnets.client.url("/vnets"),
+            "https://api.tenderly.co/api/v1/account/acc/project/proj/vnets"
+        );
+        assert_eq!(
+            client.url("/vnets"),
+            "https://api.tenderly.co/api/v2/account/acc/project/proj/vnets"
+        );
+    }
+
     #[test]
     fn test_create_vnet_request_builder() {
         let request = CreateVNetRequest::new("test-vnet", "Test VNet", 1)
@@ -279,6 +739,47 @@ mod tests {
         assert!(request.sync_state_config.is_some());
     }
 
+    #[tokio::test]
+    async fn test_create_vnet_request_validate_against() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/supported-networks"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"network_name": "Mainnet", "chain_id": "1",
+                 "supported_features": {"virtual_testnet": true}},
+                {"network_name": "Base", "chain_id": "8453",
+                 "supported_features": {"virtual_testnet": true}},
+                {"network_name": "Sim Only", "chain_id": "56",
+                 "supported_features": {"simulator": true}}
+            ])))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let supported = client.networks().vnet_chain_ids().await.unwrap();
+        assert_eq!(supported, vec![1, 8_453]);
+
+        let request = CreateVNetRequest::new("test-vnet", "Test VNet", 8_453);
+        assert!(request.validate_against(&supported).is_ok());
+
+        let request = CreateVNetRequest::new("test-vnet", "Test VNet", 56);
+        let err = request.validate_against(&supported).unwrap_err();
+        assert!(matches!(err, Error::InvalidParam(ref msg) if msg.contains("56")));
+    }
+
+    #[test]
+    fn test_create_vnet_request_for_chain() {
+        let request =
+            CreateVNetRequest::for_chain("base-vnet", "Base VNet", crate::networks::Chain::Base);
+        assert_eq!(request.fork_config.network_id, 8_453);
+        assert_eq!(request.virtual_network_config.chain_config.chain_id, 8_453);
+    }
+
     #[test]
     fn test_list_query_builder() {
         let query = ListVNetsQuery::new().slug("pr-").page(2).per_page(50);
@@ -312,4 +813,941 @@ mod tests {
             "chain_id should not be directly in virtual_network_config"
         );
     }
+
+    #[test]
+    fn test_create_vnet_fork_block_serialization() {
+        let current = CreateVNetRequest::new("test-vnet", "Test VNet", 1)
+            .block_number(18_000_000)
+            .fork_from_current_block();
+        let json = serde_json::to_value(&current).unwrap();
+        assert!(json["fork_config"].get("block_number").is_none());
+
+        let fixed = CreateVNetRequest::new("test-vnet", "Test VNet", 1).block_number(18_000_000);
+        let json = serde_json::to_value(&fixed).unwrap();
+        assert_eq!(json["fork_config"]["block_number"], 18_000_000);
+    }
+
+    #[test]
+    fn test_fork_vnet_block_serialization() {
+        let latest = ForkVNetRequest::new("vnet-123", "forked", "Forked");
+        let json = serde_json::to_value(&latest).unwrap();
+        assert_eq!(json["block_number"], "latest");
+
+        let fixed = latest.block_number(12_345_678);
+        let json = serde_json::to_value(&fixed).unwrap();
+        assert_eq!(json["block_number"], 12_345_678);
+
+        let json = serde_json::to_value(fixed.latest()).unwrap();
+        assert_eq!(json["block_number"], "latest");
+    }
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_create_and_update_vnet_request_round_trip() {
+        let create = CreateVNetRequest::new("test-vnet", "Test VNet", 1)
+            .block_number(18_000_000)
+            .chain_id(31_337)
+            .base_fee_per_gas(7)
+            .sync_state(true)
+            .explorer_page(true, "src");
+        assert_eq!(round_trip(&create), create);
+
+        let minimal = CreateVNetRequest::new("test-vnet", "Test VNet", 1);
+        assert_eq!(round_trip(&minimal), minimal);
+
+        let update = UpdateVNetRequest::new()
+            .display_name("Renamed")
+            .sync_state(false);
+        assert_eq!(round_trip(&update), update);
+        assert_eq!(
+            round_trip(&UpdateVNetRequest::new()),
+            UpdateVNetRequest::new()
+        );
+    }
+
+    #[test]
+    fn test_fork_vnet_request_round_trip() {
+        let latest = ForkVNetRequest::new("vnet123", "forked", "Forked");
+        let json = serde_json::to_value(&latest).unwrap();
+        assert_eq!(json["vnet_id"], "vnet123");
+        assert_eq!(round_trip(&latest), latest);
+
+        let fixed = latest.block_number(12_345_678);
+        assert_eq!(round_trip(&fixed), fixed);
+
+        let parsed: ForkVNetRequest =
+            serde_json::from_str(r#"{"vnet_id": "vnet123", "slug": "f", "display_name": "F"}"#)
+                .unwrap();
+        assert_eq!(parsed.source_vnet_id, "vnet123");
+        assert_eq!(parsed.block_number, None);
+
+        let err = serde_json::from_str::<ForkVNetRequest>(
+            r#"{"vnet_id": "v", "slug": "f", "display_name": "F", "block_number": "pending"}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("pending"));
+    }
+
+    #[test]
+    fn test_send_vnet_transaction_request_round_trip() {
+        let request = SendVNetTransactionRequest::new(
+            "0x1111111111111111111111111111111111111111",
+            "0x2222222222222222222222222222222222222222",
+            "0xa9059cbb",
+        )
+        .value("0x1")
+        .gas(21_000)
+        .eip1559_fees(30, 2)
+        .access_list(vec![AccessListItem {
+            address: "0x2222222222222222222222222222222222222222".into(),
+            storage_keys: vec!["0x0".into()],
+        }]);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], 2);
+        assert_eq!(round_trip(&request), request);
+    }
+
+    #[tokio::test]
+    async fn test_latest_block() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/node/mainnet"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "eth_blockNumber"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0", "id": 1, "result": "0x12a05f2"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/supported-networks"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "network_name": "Example",
+                    "chain_id": "424242",
+                    "network_slugs": {"explorer_slug": "example"},
+                    "supported_features": {"virtual_testnet": true}
+                }])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::new(
+            crate::Config::new("key", "acc", "proj")
+                .with_base_url(server.uri())
+                .with_node_rpc_url("1", format!("{}/node/mainnet", server.uri())),
+        )
+        .unwrap();
+        assert_eq!(client.vnets().latest_block(1).await.unwrap(), 19_531_250);
+
+        // Without a configured URL the network must offer Node RPC
+        let err = client.vnets().latest_block(424242).await.unwrap_err();
+        assert!(err.is_unsupported());
+        let err = client.vnets().latest_block(7).await.unwrap_err();
+        assert!(err.is_not_found());
+
+        assert_eq!(
+            public_gateway_url("sepolia"),
+            "https://sepolia.gateway.tenderly.co"
+        );
+    }
+
+    fn vnet_json(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "vnet1",
+            "slug": "test-vnet",
+            "display_name": "Test VNet",
+            "fork_config": {"network_id": 1},
+            "virtual_network_config": {"chain_config": {"chain_id": 1}},
+            "status": status
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_and_wait() {
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("creating")))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("creating")))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("running")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let request = CreateVNetRequest::new("test-vnet", "Test VNet", 1);
+
+        let vnet = client
+            .vnets()
+            .create_and_wait(&request, Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert!(vnet.is_running());
+
+        // No time left to poll: fails with the status returned by create
+        let err = client
+            .vnets()
+            .create_and_wait(&request, Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert!(err.is_timeout());
+        assert!(matches!(
+            err,
+            Error::Timeout { last_status: Some(ref s), .. } if s == "creating"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stop_and_start() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets/vnet1/stop"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("stopped")))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets/vnet1/start"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("running")))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let stopped = client.vnets().stop("vnet1").await.unwrap();
+        assert_eq!(stopped.status, Some(VNetStatus::Stopped));
+
+        let started = client.vnets().start("vnet1").await.unwrap();
+        assert!(started.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_create_or_get_creates() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("running")))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let request = CreateVNetRequest::new("test-vnet", "Test VNet", 1);
+        let vnet = client.vnets().create_or_get(&request).await.unwrap();
+        assert_eq!(vnet.id, "vnet1");
+    }
+
+    #[tokio::test]
+    async fn test_create_or_get_fetches_on_conflict() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "error": {"slug": "vnet_slug_taken", "message": "slug already exists"}
+            })))
+            .mount(&server)
+            .await;
+
+        // Partial slug match returns a near-miss alongside the exact VNet
+        let mut near_miss = vnet_json("running");
+        near_miss["id"] = "vnet0".into();
+        near_miss["slug"] = "test-vnet-2".into();
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("slug", "test-vnet"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([near_miss, vnet_json("running")])),
+            )
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let request = CreateVNetRequest::new("test-vnet", "Test VNet", 1);
+        let vnet = client.vnets().create_or_get(&request).await.unwrap();
+        assert_eq!(vnet.id, "vnet1");
+        assert_eq!(vnet.slug, "test-vnet");
+
+        let missing = CreateVNetRequest::new("other", "Other", 1);
+        let err = client.vnets().create_or_get(&missing).await.unwrap_err();
+        assert!(err.is_not_found());
+
+        // Conflicts outside create endpoints are not treated as duplicates
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets/vnet1/fork"))
+            .respond_with(ResponseTemplate::new(409).set_body_string("slug already exists"))
+            .mount(&server)
+            .await;
+        let err = client
+            .post::<serde_json::Value, _>("/vnets/vnet1/fork", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Api { status: 409, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_matching_stops_when_page_is_ignored() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Every page request returns the same full page
+        let page: Vec<_> = (0..100)
+            .map(|i| {
+                let mut json = vnet_json("running");
+                json["id"] = format!("v{}", i).into();
+                json["slug"] = format!("ci-{}", i).into();
+                json
+            })
+            .collect();
+        let ids: Vec<_> = (0..100).map(|i| format!("v{}", i)).collect();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&page))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(body_json(serde_json::json!({"vnet_ids": ids})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        assert_eq!(
+            client.vnets().delete_all_matching("ci-").await.unwrap(),
+            100
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_by_slug_pages_past_partial_matches() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let near_misses: Vec<_> = (0..100)
+            .map(|i| {
+                let mut json = vnet_json("running");
+                json["id"] = format!("v{}", i).into();
+                json["slug"] = format!("test-vnet-{}", i).into();
+                json
+            })
+            .collect();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&near_misses))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([vnet_json("running")])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let vnet = client
+            .vnets()
+            .get_by_slug("test-vnet")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vnet.id, "vnet1");
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_slug() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("slug", "test-vnet"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([vnet_json("running")])),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("slug", "missing"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        assert!(client.vnets().delete_by_slug("test-vnet").await.unwrap());
+        assert!(!client.vnets().delete_by_slug("missing").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_matching() {
+        use wiremock::matchers::{body_json, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let vnet = |id: &str, slug: &str| {
+            let mut json = vnet_json("running");
+            json["id"] = id.into();
+            json["slug"] = slug.into();
+            json
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("slug", "ci"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                vnet("a", "ci1"),
+                vnet("b", "ci2"),
+                vnet("c", "nightlyci"),
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(query_param("slug", "none"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/account/acc/project/proj/vnets"))
+            .and(body_json(serde_json::json!({"vnet_ids": ["a", "b"]})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        assert_eq!(client.vnets().delete_all_matching("ci").await.unwrap(), 2);
+        assert_eq!(client.vnets().delete_all_matching("none").await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_from_simulation_request() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let request = crate::simulation::SimulationRequest::new(
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "0x70a08231",
+        )
+        .network_id("137")
+        .gas(50_000)
+        .max_fee_per_gas("100")
+        .nonce(4)
+        .override_balance("0xd8da6bf26964af9d7eed9e03e53415d37aa96045", "0x1");
+
+        let vnet_request = VNetSimulationRequest::from(&request);
+        assert_eq!(vnet_request.from, request.from);
+        assert_eq!(vnet_request.to, request.to);
+        assert_eq!(vnet_request.input, "0x70a08231");
+        assert_eq!(vnet_request.gas, Some(50_000));
+        assert_eq!(vnet_request.max_fee_per_gas.as_deref(), Some("100"));
+        assert_eq!(vnet_request.transaction_type, Some(2));
+        assert_eq!(vnet_request.nonce, Some(4));
+        assert_eq!(vnet_request.network_id.as_deref(), Some("137"));
+
+        let server = MockServer::start().await;
+        let mut vnet = vnet_json("running");
+        vnet["virtual_network_config"]["chain_config"]["chain_id"] = 137.into();
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(
+                "/account/acc/project/proj/vnets/vnet1/transactions/simulate",
+            ))
+            .and(body_json(serde_json::json!({
+                "from": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+                "to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "input": "0x70a08231",
+                "gas": 50_000,
+                "max_fee_per_gas": "100",
+                "type": 2,
+                "nonce": 4
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"status": true, "gasUsed": "0x5fc2"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let result = client
+            .vnets()
+            .simulate("vnet1", &(&request).into())
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.gas_used_as_u64(), Some(0x5fc2));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_chain_mismatch() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet_json("running")))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(
+                "/account/acc/project/proj/vnets/vnet1/transactions/simulate",
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": true})),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let request = VNetSimulationRequest::new(
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "0x",
+        );
+
+        // VNet forks chain 1
+        let matching = request.clone().network_id("1");
+        assert!(client.vnets().simulate("vnet1", &matching).await.is_ok());
+
+        let mismatched = request.clone().network_id("137");
+        let err = client
+            .vnets()
+            .simulate("vnet1", &mismatched)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidParam(ref m) if m.contains("chain ID 1")));
+
+        let allowed = mismatched.allow_chain_mismatch(true);
+        assert!(client.vnets().simulate("vnet1", &allowed).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stop_unsupported() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/vnets/vnet1/stop"))
+            .respond_with(ResponseTemplate::new(405).set_body_string("method not allowed"))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let err = client.vnets().stop("vnet1").await.unwrap_err();
+        assert!(err.is_unsupported());
+        assert!(err.to_string().contains("method not allowed"));
+
+        // Other endpoints keep the plain API error
+        let err = client
+            .post::<serde_json::Value, _>("/vnets/vnet1/stop", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Api { status: 405, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_update_sends_patch() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut renamed = vnet_json("running");
+        renamed["display_name"] = serde_json::json!("Renamed");
+        Mock::given(method("PATCH"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .and(body_json(serde_json::json!({"display_name": "Renamed"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(renamed))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+
+        let request = UpdateVNetRequest::new().display_name("Renamed");
+        let vnet = client.vnets().update("vnet1", &request).await.unwrap();
+        assert_eq!(vnet.display_name, "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_trace_via_admin_rpc() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut vnet = vnet_json("running");
+        vnet["rpcs"] = serde_json::json!([
+            {"name": "Admin RPC", "url": format!("{}/admin", server.uri())}
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/admin"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "tenderly_traceTransaction",
+                "params": ["0xabc"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "status": false,
+                    "gasUsed": "0x5208",
+                    "logs": [],
+                    "trace": [{
+                        "type": "CALL",
+                        "from": "0x1111111111111111111111111111111111111111",
+                        "to": "0x2222222222222222222222222222222222222222",
+                        "output": "0x",
+                        "error": "execution reverted",
+                        "subtraces": 0,
+                        "traceAddress": []
+                    }]
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let trace = client.vnets().trace("vnet1", "0xabc").await.unwrap();
+
+        assert_eq!(trace.status, Some(false));
+        assert_eq!(trace.gas_used_as_u64(), Some(21000));
+        assert_eq!(trace.output(), Some("0x"));
+        assert_eq!(trace.error(), Some("execution reverted"));
+    }
+
+    #[cfg(feature = "abi")]
+    #[tokio::test]
+    async fn test_erc20_balance_of() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut vnet = vnet_json("running");
+        vnet["rpcs"] = serde_json::json!([
+            {"name": "Admin RPC", "url": format!("{}/admin", server.uri())}
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/admin"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "eth_call",
+                "params": [{
+                    "to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "data": "0x70a08231000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+                }, "latest"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let balance = client
+            .vnets()
+            .erc20_balance_of(
+                "vnet1",
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            )
+            .await
+            .unwrap();
+        assert_eq!(balance, crate::U256::from(1_000_000_000_000_000_000u128));
+    }
+
+    #[tokio::test]
+    async fn test_fund_and_send() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut vnet = vnet_json("running");
+        vnet["rpcs"] = serde_json::json!([
+            {"name": "Admin RPC", "url": format!("{}/admin", server.uri())}
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vnet))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/admin"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "tenderly_setBalance",
+                "params": ["0x1111111111111111111111111111111111111111", "0x3e8"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0", "id": 1, "result": "0xb1"
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/admin"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "eth_sendTransaction",
+                "params": [{
+                    "from": "0x1111111111111111111111111111111111111111",
+                    "to": "0x2222222222222222222222222222222222222222"
+                }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0", "id": 2, "result": "0xt1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/admin"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "eth_sendTransaction",
+                "params": [{"to": "0x3333333333333333333333333333333333333333"}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "error": {"code": -32000, "message": "execution reverted"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let from = "0x1111111111111111111111111111111111111111";
+        let tx = SendTransactionParams::new("0x0").to("0x2222222222222222222222222222222222222222");
+        let hash = client
+            .vnets()
+            .fund_and_send("vnet1", from, "1000", &tx)
+            .await
+            .unwrap();
+        assert_eq!(hash, "0xt1");
+
+        let admin_methods: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/admin")
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body["method"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(
+            admin_methods,
+            ["tenderly_setBalance", "eth_sendTransaction"]
+        );
+
+        let tx = SendTransactionParams::new(from).to("0x3333333333333333333333333333333333333333");
+        let err = client
+            .vnets()
+            .fund_and_send("vnet1", from, "1000", &tx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Funded 0x1111"));
+        assert_eq!(err.root().code(), Some(-32000));
+    }
+
+    #[tokio::test]
+    async fn test_transactions_accepts_wrapped_response() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transactions": [{"tx_hash": "0x111", "status": "success"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let txs = client.vnets().transactions("vnet1", None).await.unwrap();
+        assert_eq!(txs.len(), 1);
+        assert!(txs[0].is_success());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_found() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/account/acc/project/proj/vnets/vnet1/transactions/0xabc",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "tx1",
+                "vnet_id": "vnet1",
+                "tx_hash": "0xabc",
+                "block_number": "0x10",
+                "status": "failed",
+                "error_reason": "execution reverted",
+                "rpc_method": "eth_sendTransaction",
+                "stateOverrides": {}
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let tx = client
+            .vnets()
+            .get_transaction("vnet1", "0xabc")
+            .await
+            .unwrap();
+
+        assert_eq!(tx.tx_hash.as_deref(), Some("0xabc"));
+        assert_eq!(tx.block_number_as_u64(), Some(16));
+        assert!(tx.is_failed());
+        assert_eq!(tx.error_reason.as_deref(), Some("execution reverted"));
+        assert!(tx.state_overrides.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_not_found() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/account/acc/project/proj/vnets/vnet1/transactions/0xmissing",
+            ))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": {"message": "transaction not found"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let err = client
+            .vnets()
+            .get_transaction("vnet1", "0xmissing")
+            .await
+            .unwrap_err();
+
+        assert!(err.is_not_found(), "{:?}", err);
+    }
 }
@@ -11,6 +11,7 @@
 //! - Fork VNets from other VNets
 //! - CI/CD integration with bulk operations
 //! - Admin RPC for state manipulation (time warping, balance setting, snapshots)
+//! - Websocket subscriptions to new blocks and logs (`ws` feature)
 //!
 //! # Example
 //!
@@ -45,10 +46,18 @@
 
 pub mod admin_rpc;
 mod api;
+#[cfg(feature = "ws")]
+pub mod subscription;
 mod types;
 
+#[cfg(feature = "abi")]
+pub use admin_rpc::erc20_allowance_slot;
 pub use admin_rpc::{
-    AccessListEntry, AccessListResult, AdminRpc, LatestBlock, SendTransactionParams,
+    AccessListEntry, AccessListResult, AdminRpc, BlockId, CallFrame, CallRequest, Erc20Metadata,
+    LatestBlock, LogFilter, NodeTrace, PrestateAccount, ReceiptLog, RpcTransaction,
+    SendTransactionParams, TraceCallOptions, Tracer, TracerConfig, OZ_ERC20_ALLOWANCES_SLOT,
 };
 pub use api::VNetsApi;
+#[cfg(feature = "ws")]
+pub use subscription::{NewHead, Subscription, SubscriptionKind, VNetEvent};
 pub use types::*;
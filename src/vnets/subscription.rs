@@ -0,0 +1,471 @@
+//! Websocket subscriptions to VNet blocks and logs
+//!
+//! Requires the `ws` feature. A [`Subscription`] sends `eth_subscribe` over
+//! a VNet's websocket RPC and yields each notification as a typed
+//! [`VNetEvent`]. If the connection drops it reconnects and subscribes
+//! again; notifications sent while disconnected are not replayed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use tndrly::vnets::{LogFilter, SubscriptionKind, VNetEvent};
+//!
+//! let filter = LogFilter::new().address("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+//! let mut events = client
+//!     .vnets()
+//!     .subscribe("vnet-123", SubscriptionKind::Logs(filter))
+//!     .await?;
+//! while let Some(event) = events.recv().await {
+//!     if let VNetEvent::Log(log) = event? {
+//!         println!("log in tx {:?}", log.transaction_hash);
+//!     }
+//! }
+//! ```
+
+use super::admin_rpc::{LogFilter, ReceiptLog};
+use crate::error::{Error, Result};
+use crate::utils::parse_hex_u64;
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// What to subscribe to
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SubscriptionKind {
+    /// Every new block header (`newHeads`)
+    NewHeads,
+    /// Logs matching a filter (`logs`); block range and hash are ignored
+    Logs(LogFilter),
+}
+
+impl SubscriptionKind {
+    /// Get the subscription name as sent to the node
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::NewHeads => "newHeads",
+            Self::Logs(_) => "logs",
+        }
+    }
+
+    fn params(&self) -> serde_json::Value {
+        match self {
+            Self::NewHeads => serde_json::json!([self.as_str()]),
+            Self::Logs(filter) => {
+                let filter = LogFilter {
+                    from_block: None,
+                    to_block: None,
+                    block_hash: None,
+                    ..filter.clone()
+                };
+                serde_json::json!([self.as_str(), filter])
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for SubscriptionKind {
+    type Err = String;
+
+    /// Parse a subscription name; `logs` subscribes to every log
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "newheads" => Ok(Self::NewHeads),
+            "logs" => Ok(Self::Logs(LogFilter::new())),
+            _ => Err(format!(
+                "Invalid subscription: {}. Expected: newHeads or logs",
+                s
+            )),
+        }
+    }
+}
+
+/// Block header delivered by a `newHeads` subscription
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewHead {
+    /// Block number (hex)
+    #[serde(default)]
+    pub number: Option<String>,
+
+    /// Block hash
+    #[serde(default)]
+    pub hash: Option<String>,
+
+    /// Parent block hash
+    #[serde(default)]
+    pub parent_hash: Option<String>,
+
+    /// Block timestamp (hex)
+    #[serde(default)]
+    pub timestamp: Option<String>,
+
+    /// Gas limit (hex)
+    #[serde(default)]
+    pub gas_limit: Option<String>,
+
+    /// Gas used (hex)
+    #[serde(default)]
+    pub gas_used: Option<String>,
+
+    /// Base fee per gas (hex)
+    #[serde(default)]
+    pub base_fee_per_gas: Option<String>,
+
+    /// Miner/coinbase address
+    #[serde(default)]
+    pub miner: Option<String>,
+}
+
+impl NewHead {
+    /// Parse number from hex string to u64
+    #[must_use]
+    pub fn number_as_u64(&self) -> Option<u64> {
+        self.number.as_deref().and_then(parse_hex_u64)
+    }
+
+    /// Parse timestamp from hex string to u64
+    #[must_use]
+    pub fn timestamp_as_u64(&self) -> Option<u64> {
+        self.timestamp.as_deref().and_then(parse_hex_u64)
+    }
+}
+
+/// A notification from a [`Subscription`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum VNetEvent {
+    /// A new block was mined
+    NewHead(NewHead),
+    /// A matching log was emitted
+    Log(ReceiptLog),
+}
+
+/// A live `eth_subscribe` subscription on a VNet websocket
+///
+/// Yields events through [`recv`](Self::recv) or as a [`Stream`]. After a
+/// dropped connection it reconnects every
+/// [`RECONNECT_DELAY`](Self::RECONNECT_DELAY); once
+/// [`MAX_RECONNECT_ATTEMPTS`](Self::MAX_RECONNECT_ATTEMPTS) attempts in a
+/// row fail, the last error is yielded and the stream ends. Dropping the
+/// subscription closes the connection.
+#[derive(Debug)]
+pub struct Subscription {
+    receiver: mpsc::Receiver<Result<VNetEvent>>,
+    task: JoinHandle<()>,
+}
+
+impl Subscription {
+    /// Delay before each reconnection attempt
+    pub const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+    /// Consecutive failed reconnection attempts before giving up
+    pub const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+    /// Events buffered before the connection waits for the reader
+    const BUFFER: usize = 64;
+
+    /// Connect to a websocket RPC URL and subscribe
+    ///
+    /// Fails if the first connection or the `eth_subscribe` call fails.
+    pub async fn connect(url: &str, kind: SubscriptionKind) -> Result<Self> {
+        let (socket, id) = open(url, &kind).await?;
+        let (sender, receiver) = mpsc::channel(Self::BUFFER);
+        let task = tokio::spawn(run(url.to_string(), kind, socket, id, sender));
+        Ok(Self { receiver, task })
+    }
+
+    /// Wait for the next event
+    ///
+    /// Returns `None` once the subscription has ended.
+    pub async fn recv(&mut self) -> Option<Result<VNetEvent>> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for Subscription {
+    type Item = Result<VNetEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Incoming websocket message: a call response or a notification
+#[derive(Debug, Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    id: Option<u64>,
+    #[serde(default)]
+    result: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<RpcError>,
+    #[serde(default)]
+    params: Option<Notification>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Notification {
+    subscription: String,
+    result: serde_json::Value,
+}
+
+/// Connect, subscribe and return the socket with the subscription ID
+async fn open(url: &str, kind: &SubscriptionKind) -> Result<(Socket, String)> {
+    const REQUEST_ID: u64 = 1;
+
+    let (mut socket, _) = tokio_tungstenite::connect_async(url).await?;
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": REQUEST_ID,
+        "method": "eth_subscribe",
+        "params": kind.params(),
+    });
+    socket.send(Message::text(request.to_string())).await?;
+
+    while let Some(message) = socket.next().await {
+        let Message::Text(text) = message? else {
+            continue;
+        };
+        let envelope: Envelope = serde_json::from_str(text.as_str())?;
+        if envelope.id != Some(REQUEST_ID) {
+            continue;
+        }
+        if let Some(error) = envelope.error {
+            return Err(Error::json_rpc(error.code, error.message, error.data));
+        }
+        let id = serde_json::from_value(envelope.result.unwrap_or_default())?;
+        return Ok((socket, id));
+    }
+    Err(Error::from(tungstenite::Error::ConnectionClosed)
+        .context(format!("subscribing to {}", kind)))
+}
+
+/// Forward notifications, reconnecting whenever the socket drops
+async fn run(
+    url: String,
+    kind: SubscriptionKind,
+    mut socket: Socket,
+    mut id: String,
+    sender: mpsc::Sender<Result<VNetEvent>>,
+) {
+    loop {
+        while let Some(message) = socket.next().await {
+            let text = match message {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Close(_)) | Err(_) => break,
+                Ok(_) => continue,
+            };
+            if let Some(event) = parse_event(text.as_str(), &id, &kind) {
+                if sender.send(event).await.is_err() {
+                    return;
+                }
+            }
+        }
+
+        let mut attempts = 0;
+        loop {
+            if sender.is_closed() {
+                return;
+            }
+            tokio::time::sleep(Subscription::RECONNECT_DELAY).await;
+            match open(&url, &kind).await {
+                Ok((new_socket, new_id)) => {
+                    socket = new_socket;
+                    id = new_id;
+                    break;
+                }
+                Err(e) => {
+                    attempts += 1;
+                    if attempts >= Subscription::MAX_RECONNECT_ATTEMPTS {
+                        let _ = sender
+                            .send(Err(e.context(format!("reconnecting to {}", url))))
+                            .await;
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Decode a notification for subscription `id`; other messages yield `None`
+fn parse_event(text: &str, id: &str, kind: &SubscriptionKind) -> Option<Result<VNetEvent>> {
+    let envelope: Envelope = match serde_json::from_str(text) {
+        Ok(envelope) => envelope,
+        Err(e) => return Some(Err(e.into())),
+    };
+    let notification = envelope.params.filter(|n| n.subscription == id)?;
+    let event = match kind {
+        SubscriptionKind::NewHeads => {
+            serde_json::from_value(notification.result).map(VNetEvent::NewHead)
+        }
+        SubscriptionKind::Logs(_) => {
+            serde_json::from_value(notification.result).map(VNetEvent::Log)
+        }
+    };
+    Some(event.map_err(Error::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Serve one `newHeads` notification per connection, then drop it
+    async fn serve_heads(listener: TcpListener, connections: u64) {
+        for block in 1..=connections {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            let Some(Ok(Message::Text(request))) = socket.next().await else {
+                panic!("expected eth_subscribe request");
+            };
+            let request: serde_json::Value = serde_json::from_str(request.as_str()).unwrap();
+            assert_eq!(request["method"], "eth_subscribe");
+            assert_eq!(request["params"], serde_json::json!(["newHeads"]));
+
+            let subscription = format!("0xsub{}", block);
+            let messages = [
+                serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": subscription}),
+                // Notifications for other subscriptions are ignored
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "eth_subscription",
+                    "params": {"subscription": "0xother", "result": {"number": "0x99"}}
+                }),
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "eth_subscription",
+                    "params": {
+                        "subscription": subscription,
+                        "result": {
+                            "number": format!("0x{:x}", block),
+                            "hash": format!("0x{:064x}", block),
+                            "timestamp": "0x6553f100"
+                        }
+                    }
+                }),
+            ];
+            for message in messages {
+                socket
+                    .send(Message::text(message.to_string()))
+                    .await
+                    .unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_new_heads_reconnects() {
+        use crate::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+        let ws_server = tokio::spawn(serve_heads(listener, 2));
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/vnets/vnet1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "vnet1",
+                "slug": "test-vnet",
+                "display_name": "Test VNet",
+                "fork_config": {"network_id": 1},
+                "virtual_network_config": {"chain_config": {"chain_id": 1}},
+                "rpcs": [
+                    {"name": "Admin RPC", "url": "https://virtual.mainnet.rpc.tenderly.co/admin"},
+                    {"name": "Public WebSocket RPC", "url": ws_url}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let mut events = client
+            .vnets()
+            .subscribe("vnet1", SubscriptionKind::NewHeads)
+            .await
+            .unwrap();
+
+        // The server drops the connection after each notification
+        for expected in 1..=2 {
+            let Some(Ok(VNetEvent::NewHead(head))) = events.recv().await else {
+                panic!("expected a new head");
+            };
+            assert_eq!(head.number_as_u64(), Some(expected));
+            assert_eq!(head.timestamp_as_u64(), Some(1_700_000_000));
+        }
+        ws_server.await.unwrap();
+    }
+
+    #[test]
+    fn test_subscription_kind_display_round_trip() {
+        for kind in [
+            SubscriptionKind::NewHeads,
+            SubscriptionKind::Logs(LogFilter::new()),
+        ] {
+            assert_eq!(kind.to_string().parse::<SubscriptionKind>().unwrap(), kind);
+        }
+        assert_eq!(
+            "new_heads".parse::<SubscriptionKind>().unwrap(),
+            SubscriptionKind::NewHeads
+        );
+        assert!("pendingTransactions".parse::<SubscriptionKind>().is_err());
+    }
+
+    #[test]
+    fn test_logs_params_drop_block_range() {
+        let filter = LogFilter::new()
+            .from_block(1u64)
+            .address("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+            .topic(
+                0,
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            );
+        assert_eq!(
+            SubscriptionKind::Logs(filter).params(),
+            serde_json::json!([
+                "logs",
+                {
+                    "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "topics": ["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"]
+                }
+            ])
+        );
+    }
+}
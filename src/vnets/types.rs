@@ -1,9 +1,14 @@
 //! Types for Virtual TestNets API
 
+use crate::client::encode_path_segment;
+pub use crate::simulation::DecodedParam;
+use crate::utils::{parse_address, parse_hex_u64};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Request to create a new Virtual TestNet
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateVNetRequest {
     /// Unique slug for the VNet
     pub slug: String,
@@ -47,6 +52,18 @@ impl CreateVNetRequest {
         }
     }
 
+    /// Create a new VNet request forking a well-known
+    /// [`Chain`](crate::networks::Chain)
+    ///
+    /// Equivalent to [`new`](Self::new) with `chain.id()`.
+    pub fn for_chain(
+        slug: impl Into<String>,
+        display_name: impl Into<String>,
+        chain: crate::networks::Chain,
+    ) -> Self {
+        Self::new(slug, display_name, chain.id())
+    }
+
     /// Fork from a specific block
     #[must_use]
     pub fn block_number(mut self, block: u64) -> Self {
@@ -54,6 +71,17 @@ impl CreateVNetRequest {
         self
     }
 
+    /// Fork from the parent network's current block
+    ///
+    /// Sugar for leaving the block unset, which the API treats as `latest`.
+    /// Use [`VNetsApi::latest_block`](super::VNetsApi::latest_block) with
+    /// [`block_number`](Self::block_number) to pin an explicit block instead.
+    #[must_use]
+    pub fn fork_from_current_block(mut self) -> Self {
+        self.fork_config.block_number = None;
+        self
+    }
+
     /// Set a custom chain ID
     #[must_use]
     pub fn chain_id(mut self, chain_id: u64) -> Self {
@@ -84,10 +112,36 @@ impl CreateVNetRequest {
         });
         self
     }
+
+    /// Check that the fork network is one of `supported` chain IDs
+    ///
+    /// Opt-in and offline: fetch the list once with
+    /// [`NetworksApi::vnet_chain_ids`](crate::networks::NetworksApi::vnet_chain_ids)
+    /// (or use your own) and reuse it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let supported = client.networks().vnet_chain_ids().await?;
+    /// request.validate_against(&supported)?;
+    /// let vnet = client.vnets().create(&request).await?;
+    /// ```
+    pub fn validate_against(&self, supported: &[u64]) -> crate::error::Result<()> {
+        let network_id = self.fork_config.network_id;
+        if supported.contains(&network_id) {
+            Ok(())
+        } else {
+            Err(crate::error::Error::invalid_param(format!(
+                "fork network {} is not supported for Virtual TestNets",
+                network_id
+            )))
+        }
+    }
 }
 
 /// Fork configuration for requests
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ForkConfig {
     /// Network ID to fork from
     pub network_id: u64,
@@ -98,7 +152,7 @@ pub struct ForkConfig {
 }
 
 /// Fork configuration from API response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ForkConfigResponse {
     /// Network ID
     pub network_id: u64,
@@ -109,7 +163,8 @@ pub struct ForkConfigResponse {
 }
 
 /// Virtual network configuration for requests
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VirtualNetworkConfig {
     /// Chain configuration (required by Tenderly API)
     pub chain_config: ChainConfig,
@@ -120,7 +175,7 @@ pub struct VirtualNetworkConfig {
 }
 
 /// Virtual network configuration from API response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VirtualNetworkConfigResponse {
     /// Nested chain configuration
     #[serde(default)]
@@ -144,21 +199,24 @@ impl VirtualNetworkConfigResponse {
 }
 
 /// Chain configuration nested in VirtualNetworkConfigResponse
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ChainConfig {
     /// Chain ID
     pub chain_id: u64,
 }
 
 /// State sync configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SyncStateConfig {
     /// Whether to sync state from the parent network
     pub enabled: bool,
 }
 
 /// Explorer page configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ExplorerPageConfig {
     /// Whether explorer page is enabled
     pub enabled: bool,
@@ -168,7 +226,7 @@ pub struct ExplorerPageConfig {
 }
 
 /// Virtual TestNet details from API response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VNet {
     /// VNet ID
     pub id: String,
@@ -195,7 +253,94 @@ pub struct VNet {
 
     /// Status
     #[serde(default)]
-    pub status: Option<String>,
+    pub status: Option<VNetStatus>,
+
+    /// Dashboard URL for the VNet, if the API provided one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dashboard_url: Option<String>,
+}
+
+impl VNet {
+    /// Check if the VNet is running
+    pub fn is_running(&self) -> bool {
+        self.status == Some(VNetStatus::Running)
+    }
+
+    /// Dashboard URL for the VNet
+    ///
+    /// Uses the URL from the API when present, otherwise builds one from the
+    /// client's account and project slugs.
+    #[must_use]
+    pub fn dashboard_url(&self, client: &crate::Client) -> String {
+        self.dashboard_url.clone().unwrap_or_else(|| {
+            client.dashboard_url(&format!("/testnet/{}", encode_path_segment(&self.id)))
+        })
+    }
+}
+
+/// Virtual TestNet status
+///
+/// Unrecognized values are preserved in [`VNetStatus::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VNetStatus {
+    /// VNet is running and accepting requests
+    Running,
+    /// VNet is stopped
+    Stopped,
+    /// VNet is being created
+    Creating,
+    /// VNet creation or operation failed
+    Failed,
+    /// Status not known to this client
+    Unknown(String),
+}
+
+impl VNetStatus {
+    /// Get the string representation
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Running => "running",
+            Self::Stopped => "stopped",
+            Self::Creating => "creating",
+            Self::Failed => "failed",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for VNetStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for VNetStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "running" => Self::Running,
+            "stopped" => Self::Stopped,
+            "creating" | "pending" => Self::Creating,
+            "failed" | "error" => Self::Failed,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VNetStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for VNetStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
 }
 
 fn deserialize_rpcs<'de, D>(deserializer: D) -> std::result::Result<Option<VNetRpcs>, D::Error>
@@ -207,7 +352,7 @@ where
 }
 
 /// Single RPC endpoint
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RpcEndpoint {
     /// Endpoint name (e.g., "Admin RPC", "Public RPC")
     pub name: String,
@@ -217,7 +362,7 @@ pub struct RpcEndpoint {
 }
 
 /// Collection of RPC endpoints for a VNet
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
 pub struct VNetRpcs {
     /// All RPC endpoints
     pub endpoints: Vec<RpcEndpoint>,
@@ -225,26 +370,72 @@ pub struct VNetRpcs {
 
 impl VNetRpcs {
     /// Get the public RPC URL
+    ///
+    /// Skips websocket endpoints; see [`websocket`](Self::websocket).
     #[must_use]
     pub fn public(&self) -> Option<&str> {
-        self.endpoints
-            .iter()
+        self.http_endpoints()
             .find(|e| e.name.to_lowercase().contains("public"))
             .map(|e| e.url.as_str())
     }
 
     /// Get the admin RPC URL
+    ///
+    /// Skips websocket endpoints; see [`websocket`](Self::websocket).
     #[must_use]
     pub fn admin(&self) -> Option<&str> {
+        self.http_endpoints()
+            .find(|e| e.name.to_lowercase().contains("admin"))
+            .map(|e| e.url.as_str())
+    }
+
+    /// Get the first websocket (`ws://` or `wss://`) RPC URL
+    #[must_use]
+    pub fn websocket(&self) -> Option<&str> {
         self.endpoints
             .iter()
-            .find(|e| e.name.to_lowercase().contains("admin"))
+            .find(|e| e.is_websocket())
+            .map(|e| e.url.as_str())
+    }
+
+    /// Get the URL of the endpoint named `name`, ignoring case
+    #[must_use]
+    pub fn by_name(&self, name: &str) -> Option<&str> {
+        self.endpoints
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
             .map(|e| e.url.as_str())
     }
+
+    /// All endpoints as a map from name to URL
+    ///
+    /// If two endpoints share a name, the first one wins.
+    #[must_use]
+    pub fn as_map(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        for endpoint in &self.endpoints {
+            map.entry(endpoint.name.clone())
+                .or_insert_with(|| endpoint.url.clone());
+        }
+        map
+    }
+
+    fn http_endpoints(&self) -> impl Iterator<Item = &RpcEndpoint> {
+        self.endpoints.iter().filter(|e| !e.is_websocket())
+    }
+}
+
+impl RpcEndpoint {
+    /// Whether the URL uses the `ws` or `wss` scheme
+    #[must_use]
+    pub fn is_websocket(&self) -> bool {
+        let url = self.url.to_ascii_lowercase();
+        url.starts_with("ws://") || url.starts_with("wss://")
+    }
 }
 
 /// Response when creating a VNet
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreateVNetResponse {
     /// The created VNet
     #[serde(flatten)]
@@ -255,7 +446,7 @@ pub struct CreateVNetResponse {
 // No wrapper type needed.
 
 /// Query parameters for listing VNets
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
 pub struct ListVNetsQuery {
     /// Filter by slug (partial match)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -299,7 +490,8 @@ impl ListVNetsQuery {
 }
 
 /// Request to delete multiple VNets
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeleteVNetsRequest {
     /// List of VNet IDs to delete
     pub vnet_ids: Vec<String>,
@@ -320,7 +512,8 @@ impl DeleteVNetsRequest {
 }
 
 /// Request to fork a VNet
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ForkVNetRequest {
     /// ID of the source VNet to fork from
     #[serde(rename = "vnet_id")]
@@ -332,11 +525,57 @@ pub struct ForkVNetRequest {
     /// Display name for the forked VNet
     pub display_name: String,
 
-    /// Block number to fork from (on the source VNet)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Block number to fork from on the source VNet (serialized as
+    /// `"latest"` when unset)
+    #[serde(
+        default,
+        serialize_with = "serialize_block_or_latest",
+        deserialize_with = "deserialize_block_or_latest"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "BlockOrLatestSchema"))]
     pub block_number: Option<u64>,
 }
 
+/// Schema for [`ForkVNetRequest::block_number`]: a number or `"latest"`
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+enum BlockOrLatestSchema {
+    Number(u64),
+    Latest(String),
+}
+
+fn serialize_block_or_latest<S: serde::Serializer>(
+    block: &Option<u64>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match block {
+        Some(block) => serializer.serialize_u64(*block),
+        None => serializer.serialize_str("latest"),
+    }
+}
+
+fn deserialize_block_or_latest<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BlockOrTag {
+        Number(u64),
+        Tag(String),
+    }
+
+    match Option::<BlockOrTag>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(BlockOrTag::Number(block)) => Ok(Some(block)),
+        Some(BlockOrTag::Tag(tag)) if tag == "latest" => Ok(None),
+        Some(BlockOrTag::Tag(tag)) => Err(serde::de::Error::custom(format!(
+            "invalid block_number: {tag}. Expected: a number or \"latest\""
+        ))),
+    }
+}
+
 impl ForkVNetRequest {
     /// Create a fork request
     pub fn new(
@@ -358,10 +597,17 @@ impl ForkVNetRequest {
         self.block_number = Some(block);
         self
     }
+
+    /// Fork from the source VNet's latest block (the default)
+    #[must_use]
+    pub fn latest(mut self) -> Self {
+        self.block_number = None;
+        self
+    }
 }
 
 /// Transaction on a VNet
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VNetTransaction {
     /// Unique transaction ID
     #[serde(default)]
@@ -411,6 +657,14 @@ pub struct VNetTransaction {
     #[serde(default)]
     pub status: Option<String>,
 
+    /// Reason the transaction failed
+    #[serde(default)]
+    pub error_reason: Option<String>,
+
+    /// Transaction signature
+    #[serde(default)]
+    pub signature: Option<String>,
+
     /// Transaction input data
     #[serde(default)]
     pub input: Option<String>,
@@ -448,11 +702,11 @@ pub struct VNetTransaction {
     pub rpc_method: Option<String>,
 
     /// State overrides applied
-    #[serde(default)]
+    #[serde(default, alias = "stateOverrides")]
     pub state_overrides: Option<serde_json::Value>,
 
     /// Block overrides applied
-    #[serde(default)]
+    #[serde(default, alias = "blockOverrides")]
     pub block_overrides: Option<serde_json::Value>,
 
     /// Transaction category
@@ -505,6 +759,25 @@ impl VNetTransaction {
         self.nonce.as_ref().and_then(|s| parse_hex_u64(s))
     }
 
+    /// Dashboard URL for the transaction
+    ///
+    /// Uses the URL from the API when present, otherwise builds one from the
+    /// client's account and project slugs. Returns `None` if neither is
+    /// available (the VNet ID or hash is missing).
+    #[must_use]
+    pub fn dashboard_url(&self, client: &crate::Client) -> Option<String> {
+        if let Some(url) = &self.dashboard_url {
+            return Some(url.clone());
+        }
+        let vnet_id = self.vnet_id.as_deref()?;
+        let hash = self.tx_hash.as_deref()?;
+        Some(client.dashboard_url(&format!(
+            "/testnet/{}/tx/{}",
+            encode_path_segment(vnet_id),
+            encode_path_segment(hash)
+        )))
+    }
+
     /// Check if transaction succeeded
     #[must_use]
     pub fn is_success(&self) -> bool {
@@ -516,24 +789,365 @@ impl VNetTransaction {
     pub fn is_failed(&self) -> bool {
         self.status.as_ref().is_some_and(|s| s == "failed")
     }
+
+    /// Check if this transaction deployed a contract (no `to` address)
+    ///
+    /// Fixture entries also lack `to`, so a transaction hash or created
+    /// contract address is required as well.
+    #[must_use]
+    pub fn is_contract_creation(&self) -> bool {
+        self.to.as_deref().is_none_or(str::is_empty)
+            && (self.tx_hash.is_some() || self.contract_address.is_some())
+    }
+
+    /// Address of the contract deployed by this transaction
+    #[must_use]
+    pub fn created_contract_address(&self) -> Option<&str> {
+        if !self.is_contract_creation() {
+            return None;
+        }
+        self.contract_address.as_deref().filter(|a| !a.is_empty())
+    }
+}
+
+/// Result of simulating a transaction on a VNet
+///
+/// The VNet endpoint reports quantities as hex strings in camelCase fields,
+/// unlike the main Simulation API's
+/// [`SimulationResponse`](crate::simulation::SimulationResponse).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VNetSimulationResponse {
+    /// Whether the simulated transaction succeeded
+    #[serde(default)]
+    pub status: bool,
+
+    /// Block number the simulation ran on (hex string)
+    #[serde(default)]
+    pub block_number: Option<String>,
+
+    /// Gas used (hex string)
+    #[serde(default)]
+    pub gas_used: Option<String>,
+
+    /// Cumulative gas used in the block (hex string)
+    #[serde(default)]
+    pub cumulative_gas_used: Option<String>,
+
+    /// Decoded logs
+    #[serde(default)]
+    pub logs: Vec<VNetSimulationLog>,
+
+    /// Logs bloom filter
+    #[serde(default)]
+    pub logs_bloom: Option<String>,
+
+    /// Flat call trace, root call first
+    #[serde(default)]
+    pub trace: Vec<VNetTraceCall>,
+
+    /// Transaction type (hex string)
+    #[serde(default, rename = "type")]
+    pub tx_type: Option<String>,
+}
+
+impl VNetSimulationResponse {
+    /// Parse gas_used from hex string to u64
+    #[must_use]
+    pub fn gas_used_as_u64(&self) -> Option<u64> {
+        self.gas_used.as_ref().and_then(|s| parse_hex_u64(s))
+    }
+
+    /// Parse block_number from hex string to u64
+    #[must_use]
+    pub fn block_number_as_u64(&self) -> Option<u64> {
+        self.block_number.as_ref().and_then(|s| parse_hex_u64(s))
+    }
+
+    /// Return data of the top-level call (hex string)
+    #[must_use]
+    pub fn output(&self) -> Option<&str> {
+        self.trace.first()?.output.as_deref()
+    }
+
+    /// Error message of the first failing call, root first
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.trace.iter().find_map(|call| call.error.as_deref())
+    }
+
+    /// Check if the simulated transaction succeeded
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.status
+    }
+}
+
+/// Decoded log from a VNet simulation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VNetSimulationLog {
+    /// Event name
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Whether the event is anonymous
+    #[serde(default)]
+    pub anonymous: bool,
+
+    /// Decoded event parameters
+    #[serde(default)]
+    pub inputs: Vec<DecodedParam>,
+
+    /// Raw log data
+    #[serde(default)]
+    pub raw: Option<RawLog>,
+}
+
+/// Raw EVM log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawLog {
+    /// Emitting contract address
+    pub address: String,
+
+    /// Log data (hex)
+    #[serde(default)]
+    pub data: String,
+
+    /// Log topics
+    #[serde(default)]
+    pub topics: Vec<String>,
+}
+
+/// Call in a VNet simulation trace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VNetTraceCall {
+    /// Call type (e.g., "CALL", "DELEGATECALL")
+    #[serde(default, rename = "type")]
+    pub call_type: Option<String>,
+
+    /// Caller address
+    #[serde(default)]
+    pub from: Option<String>,
+
+    /// Callee address
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// Decoded method name
+    #[serde(default)]
+    pub method: Option<String>,
+
+    /// Gas available (hex string)
+    #[serde(default)]
+    pub gas: Option<String>,
+
+    /// Gas used (hex string)
+    #[serde(default)]
+    pub gas_used: Option<String>,
+
+    /// Value transferred (hex string)
+    #[serde(default)]
+    pub value: Option<String>,
+
+    /// Calldata
+    #[serde(default)]
+    pub input: Option<String>,
+
+    /// Return data
+    #[serde(default)]
+    pub output: Option<String>,
+
+    /// Error message, if the call reverted
+    #[serde(default, alias = "errorMessage")]
+    pub error: Option<String>,
+
+    /// Decoded call arguments
+    #[serde(default)]
+    pub decoded_input: Vec<DecodedParam>,
+
+    /// Decoded return values
+    #[serde(default)]
+    pub decoded_output: Vec<DecodedParam>,
+
+    /// Number of direct subcalls
+    #[serde(default)]
+    pub subtraces: u64,
+
+    /// Position in the call tree
+    #[serde(default)]
+    pub trace_address: Vec<u64>,
+}
+
+/// Trace of a transaction already mined on a VNet
+///
+/// Returned by `tenderly_traceTransaction`; shares its log and call shapes
+/// with [`VNetSimulationResponse`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceResponse {
+    /// Whether the transaction succeeded
+    #[serde(default)]
+    pub status: Option<bool>,
+
+    /// Block number the transaction was mined in (hex string)
+    #[serde(default)]
+    pub block_number: Option<String>,
+
+    /// Gas used (hex string)
+    #[serde(default)]
+    pub gas_used: Option<String>,
+
+    /// Decoded logs
+    #[serde(default)]
+    pub logs: Vec<VNetSimulationLog>,
+
+    /// Flat call trace, root call first
+    #[serde(default)]
+    pub trace: Vec<VNetTraceCall>,
+
+    /// Address names used by [`pretty`](Self::pretty), keyed by lowercase address
+    #[serde(skip)]
+    pub address_book: HashMap<String, String>,
+}
+
+impl TraceResponse {
+    /// Parse gas_used from hex string to u64
+    #[must_use]
+    pub fn gas_used_as_u64(&self) -> Option<u64> {
+        self.gas_used.as_ref().and_then(|s| parse_hex_u64(s))
+    }
+
+    /// Return data of the top-level call (hex string)
+    #[must_use]
+    pub fn output(&self) -> Option<&str> {
+        self.trace.first()?.output.as_deref()
+    }
+
+    /// Error message of the first failing call, root first
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.trace.iter().find_map(|call| call.error.as_deref())
+    }
+
+    /// Attach address names for [`pretty`](Self::pretty)
+    #[must_use]
+    pub fn with_address_book(mut self, book: HashMap<String, String>) -> Self {
+        self.address_book.extend(
+            book.into_iter()
+                .map(|(address, name)| (address.to_ascii_lowercase(), name)),
+        );
+        self
+    }
+
+    /// Name of `address` in the address book, matched case-insensitively
+    #[must_use]
+    pub fn label_for(&self, address: &str) -> Option<&str> {
+        self.address_book
+            .get(&address.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Render the call tree, one call per line, with addresses replaced by
+    /// their names
+    ///
+    /// ```text
+    /// CALL Alice -> USDC.transfer(to=Bob, amount=100)
+    ///   DELEGATECALL USDC -> 0x43506849d7c04f9138d1a2050bbf3a0c054402dd.transfer(to=Bob, amount=100)
+    /// ```
+    #[must_use]
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        for call in &self.trace {
+            let name = |address: Option<&str>| {
+                address.map_or_else(
+                    || "?".to_string(),
+                    |a| self.label_for(a).unwrap_or(a).to_string(),
+                )
+            };
+            let args: Vec<String> = call
+                .decoded_input
+                .iter()
+                .map(|param| {
+                    let value = match param.value_str() {
+                        Some(v) => name(Some(v)),
+                        None => param.value.to_string(),
+                    };
+                    if param.name.is_empty() {
+                        value
+                    } else {
+                        format!("{}={}", param.name, value)
+                    }
+                })
+                .collect();
+            out.push_str(&"  ".repeat(call.trace_address.len()));
+            out.push_str(call.call_type.as_deref().unwrap_or("CALL"));
+            out.push(' ');
+            out.push_str(&name(call.from.as_deref()));
+            out.push_str(" -> ");
+            out.push_str(&name(call.to.as_deref()));
+            if let Some(method) = &call.method {
+                out.push_str(&format!(".{}({})", method, args.join(", ")));
+            }
+            if let Some(error) = &call.error {
+                out.push_str(&format!(" [reverted: {}]", error));
+            }
+            out.push('\n');
+        }
+        out
+    }
 }
 
-/// Parse a hex string (with or without 0x prefix) to u64
-fn parse_hex_u64(s: &str) -> Option<u64> {
-    let s = s.strip_prefix("0x").unwrap_or(s);
-    u64::from_str_radix(s, 16).ok()
+/// Transactions returned by the list endpoint
+///
+/// The endpoint returns a bare array, but some deployments wrap it as
+/// `{"transactions": [...]}`; both shapes are accepted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct ListVNetTransactionsResponse(pub Vec<VNetTransaction>);
+
+impl<'de> Deserialize<'de> for ListVNetTransactionsResponse {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Shape {
+            Bare(Vec<VNetTransaction>),
+            Wrapped {
+                #[serde(alias = "data")]
+                transactions: Vec<VNetTransaction>,
+            },
+        }
+
+        Ok(Self(match Shape::deserialize(deserializer)? {
+            Shape::Bare(transactions) | Shape::Wrapped { transactions } => transactions,
+        }))
+    }
+}
+
+impl From<ListVNetTransactionsResponse> for Vec<VNetTransaction> {
+    fn from(response: ListVNetTransactionsResponse) -> Self {
+        response.0
+    }
 }
 
 /// Query parameters for listing VNet transactions
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
 pub struct ListVNetTransactionsQuery {
     /// Filter by address (sender or recipient)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
 
-    /// Filter by status ("success" or "failed")
+    /// Filter by status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TxStatus>,
+
+    /// Filter by category (read or write)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<TxCategory>,
+
+    /// Filter by kind (e.g., "blockchain", "fixture")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
 
     /// Page number
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -544,6 +1158,103 @@ pub struct ListVNetTransactionsQuery {
     pub per_page: Option<u32>,
 }
 
+/// VNet transaction status filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TxStatus {
+    /// Executed successfully
+    Success,
+    /// Reverted or failed
+    Failed,
+}
+
+impl TxStatus {
+    /// Get the string representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl From<bool> for TxStatus {
+    /// `true` maps to [`TxStatus::Success`], `false` to [`TxStatus::Failed`]
+    fn from(success: bool) -> Self {
+        if success {
+            Self::Success
+        } else {
+            Self::Failed
+        }
+    }
+}
+
+impl std::fmt::Display for TxStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for TxStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "success" => Ok(Self::Success),
+            "failed" => Ok(Self::Failed),
+            _ => Err(format!(
+                "Invalid transaction status: {}. Expected: success or failed",
+                s
+            )),
+        }
+    }
+}
+
+/// VNet transaction category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TxCategory {
+    /// Calls that don't change state (e.g., `eth_call`)
+    Read,
+    /// State-changing transactions
+    Write,
+}
+
+impl TxCategory {
+    /// Get the string representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
+}
+
+impl std::fmt::Display for TxCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for TxCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "read" => Ok(Self::Read),
+            "write" => Ok(Self::Write),
+            _ => Err(format!(
+                "Invalid transaction category: {}. Expected: read or write",
+                s
+            )),
+        }
+    }
+}
+
 impl ListVNetTransactionsQuery {
     /// Create a new query
     pub fn new() -> Self {
@@ -557,9 +1268,9 @@ impl ListVNetTransactionsQuery {
         self
     }
 
-    /// Filter by status ("success" or "failed")
+    /// Filter by status (a [`TxStatus`] or `bool`, where `true` is success)
     #[must_use]
-    pub fn status(mut self, status: impl Into<String>) -> Self {
+    pub fn status(mut self, status: impl Into<TxStatus>) -> Self {
         self.status = Some(status.into());
         self
     }
@@ -567,14 +1278,28 @@ impl ListVNetTransactionsQuery {
     /// Filter for successful transactions
     #[must_use]
     pub fn success(mut self) -> Self {
-        self.status = Some("success".to_string());
+        self.status = Some(TxStatus::Success);
         self
     }
 
     /// Filter for failed transactions
     #[must_use]
     pub fn failed(mut self) -> Self {
-        self.status = Some("failed".to_string());
+        self.status = Some(TxStatus::Failed);
+        self
+    }
+
+    /// Filter by category
+    #[must_use]
+    pub fn category(mut self, category: TxCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Filter by kind (e.g., "blockchain", "fixture")
+    #[must_use]
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = Some(kind.into());
         self
     }
 
@@ -594,7 +1319,8 @@ impl ListVNetTransactionsQuery {
 }
 
 /// Request to simulate a transaction on a VNet
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VNetSimulationRequest {
     /// Sender address
     pub from: String,
@@ -632,6 +1358,39 @@ pub struct VNetSimulationRequest {
     /// Nonce
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<u64>,
+
+    /// Expected chain ID, checked against the VNet's before simulating
+    ///
+    /// Not sent to the API.
+    #[serde(skip)]
+    pub network_id: Option<String>,
+
+    /// Skip the chain ID check in [`VNetsApi::simulate`](super::VNetsApi::simulate)
+    #[serde(skip)]
+    pub allow_chain_mismatch: bool,
+}
+
+impl From<&crate::simulation::SimulationRequest> for VNetSimulationRequest {
+    /// Maps the transaction fields (`from`, `to`, `input`, `value`, `gas`,
+    /// fees, type and nonce) and the network ID, which is checked against
+    /// the VNet's chain. State/block overrides, access list and save
+    /// settings have no counterpart and are dropped.
+    fn from(request: &crate::simulation::SimulationRequest) -> Self {
+        Self {
+            from: request.from.clone(),
+            to: request.to.clone(),
+            input: request.input.clone(),
+            value: request.value.clone(),
+            gas: request.gas,
+            gas_price: request.gas_price.clone(),
+            max_fee_per_gas: request.max_fee_per_gas.clone(),
+            max_priority_fee_per_gas: request.max_priority_fee_per_gas.clone(),
+            transaction_type: request.transaction_type,
+            nonce: request.nonce,
+            network_id: Some(request.network_id.clone()),
+            allow_chain_mismatch: false,
+        }
+    }
 }
 
 impl VNetSimulationRequest {
@@ -648,6 +1407,8 @@ impl VNetSimulationRequest {
             max_priority_fee_per_gas: None,
             transaction_type: None,
             nonce: None,
+            network_id: None,
+            allow_chain_mismatch: false,
         }
     }
 
@@ -658,6 +1419,23 @@ impl VNetSimulationRequest {
         self
     }
 
+    /// Set the expected chain ID
+    ///
+    /// [`VNetsApi::simulate`](super::VNetsApi::simulate) fetches the VNet and
+    /// fails if its chain ID differs.
+    #[must_use]
+    pub fn network_id(mut self, id: impl Into<String>) -> Self {
+        self.network_id = Some(id.into());
+        self
+    }
+
+    /// Simulate even if the network ID differs from the VNet's chain ID
+    #[must_use]
+    pub fn allow_chain_mismatch(mut self, allow: bool) -> Self {
+        self.allow_chain_mismatch = allow;
+        self
+    }
+
     /// Set gas limit
     #[must_use]
     pub fn gas(mut self, gas: u64) -> Self {
@@ -686,22 +1464,45 @@ impl VNetSimulationRequest {
     }
 
     /// Set transaction type explicitly
+    ///
+    /// Setters that imply a type (fees, blobs, authorizations) also set it;
+    /// whichever call comes last wins.
     #[must_use]
     pub fn transaction_type(mut self, tx_type: u8) -> Self {
         self.transaction_type = Some(tx_type);
         self
     }
 
+    /// Clear the transaction type so the API infers it from the fields set
+    #[must_use]
+    pub fn clear_transaction_type(mut self) -> Self {
+        self.transaction_type = None;
+        self
+    }
+
     /// Set nonce
     #[must_use]
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.nonce = Some(nonce);
         self
     }
+
+    /// Copy of the request with `from`/`to` lowercased and `0x`-prefixed
+    ///
+    /// See [`SimulationRequest::normalized`](crate::simulation::SimulationRequest::normalized).
+    pub fn normalized(&self, strict: bool) -> crate::error::Result<Self> {
+        let mut request = self.clone();
+        request.from = parse_address("from", &self.from, strict)?;
+        if !self.to.is_empty() {
+            request.to = parse_address("to", &self.to, strict)?;
+        }
+        Ok(request)
+    }
 }
 
 /// Request to update a Virtual TestNet
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateVNetRequest {
     /// New display name
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -759,7 +1560,8 @@ impl UpdateVNetRequest {
 }
 
 /// Request to send a transaction on a Virtual TestNet
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SendVNetTransactionRequest {
     /// Sender address
     pub from: String,
@@ -794,10 +1596,32 @@ pub struct SendVNetTransactionRequest {
     /// Access list (EIP-2930)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub access_list: Option<Vec<AccessListItem>>,
+
+    /// Max fee per blob gas in wei (EIP-4844)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_blob_gas: Option<String>,
+
+    /// Versioned hashes of the blobs carried by the transaction (EIP-4844)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_versioned_hashes: Option<Vec<String>>,
+
+    /// Raw blob data as `0x`-prefixed hex (EIP-4844)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blobs: Option<Vec<String>>,
+
+    /// Signed delegations to contract code (EIP-7702)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_list: Option<Vec<crate::simulation::Authorization>>,
+
+    /// Transaction type (0 = legacy, 1 = access list, 2 = EIP-1559, 3 = blob,
+    /// 4 = set code)
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub transaction_type: Option<u8>,
 }
 
 /// Access list item for EIP-2930 transactions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AccessListItem {
     /// Address being accessed
     pub address: String,
@@ -820,6 +1644,11 @@ impl SendVNetTransactionRequest {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             access_list: None,
+            max_fee_per_blob_gas: None,
+            blob_versioned_hashes: None,
+            blobs: None,
+            authorization_list: None,
+            transaction_type: None,
         }
     }
 
@@ -839,6 +1668,11 @@ impl SendVNetTransactionRequest {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             access_list: None,
+            max_fee_per_blob_gas: None,
+            blob_versioned_hashes: None,
+            blobs: None,
+            authorization_list: None,
+            transaction_type: None,
         }
     }
 
@@ -877,12 +1711,161 @@ impl SendVNetTransactionRequest {
         self
     }
 
+    /// Set EIP-1559 fees from a gas price bucket
+    ///
+    /// Fees are encoded as hex strings.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let estimate = client.gas().gas_price("1").await?;
+    /// let tx = SendVNetTransactionRequest::new(from, to, data)
+    ///     .gas_fees(estimate.high());
+    /// ```
+    #[must_use]
+    pub fn gas_fees(mut self, price: &crate::gas::GasPrice) -> Self {
+        self.max_fee_per_gas = Some(format!("0x{:x}", price.max_fee_per_gas));
+        self.max_priority_fee_per_gas = Some(format!("0x{:x}", price.max_priority_fee_per_gas));
+        self
+    }
+
+    /// Set EIP-1559 fees from a base fee and priority fee
+    ///
+    /// Sets `max_fee_per_gas` to `base_fee * 2 + priority` (see
+    /// [`BASE_FEE_MULTIPLIER`](crate::gas::BASE_FEE_MULTIPLIER)) and
+    /// transaction type 2, keeping type 3 or 4 if blob or
+    /// authorization fields are set.
+    #[must_use]
+    pub fn eip1559_fees(self, base_fee: u128, priority: u128) -> Self {
+        self.eip1559_fees_with_multiplier(base_fee, priority, crate::gas::BASE_FEE_MULTIPLIER)
+    }
+
+    /// Set EIP-1559 fees with a custom base fee multiplier
+    #[must_use]
+    pub fn eip1559_fees_with_multiplier(
+        mut self,
+        base_fee: u128,
+        priority: u128,
+        multiplier: u128,
+    ) -> Self {
+        let price =
+            crate::gas::GasPrice::from_base_fee_with_multiplier(base_fee, priority, multiplier);
+        if !matches!(self.transaction_type, Some(3 | 4)) {
+            self.transaction_type = Some(2);
+        }
+        self.gas_fees(&price)
+    }
+
     /// Set access list (EIP-2930)
     #[must_use]
     pub fn access_list(mut self, list: Vec<AccessListItem>) -> Self {
         self.access_list = Some(list);
         self
     }
+
+    /// Set max fee per blob gas (EIP-4844)
+    ///
+    /// Automatically sets transaction type to 3.
+    #[must_use]
+    pub fn max_fee_per_blob_gas(mut self, fee: impl Into<String>) -> Self {
+        self.max_fee_per_blob_gas = Some(fee.into());
+        self.transaction_type = Some(3);
+        self
+    }
+
+    /// Set blob versioned hashes (EIP-4844)
+    ///
+    /// Automatically sets transaction type to 3.
+    #[must_use]
+    pub fn blob_versioned_hashes(mut self, hashes: Vec<String>) -> Self {
+        self.blob_versioned_hashes = Some(hashes);
+        self.transaction_type = Some(3);
+        self
+    }
+
+    /// Set blob data (EIP-4844)
+    ///
+    /// Each blob must already be `0x`-prefixed hex; it is sent as-is.
+    /// Automatically sets transaction type to 3.
+    #[must_use]
+    pub fn blobs(mut self, blobs: Vec<String>) -> Self {
+        self.blobs = Some(blobs);
+        self.transaction_type = Some(3);
+        self
+    }
+
+    /// Set the authorization list (EIP-7702)
+    ///
+    /// Automatically sets transaction type to 4.
+    #[must_use]
+    pub fn authorization_list(mut self, list: Vec<crate::simulation::Authorization>) -> Self {
+        self.authorization_list = Some(list);
+        self.transaction_type = Some(4);
+        self
+    }
+
+    /// Set transaction type explicitly
+    ///
+    /// Setters that imply a type (fees, blobs, authorizations) also set it;
+    /// whichever call comes last wins.
+    #[must_use]
+    pub fn transaction_type(mut self, tx_type: u8) -> Self {
+        self.transaction_type = Some(tx_type);
+        self
+    }
+
+    /// Clear the transaction type so the API infers it from the fields set
+    #[must_use]
+    pub fn clear_transaction_type(mut self) -> Self {
+        self.transaction_type = None;
+        self
+    }
+
+    /// Copy of the request with `from`/`to` lowercased and `0x`-prefixed
+    ///
+    /// See [`SimulationRequest::normalized`](crate::simulation::SimulationRequest::normalized).
+    pub fn normalized(&self, strict: bool) -> crate::error::Result<Self> {
+        let mut request = self.clone();
+        request.from = parse_address("from", &self.from, strict)?;
+        if !self.to.is_empty() {
+            request.to = parse_address("to", &self.to, strict)?;
+        }
+        Ok(request)
+    }
+
+    /// Build a simulation request for this transaction
+    ///
+    /// The reverse of
+    /// [`SimulationRequest::to_vnet_send`](crate::simulation::SimulationRequest::to_vnet_send).
+    /// The network defaults to mainnet; set it with
+    /// [`network_id`](crate::simulation::SimulationRequest::network_id).
+    /// Blob data has no counterpart and is dropped.
+    #[must_use]
+    pub fn to_simulation(&self) -> crate::simulation::SimulationRequest {
+        let mut request = crate::simulation::SimulationRequest::new(
+            self.from.clone(),
+            self.to.clone(),
+            self.input.clone().unwrap_or_else(|| "0x".to_string()),
+        );
+        request.value = self.value.clone();
+        request.gas = self.gas;
+        request.gas_price = self.gas_price.clone();
+        request.max_fee_per_gas = self.max_fee_per_gas.clone();
+        request.max_priority_fee_per_gas = self.max_priority_fee_per_gas.clone();
+        request.access_list = self.access_list.as_ref().map(|list| {
+            list.iter()
+                .map(|item| crate::simulation::AccessListEntry {
+                    address: item.address.clone(),
+                    storage_keys: item.storage_keys.clone(),
+                })
+                .collect()
+        });
+        request.max_fee_per_blob_gas = self.max_fee_per_blob_gas.clone();
+        request.blob_versioned_hashes = self.blob_versioned_hashes.clone();
+        request.authorization_list = self.authorization_list.clone();
+        request.transaction_type = self.transaction_type;
+        request
+    }
 }
 
 #[cfg(test)]
@@ -987,6 +1970,37 @@ mod tests {
         assert!(tx.is_success());
     }
 
+    #[test]
+    fn test_vnet_transaction_contract_creation() {
+        let json = r#"{
+            "tx_hash": "0xdeploy",
+            "from": "0x1234567890abcdef1234567890abcdef12345678",
+            "to": null,
+            "input": "0x6080604052348015600f57600080fd5b50",
+            "status": "success",
+            "kind": "blockchain",
+            "contract_address": "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+        }"#;
+
+        let tx: VNetTransaction = serde_json::from_str(json).unwrap();
+        assert!(tx.is_contract_creation());
+        assert_eq!(
+            tx.created_contract_address(),
+            Some("0x5fbdb2315678afecb367f032d93f642f64180aa3")
+        );
+
+        let call: VNetTransaction = serde_json::from_str(
+            r#"{"tx_hash": "0x1", "to": "0xabcdef1234567890abcdef1234567890abcdef12", "contract_address": "0xabcdef1234567890abcdef1234567890abcdef12"}"#,
+        )
+        .unwrap();
+        assert!(!call.is_contract_creation());
+        assert_eq!(call.created_contract_address(), None);
+
+        let fixture: VNetTransaction =
+            serde_json::from_str(r#"{"id": "fixture-1", "kind": "fixture"}"#).unwrap();
+        assert!(!fixture.is_contract_creation());
+    }
+
     #[test]
     fn test_vnet_transactions_array_deserialization() {
         // API returns a raw array, not a wrapped object
@@ -1008,16 +2022,23 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_hex_u64() {
-        assert_eq!(parse_hex_u64("0x0"), Some(0));
-        assert_eq!(parse_hex_u64("0x1"), Some(1));
-        assert_eq!(parse_hex_u64("0xa"), Some(10));
-        assert_eq!(parse_hex_u64("0x10"), Some(16));
-        assert_eq!(parse_hex_u64("0x5208"), Some(21_000));
-        assert_eq!(parse_hex_u64("0x170abab"), Some(24_161_195));
-        // Without 0x prefix
-        assert_eq!(parse_hex_u64("5208"), Some(21_000));
-        assert_eq!(parse_hex_u64("170abab"), Some(24_161_195));
+    fn test_list_vnet_transactions_response_shapes() {
+        let bare: ListVNetTransactionsResponse =
+            serde_json::from_str(r#"[{"tx_hash": "0x111"}, {"tx_hash": "0x222"}]"#).unwrap();
+        assert_eq!(bare.0.len(), 2);
+
+        let wrapped: ListVNetTransactionsResponse =
+            serde_json::from_str(r#"{"transactions": [{"tx_hash": "0x111"}]}"#).unwrap();
+        assert_eq!(wrapped.0[0].tx_hash.as_deref(), Some("0x111"));
+
+        let empty: ListVNetTransactionsResponse = serde_json::from_str(r#"{"data": []}"#).unwrap();
+        assert!(empty.0.is_empty());
+
+        // An object without a transaction list is not an empty page
+        assert!(serde_json::from_str::<ListVNetTransactionsResponse>(
+            r#"{"error": {"message": "not found"}}"#
+        )
+        .is_err());
     }
 
     #[test]
@@ -1029,11 +2050,409 @@ mod tests {
             .per_page(50);
 
         assert_eq!(query.address, Some("0x1234".to_string()));
-        assert_eq!(query.status, Some("success".to_string()));
+        assert_eq!(query.status, Some(TxStatus::Success));
         assert_eq!(query.page, Some(2));
         assert_eq!(query.per_page, Some(50));
 
         let failed_query = ListVNetTransactionsQuery::new().failed();
-        assert_eq!(failed_query.status, Some("failed".to_string()));
+        assert_eq!(failed_query.status, Some(TxStatus::Failed));
+    }
+
+    #[test]
+    fn test_list_vnet_transactions_query_status_string() {
+        let query = ListVNetTransactionsQuery::new().failed();
+        assert_eq!(query_string(&query), "status=failed");
+
+        let query = ListVNetTransactionsQuery::new().status(true);
+        assert_eq!(query_string(&query), "status=success");
+    }
+
+    fn query_string(query: &ListVNetTransactionsQuery) -> String {
+        let request = reqwest::Client::new()
+            .get("http://localhost/")
+            .query(query)
+            .build()
+            .unwrap();
+        request.url().query().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn test_list_vnet_transactions_query_filters() {
+        let query = ListVNetTransactionsQuery::new()
+            .category(TxCategory::Write)
+            .kind("blockchain");
+        assert_eq!(query_string(&query), "category=write&kind=blockchain");
+
+        let query = ListVNetTransactionsQuery::new().page(1);
+        let qs = query_string(&query);
+        assert!(!qs.contains("category") && !qs.contains("kind"), "{}", qs);
+
+        assert_eq!("READ".parse::<TxCategory>().unwrap(), TxCategory::Read);
+        assert!("other".parse::<TxCategory>().is_err());
+    }
+
+    #[test]
+    fn test_vnet_status_deserialization() {
+        for (raw, expected) in [
+            ("running", VNetStatus::Running),
+            ("STOPPED", VNetStatus::Stopped),
+            ("creating", VNetStatus::Creating),
+            ("failed", VNetStatus::Failed),
+        ] {
+            let status: VNetStatus = serde_json::from_value(serde_json::json!(raw)).unwrap();
+            assert_eq!(status, expected);
+        }
+
+        let status: VNetStatus = serde_json::from_str(r#""hibernating""#).unwrap();
+        assert_eq!(status, VNetStatus::Unknown("hibernating".to_string()));
+        assert_eq!(status.to_string(), "hibernating");
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""hibernating""#);
+    }
+
+    #[test]
+    fn test_vnet_is_running() {
+        let json = r#"{
+            "id": "vnet-1",
+            "slug": "test",
+            "display_name": "Test",
+            "fork_config": {"network_id": 1},
+            "virtual_network_config": {"chain_config": {"chain_id": 1}},
+            "status": "running"
+        }"#;
+
+        let vnet: VNet = serde_json::from_str(json).unwrap();
+        assert_eq!(vnet.status, Some(VNetStatus::Running));
+        assert!(vnet.is_running());
+    }
+
+    #[test]
+    fn test_vnet_equality() {
+        let json = r#"{
+            "id": "vnet-1",
+            "slug": "test",
+            "display_name": "Test",
+            "fork_config": {"network_id": 1, "block_number": "0x170abab"},
+            "virtual_network_config": {"chain_config": {"chain_id": 1}},
+            "status": "running"
+        }"#;
+
+        let a: VNet = serde_json::from_str(json).unwrap();
+        let b: VNet = serde_json::from_str(json).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, a.clone());
+
+        let mut c = b.clone();
+        c.status = Some(VNetStatus::Stopped);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_dashboard_urls() {
+        let client = crate::Client::new(crate::Config::new("key", "acc", "proj")).unwrap();
+
+        let mut vnet: VNet = serde_json::from_value(serde_json::json!({
+            "id": "vnet1",
+            "slug": "test",
+            "display_name": "Test",
+            "fork_config": {"network_id": 1},
+            "virtual_network_config": {"chain_config": {"chain_id": 1}}
+        }))
+        .unwrap();
+        assert_eq!(
+            vnet.dashboard_url(&client),
+            "https://dashboard.tenderly.co/acc/proj/testnet/vnet1"
+        );
+        vnet.dashboard_url = Some("https://custom.example/vnet1".into());
+        assert_eq!(vnet.dashboard_url(&client), "https://custom.example/vnet1");
+
+        let mut tx: VNetTransaction = serde_json::from_value(serde_json::json!({
+            "vnet_id": "vnet1",
+            "tx_hash": "0xabc"
+        }))
+        .unwrap();
+        assert_eq!(
+            tx.dashboard_url(&client).as_deref(),
+            Some("https://dashboard.tenderly.co/acc/proj/testnet/vnet1/tx/0xabc")
+        );
+        tx.dashboard_url = Some("https://custom.example/tx".into());
+        assert_eq!(
+            tx.dashboard_url(&client).as_deref(),
+            Some("https://custom.example/tx")
+        );
+
+        tx.dashboard_url = None;
+        tx.vnet_id = None;
+        assert!(tx.dashboard_url(&client).is_none());
+    }
+
+    #[test]
+    fn test_send_transaction_eip1559_fees() {
+        let tx = SendVNetTransactionRequest::new("0x1", "0x2", "0x").eip1559_fees(30, 2);
+        assert_eq!(tx.max_fee_per_gas.as_deref(), Some("0x3e")); // 30 * 2 + 2
+        assert_eq!(tx.max_priority_fee_per_gas.as_deref(), Some("0x2"));
+        assert_eq!(tx.transaction_type, Some(2));
+
+        let tx = SendVNetTransactionRequest::new("0x1", "0x2", "0x")
+            .blobs(vec![])
+            .eip1559_fees_with_multiplier(30, 2, 3);
+        assert_eq!(tx.max_fee_per_gas.as_deref(), Some("0x5c")); // 30 * 3 + 2
+        assert_eq!(tx.transaction_type, Some(3));
+    }
+
+    #[test]
+    fn test_send_transaction_type_call_order() {
+        let base = SendVNetTransactionRequest::new("0x1", "0x2", "0x");
+        assert_eq!(
+            base.clone()
+                .eip1559_fees(30, 2)
+                .transaction_type(0)
+                .transaction_type,
+            Some(0)
+        );
+        assert_eq!(
+            base.clone()
+                .transaction_type(0)
+                .eip1559_fees(30, 2)
+                .transaction_type,
+            Some(2)
+        );
+        assert_eq!(
+            base.clone()
+                .eip1559_fees(30, 2)
+                .clear_transaction_type()
+                .transaction_type,
+            None
+        );
+        assert_eq!(
+            VNetSimulationRequest::new("0x1", "0x2", "0x")
+                .transaction_type(2)
+                .clear_transaction_type()
+                .transaction_type,
+            None
+        );
+    }
+
+    #[test]
+    fn test_send_transaction_authorization_list() {
+        let authorization = crate::simulation::Authorization::new(
+            8453,
+            "0x3333333333333333333333333333333333333333",
+            0,
+            0,
+            "0xaa",
+            "0xbb",
+        );
+        let request = SendVNetTransactionRequest::new("0x1234", "0x1234", "0x")
+            .authorization_list(vec![authorization])
+            .eip1559_fees(30, 2);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], 4);
+        assert_eq!(json["authorization_list"][0]["chain_id"], 8453);
+        assert_eq!(json["authorization_list"][0]["y_parity"], 0);
+        assert_eq!(json["authorization_list"][0]["r"], "0xaa");
+        assert_eq!(
+            request.to_simulation().authorization_list,
+            request.authorization_list
+        );
+    }
+
+    #[test]
+    fn test_send_transaction_blob_fields() {
+        let hash = format!("0x01{}", "ab".repeat(31));
+        let request = SendVNetTransactionRequest::new("0x1234", "0x5678", "0x")
+            .max_fee_per_blob_gas("0x3b9aca00")
+            .blob_versioned_hashes(vec![hash.clone()])
+            .blobs(vec!["0xdeadbeef".into()]);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], 3);
+        assert_eq!(json["max_fee_per_blob_gas"], "0x3b9aca00");
+        assert_eq!(json["blob_versioned_hashes"], serde_json::json!([hash]));
+        assert_eq!(json["blobs"], serde_json::json!(["0xdeadbeef"]));
+
+        // Blob fields are omitted from plain transactions
+        let plain = serde_json::to_value(SendVNetTransactionRequest::transfer(
+            "0x1234", "0x5678", "0x1",
+        ))
+        .unwrap();
+        assert!(plain.get("type").is_none());
+        assert!(plain.get("max_fee_per_blob_gas").is_none());
+        assert!(plain.get("blob_versioned_hashes").is_none());
+        assert!(plain.get("blobs").is_none());
+    }
+
+    #[test]
+    fn test_vnet_simulation_response_deserialization() {
+        let json = r#"{
+            "blockNumber": "0xfc497b",
+            "cumulativeGasUsed": "0x0",
+            "gasUsed": "0xb412",
+            "logs": [{
+                "anonymous": false,
+                "inputs": [
+                    {"name": "src", "type": "address", "value": "0xe2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2"},
+                    {"name": "wad", "type": "uint256", "value": "299"}
+                ],
+                "name": "Approval",
+                "raw": {
+                    "address": "0x6b175474e89094c44da98b954eedeac495271d0f",
+                    "data": "0x000000000000000000000000000000000000000000000000000000000000012b",
+                    "topics": ["0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"]
+                }
+            }],
+            "status": true,
+            "trace": [{
+                "decodedOutput": [{"name": "", "type": "bool", "value": true}],
+                "from": "0xe2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2",
+                "gas": "0x79bdb0",
+                "gasUsed": "0x5fc2",
+                "method": "approve",
+                "output": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "subtraces": 0,
+                "to": "0x6b175474e89094c44da98b954eedeac495271d0f",
+                "traceAddress": [0],
+                "type": "CALL",
+                "value": "0x0"
+            }],
+            "type": "0x0"
+        }"#;
+
+        let result: VNetSimulationResponse = serde_json::from_str(json).unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.gas_used_as_u64(), Some(0xb412));
+        assert_eq!(result.block_number_as_u64(), Some(0xfc497b));
+        assert_eq!(
+            result.output(),
+            Some("0x0000000000000000000000000000000000000000000000000000000000000001")
+        );
+        assert_eq!(result.error(), None);
+        assert_eq!(result.logs[0].name.as_deref(), Some("Approval"));
+        assert_eq!(result.logs[0].inputs[1].param_type, "uint256");
+        assert_eq!(result.logs[0].raw.as_ref().unwrap().topics.len(), 1);
+        assert_eq!(result.trace[0].method.as_deref(), Some("approve"));
+        assert_eq!(result.trace[0].call_type.as_deref(), Some("CALL"));
+    }
+
+    #[test]
+    fn test_vnet_simulation_response_revert() {
+        let json = r#"{
+            "gasUsed": "0x6a4",
+            "status": false,
+            "trace": [
+                {"type": "CALL", "method": "transfer", "gasUsed": "0x6a4", "subtraces": 1,
+                 "traceAddress": [], "error": "execution reverted"},
+                {"type": "STATICCALL", "method": "balanceOf", "gasUsed": "0x100",
+                 "traceAddress": [0], "errorMessage": "insufficient balance"}
+            ]
+        }"#;
+
+        let result: VNetSimulationResponse = serde_json::from_str(json).unwrap();
+        assert!(!result.is_success());
+        assert_eq!(result.gas_used_as_u64(), Some(0x6a4));
+        assert_eq!(result.error(), Some("execution reverted"));
+        assert_eq!(
+            result.trace[1].error.as_deref(),
+            Some("insufficient balance")
+        );
+        assert!(result.logs.is_empty());
+        assert_eq!(result.output(), None);
+    }
+
+    #[test]
+    fn test_trace_pretty_uses_address_book() {
+        let trace: TraceResponse = serde_json::from_value(serde_json::json!({
+            "status": true,
+            "trace": [
+                {
+                    "type": "CALL",
+                    "from": "0x1111111111111111111111111111111111111111",
+                    "to": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                    "method": "transfer",
+                    "decodedInput": [
+                        {"name": "to", "type": "address", "value": "0x2222222222222222222222222222222222222222"},
+                        {"name": "amount", "type": "uint256", "value": "100"}
+                    ],
+                    "subtraces": 1,
+                    "traceAddress": []
+                },
+                {
+                    "type": "DELEGATECALL",
+                    "from": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "to": "0x43506849d7c04f9138d1a2050bbf3a0c054402dd",
+                    "method": "transfer",
+                    "error": "execution reverted",
+                    "traceAddress": [0]
+                }
+            ]
+        }))
+        .unwrap();
+        let trace = trace.with_address_book(HashMap::from([
+            (
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string(),
+                "USDC".to_string(),
+            ),
+            (
+                "0x1111111111111111111111111111111111111111".to_string(),
+                "Alice".to_string(),
+            ),
+            (
+                "0x2222222222222222222222222222222222222222".to_string(),
+                "Bob".to_string(),
+            ),
+        ]));
+
+        assert_eq!(
+            trace.label_for("0xA0B86991C6218B36C1D19D4A2E9EB0CE3606EB48"),
+            Some("USDC")
+        );
+        assert_eq!(
+            trace.label_for("0x43506849d7c04f9138d1a2050bbf3a0c054402dd"),
+            None
+        );
+        assert_eq!(
+            trace.pretty(),
+            "CALL Alice -> USDC.transfer(to=Bob, amount=100)\n  \
+             DELEGATECALL USDC -> 0x43506849d7c04f9138d1a2050bbf3a0c054402dd.transfer() \
+             [reverted: execution reverted]\n"
+        );
+    }
+
+    #[test]
+    fn test_vnet_rpcs_lookup() {
+        let rpcs = VNetRpcs {
+            endpoints: serde_json::from_value(serde_json::json!([
+                {"name": "Admin RPC", "url": "https://virtual.mainnet.rpc.tenderly.co/admin-key"},
+                {"name": "Public WebSocket RPC", "url": "wss://virtual.mainnet.rpc.tenderly.co/ws-key"},
+                {"name": "Public RPC", "url": "https://virtual.mainnet.rpc.tenderly.co/public-key"}
+            ]))
+            .unwrap(),
+        };
+
+        assert_eq!(
+            rpcs.admin(),
+            Some("https://virtual.mainnet.rpc.tenderly.co/admin-key")
+        );
+        // The websocket endpoint comes first but isn't the public HTTP RPC
+        assert_eq!(
+            rpcs.public(),
+            Some("https://virtual.mainnet.rpc.tenderly.co/public-key")
+        );
+        assert_eq!(
+            rpcs.websocket(),
+            Some("wss://virtual.mainnet.rpc.tenderly.co/ws-key")
+        );
+        assert_eq!(
+            rpcs.by_name("public websocket rpc"),
+            Some("wss://virtual.mainnet.rpc.tenderly.co/ws-key")
+        );
+        assert_eq!(rpcs.by_name("Public"), None);
+
+        let map = rpcs.as_map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(
+            map["Public RPC"],
+            "https://virtual.mainnet.rpc.tenderly.co/public-key"
+        );
     }
 }
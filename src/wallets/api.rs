@@ -1,22 +1,29 @@
 //! Wallet API operations
 //!
 //! Note: The Tenderly Wallets API has limited endpoints. Wallets can be added
-//! and queried on specific networks, but there is no list, update, or delete
-//! endpoint in the current API.
+//! and queried on specific networks; listing, renaming, and removal go through
+//! the contract endpoints, since wallets are stored alongside contracts.
 
 use super::types::*;
-use crate::client::{encode_path_segment, Client};
-use crate::error::Result;
+use crate::client::{encode_path_segment, ApiVersion, Client};
+use crate::contracts::RenameContractRequest;
+use crate::error::{Error, Result};
+use std::borrow::Cow;
 
 /// Wallet API client
 pub struct WalletsApi<'a> {
-    client: &'a Client,
+    client: Cow<'a, Client>,
 }
 
 impl<'a> WalletsApi<'a> {
+    /// API version every endpoint in this module is served from
+    pub const API_VERSION: ApiVersion = ApiVersion::V1;
+
     /// Create a new Wallet API client
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client: client.pinned(Self::API_VERSION),
+        }
     }
 
     /// List all wallets in the project
@@ -53,6 +60,9 @@ impl<'a> WalletsApi<'a> {
     /// Returns an error if no networks have been specified. Use `.network()` to add
     /// at least one network to monitor the wallet on.
     ///
+    /// If the wallet is already part of the project, the existing entries are
+    /// fetched and returned instead of an error.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -65,11 +75,26 @@ impl<'a> WalletsApi<'a> {
     /// ```
     pub async fn add(&self, request: &AddWalletRequest) -> Result<AddWalletResponse> {
         // Validate that at least one network is specified
-        request
-            .validate()
-            .map_err(crate::error::Error::invalid_param)?;
+        request.validate().map_err(Error::invalid_param)?;
+
+        let response = self.client.post("/wallet", request).await;
+        match response.map_err(Error::into_already_exists) {
+            Err(e) if e.is_already_exists() => self.existing(request).await,
+            result => result,
+        }
+    }
 
-        self.client.post("/wallet", request).await
+    /// Fetch the existing entries for a wallet that was already added
+    async fn existing(&self, request: &AddWalletRequest) -> Result<AddWalletResponse> {
+        let mut contracts = Vec::with_capacity(request.network_ids.len());
+        for network_id in &request.network_ids {
+            contracts.push(self.get(&request.address, network_id).await?);
+        }
+        Ok(AddWalletResponse {
+            account_id: None,
+            account_type: Some("wallet".to_string()),
+            contracts,
+        })
     }
 
     /// Get wallet details on a specific network
@@ -95,6 +120,58 @@ impl<'a> WalletsApi<'a> {
             ))
             .await
     }
+
+    /// Rename a wallet on a specific network
+    ///
+    /// Takes `(network_id, address)` like
+    /// [`ContractsApi::rename`](crate::contracts::ContractsApi::rename).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// client.wallets()
+    ///     .rename("1", "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045", "Vitalik")
+    ///     .await?;
+    /// ```
+    pub async fn rename(
+        &self,
+        network_id: &str,
+        address: &str,
+        display_name: impl Into<String>,
+    ) -> Result<()> {
+        let request = RenameContractRequest {
+            display_name: display_name.into(),
+        };
+        self.client
+            .post_no_response(
+                &format!(
+                    "/contract/{}/{}/rename",
+                    encode_path_segment(network_id),
+                    encode_path_segment(address)
+                ),
+                &request,
+            )
+            .await
+    }
+
+    /// Remove a wallet from the project on a specific network
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// client.wallets()
+    ///     .remove("1", "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045")
+    ///     .await?;
+    /// ```
+    pub async fn remove(&self, network_id: &str, address: &str) -> Result<()> {
+        self.client
+            .delete(&format!(
+                "/contract/{}/{}",
+                encode_path_segment(network_id),
+                encode_path_segment(address)
+            ))
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -139,4 +216,99 @@ mod tests {
         assert_eq!(json["network_ids"][0], "1");
         assert_eq!(json["network_ids"][1], "137");
     }
+
+    #[test]
+    fn test_project_wallet_deserialization() {
+        let json = r#"{
+            "id": "eth:1:0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "display_name": "vitalik.eth",
+            "account_type": "wallet",
+            "account": {
+                "address": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+                "network_id": "1",
+                "balance": "0xde0b6b3a7640000",
+                "type": "wallet"
+            },
+            "tags": [{"tag": "whale"}]
+        }"#;
+
+        let wallet: ProjectWallet = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            wallet.address(),
+            Some("0xd8da6bf26964af9d7eed9e03e53415d37aa96045")
+        );
+        assert_eq!(wallet.network_id(), Some("1"));
+        assert_eq!(wallet.balance(), Some("0xde0b6b3a7640000"));
+        assert!(wallet.is_wallet());
+        assert_eq!(wallet.tags[0].tag, "whale");
+    }
+
+    #[tokio::test]
+    async fn test_rename_and_remove_take_network_first() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/account/acc/project/proj/contract/1/{address}/rename"
+            )))
+            .and(body_json(serde_json::json!({"display_name": "Treasury"})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path(format!(
+                "/account/acc/project/proj/contract/1/{address}"
+            )))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        client
+            .wallets()
+            .rename("1", address, "Treasury")
+            .await
+            .unwrap();
+        client.wallets().remove("1", address).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_add_existing_wallet_returns_entry() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/account/acc/project/proj/wallet"))
+            .respond_with(ResponseTemplate::new(409).set_body_string("wallet already exists"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/acc/project/proj/wallet/0xabcd/network/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "eth:1:0xabcd",
+                "account_type": "wallet",
+                "account": {"address": "0xabcd", "network_id": "1", "type": "wallet"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            Client::new(crate::Config::new("key", "acc", "proj").with_base_url(server.uri()))
+                .unwrap();
+        let request = AddWalletRequest::new("0xabcd").network("1");
+
+        let response = client.wallets().add(&request).await.unwrap();
+
+        assert_eq!(response.contracts.len(), 1);
+        assert_eq!(response.contracts[0].address(), Some("0xabcd"));
+    }
 }
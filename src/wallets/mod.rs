@@ -7,32 +7,33 @@
 //!
 //! ```ignore
 //! use tndrly::Client;
-//! use tndrly::wallets::{AddWalletRequest, UpdateWalletRequest};
+//! use tndrly::wallets::AddWalletRequest;
 //!
 //! let client = Client::from_env()?;
 //!
 //! // Add a wallet to monitor
+//! // Adding a wallet that is already monitored returns the existing entry
 //! let request = AddWalletRequest::new("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045")
 //!     .display_name("vitalik.eth")
-//!     .tag("whale");
+//!     .network("1");
 //!
 //! let wallet = client.wallets().add(&request).await?;
 //!
 //! // List all wallets
 //! let wallets = client.wallets().list().await?;
 //! for wallet in wallets {
-//!     println!("{}: {:?}", wallet.address, wallet.display_name);
+//!     println!("{:?}: {:?}", wallet.address(), wallet.display_name);
 //! }
 //!
-//! // Update a wallet
-//! let update = UpdateWalletRequest::new()
-//!     .display_name("Vitalik Buterin")
-//!     .tag("founder");
-//!
-//! client.wallets().update("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045", &update).await?;
+//! // Rename a wallet
+//! client.wallets()
+//!     .rename("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045", "1", "Vitalik Buterin")
+//!     .await?;
 //!
 //! // Remove a wallet
-//! client.wallets().remove("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").await?;
+//! client.wallets()
+//!     .remove("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045", "1")
+//!     .await?;
 //! ```
 
 mod api;
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// Request to add a wallet to the project
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AddWalletRequest {
     /// Wallet address
     pub address: String,
@@ -134,6 +135,9 @@ impl WalletOnNetwork {
     }
 }
 
+/// Alias for [`WalletOnNetwork`], a wallet entry in the project
+pub type ProjectWallet = WalletOnNetwork;
+
 /// Nested wallet account details from API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletAccount {
@@ -0,0 +1,41 @@
+//! Environment-based configuration
+//!
+//! Kept in its own test binary: it sets process-wide `TENDERLY_*`
+//! variables, which would race with tests running on other threads.
+
+use tndrly::{Client, Config, Error, API_BASE_URL};
+
+const VARS: [&str; 4] = [
+    "TENDERLY_ACCESS_KEY",
+    "TENDERLY_ACCOUNT",
+    "TENDERLY_PROJECT",
+    "TENDERLY_BASE_URL",
+];
+
+#[test]
+fn test_config_from_env() {
+    std::env::set_var("TENDERLY_ACCESS_KEY", "env-key");
+    std::env::set_var("TENDERLY_ACCOUNT", "env-account");
+    std::env::set_var("TENDERLY_PROJECT", "env-project");
+    std::env::set_var("TENDERLY_BASE_URL", "http://127.0.0.1:8080/");
+
+    let client = Client::from_env().unwrap();
+    assert_eq!(client.account(), "env-account");
+    assert_eq!(client.access_key(), "env-key");
+    assert_eq!(client.config().base_url(), "http://127.0.0.1:8080");
+
+    std::env::remove_var("TENDERLY_BASE_URL");
+    assert_eq!(Config::from_env().unwrap().base_url(), API_BASE_URL);
+
+    std::env::remove_var("TENDERLY_PROJECT");
+    let err = Config::from_env().unwrap_err();
+    assert!(
+        matches!(&err, Error::MissingEnv { var } if var == "TENDERLY_PROJECT"),
+        "{:?}",
+        err
+    );
+
+    for var in VARS {
+        std::env::remove_var(var);
+    }
+}
@@ -0,0 +1,38 @@
+//! Example of testing against `MockTenderly`
+//!
+//! ```bash
+//! cargo test --features testkit --test testkit
+//! ```
+
+#![cfg(feature = "testkit")]
+
+use tndrly::simulation::SimulationRequest;
+use tndrly::testkit::MockTenderly;
+
+#[tokio::test]
+async fn simulate_against_mock() {
+    let mock = MockTenderly::start().await;
+    let client = mock.client();
+
+    let request = SimulationRequest::new(
+        "0x1111111111111111111111111111111111111111",
+        "0x2222222222222222222222222222222222222222",
+        "0xa9059cbb",
+    )
+    .network_id("137");
+    let response = client.simulation().simulate(&request).await.unwrap();
+    assert!(response.simulation.status);
+    assert_eq!(
+        response.simulation.from,
+        "0x1111111111111111111111111111111111111111"
+    );
+    assert_eq!(response.simulation.network_id, "137");
+    assert_eq!(response.simulation.gas_used, 21_000);
+
+    // Swap in a revert for the next call
+    let mut reverted = serde_json::to_value(&response).unwrap();
+    reverted["simulation"]["status"] = false.into();
+    mock.simulation_response(reverted);
+    let response = client.simulation().simulate(&request).await.unwrap();
+    assert!(!response.simulation.status);
+}
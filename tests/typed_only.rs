@@ -0,0 +1,56 @@
+//! Build check for the typed-only API
+//!
+//! Only compiled without the `dynamic-json` feature:
+//!
+//! ```bash
+//! cargo test --no-default-features --features abi --test typed_only
+//! ```
+
+#![cfg(not(feature = "dynamic-json"))]
+
+use tndrly::simulation::{SimulationInfo, TransactionTrace};
+use tndrly::vnets::AdminRpc;
+use tndrly::Client;
+
+/// Type-checks the typed replacements; never awaited
+async fn typed_calls(client: &Client, admin: &AdminRpc) -> tndrly::Result<()> {
+    let _: SimulationInfo = client.simulation().info("sim").await?;
+    let _: TransactionTrace = client.simulation().trace("0xabc").await?;
+    let _: String = admin.request("eth_chainId", [(); 0]).await?;
+    let _: String = client.get_text("/simulations").await?;
+    Ok(())
+}
+
+/// Stand-in returned when `Client` has no inherent method of the same name
+struct Absent;
+
+/// Inherent methods win over trait methods, so these only resolve here
+/// while the untyped `Client` methods are compiled out
+trait UntypedFallback {
+    fn get_raw(&self, path: &str) -> Absent;
+    fn post_raw(&self, path: &str, body: &()) -> Absent;
+}
+
+impl UntypedFallback for Client {
+    fn get_raw(&self, _path: &str) -> Absent {
+        Absent
+    }
+
+    fn post_raw(&self, _path: &str, _body: &()) -> Absent {
+        Absent
+    }
+}
+
+#[test]
+fn untyped_client_methods_are_absent() {
+    let client = Client::new(tndrly::Config::new("key", "acc", "proj")).unwrap();
+    let Absent = client.get_raw("/simulations");
+    let Absent = client.post_raw("/simulate", &());
+}
+
+#[test]
+fn builds_without_dynamic_json() {
+    let client = Client::new(tndrly::Config::new("key", "acc", "proj")).unwrap();
+    let admin = AdminRpc::new("http://localhost").unwrap();
+    drop(typed_calls(&client, &admin));
+}